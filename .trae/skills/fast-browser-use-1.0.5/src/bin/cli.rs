@@ -1,14 +1,32 @@
-use browser_use::{BrowserSession, LaunchOptions};
+use browser_use::{BrowserSession, LaunchOptions, ToolRegistry, browser::kill_browser_process, tools::normalize_url};
 use clap::{Parser, Subcommand};
-use log::{info, warn};
+use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf, thread, time::Duration};
+use serde_json::{Value, json};
+use std::{fs,
+          io::{self, BufRead, Write},
+          path::PathBuf,
+          sync::atomic::{AtomicU32, Ordering},
+          thread,
+          time::Duration};
 
 #[derive(Parser)]
 #[command(name = "fast-browser-use")]
 #[command(version)]
 #[command(about = "Fastest Browser Use CLI", long_about = None)]
 struct Cli {
+    /// Give up after this many seconds (exit code 124); also the timeout for page loads
+    #[arg(long, global = true)]
+    timeout: Option<u64>,
+
+    /// Show the browser window instead of running headless
+    #[arg(long, global = true)]
+    headed: bool,
+
+    /// Print a single JSON result object to stdout instead of human-readable output
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -32,6 +50,14 @@ enum Commands {
         /// Load session (cookies/local storage) from file
         #[arg(long)]
         load_session: Option<PathBuf>,
+
+        /// Don't load images (faster for text extraction)
+        #[arg(long)]
+        block_images: bool,
+
+        /// Wait until no new requests were made for this many ms before continuing
+        #[arg(long, value_name = "MS")]
+        network_idle: Option<u64>,
     },
     /// Snapshot the current page (AI-optimized YAML DOM)
     Snapshot {
@@ -43,6 +69,14 @@ enum Commands {
         #[arg(long)]
         include_styles: bool,
 
+        /// Don't load images (faster for text extraction)
+        #[arg(long)]
+        block_images: bool,
+
+        /// Wait until no new requests were made for this many ms before continuing
+        #[arg(long, value_name = "MS")]
+        network_idle: Option<u64>,
+
         /// Output file path
         #[arg(long)]
         output: Option<PathBuf>,
@@ -75,6 +109,14 @@ enum Commands {
         #[arg(long, default_value = "1000")]
         delay: u64,
 
+        /// Don't load images (faster for text extraction)
+        #[arg(long)]
+        block_images: bool,
+
+        /// Wait until no new requests were made for this many ms before continuing
+        #[arg(long, value_name = "MS")]
+        network_idle: Option<u64>,
+
         /// Output file (JSON)
         #[arg(long)]
         output: Option<PathBuf>,
@@ -85,6 +127,14 @@ enum Commands {
         #[arg(long)]
         url: String,
 
+        /// Don't load images (faster for text extraction)
+        #[arg(long)]
+        block_images: bool,
+
+        /// Wait until no new requests were made for this many ms before continuing
+        #[arg(long, value_name = "MS")]
+        network_idle: Option<u64>,
+
         /// Output file path
         #[arg(long)]
         output: Option<PathBuf>,
@@ -103,6 +153,80 @@ enum Commands {
         #[arg(long)]
         full_page: bool,
     },
+    /// Run JavaScript on a page and print the result as JSON
+    Evaluate {
+        /// URL to run the script on
+        #[arg(long)]
+        url: String,
+
+        /// JavaScript expression to evaluate (Promises are awaited)
+        #[arg(long)]
+        script: String,
+
+        /// Output file (JSON)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Extract the text or HTML of the page or of one element
+    Extract {
+        /// URL to extract from
+        #[arg(long)]
+        url: String,
+
+        /// CSS selector of the element to extract (default: the whole body)
+        #[arg(long)]
+        selector: Option<String>,
+
+        /// Output format
+        #[arg(long, default_value = "text", value_parser = ["text", "html"])]
+        format: String,
+
+        /// Output file path
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Save a page as PDF
+    Pdf {
+        /// URL to print
+        #[arg(long)]
+        url: String,
+
+        /// Output file path (PDF)
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Use landscape orientation
+        #[arg(long)]
+        landscape: bool,
+
+        /// Leave out background colors and images
+        #[arg(long)]
+        no_background: bool,
+    },
+    /// Archive a page as a single MHTML file with its CSS and images inlined
+    Archive {
+        /// URL to archive
+        #[arg(long)]
+        url: String,
+
+        /// Output file path (.mhtml)
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Dump the cookies of a site as JSON, or set them from a file
+    Cookies {
+        /// URL whose cookies to read or set
+        #[arg(long)]
+        url: String,
+
+        /// Load cookies from this file (as written by `login`) instead of dumping them
+        #[arg(long)]
+        set: Option<PathBuf>,
+
+        /// Output file (JSON)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
     /// Analyze sitemap and page structure
     Sitemap {
         /// Base URL of the site to analyze
@@ -125,6 +249,22 @@ enum Commands {
         #[arg(long)]
         output: Option<PathBuf>,
     },
+    /// Print the JSON schemas of the tools' parameters, e.g. to generate typed clients
+    Schema {
+        /// Only describe this tool (default: all tools)
+        #[arg(long)]
+        tool: Option<String>,
+
+        /// Output file (JSON)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Open a headed browser and run commands typed on stdin (type `help` for a list)
+    Interact {
+        /// URL to open first
+        #[arg(long)]
+        url: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize)]
@@ -133,40 +273,257 @@ struct SessionData {
     // Local storage could be added here
 }
 
+/// Convert saved cookies to the format accepted by `set_cookies`, scoped to `url`
+fn cookie_params(session_data: SessionData, url: &str) -> Vec<browser_use::tools::cookies::CookieParam> {
+    session_data.cookies.into_iter().map(|c| {
+        browser_use::tools::cookies::CookieParam {
+            name: c.name,
+            value: c.value,
+            url: Some(url.to_string()), // Scope to target URL
+            domain: Some(c.domain),
+            path: Some(c.path),
+            secure: Some(c.secure),
+            http_only: Some(c.http_only),
+            same_site: None, // Simplified
+            expires: Some(c.expires),
+        }
+    }).collect()
+}
+
+/// Resource types blocked by `--block-images`
+fn blocked_resources(block_images: bool) -> Vec<String> {
+    if block_images { vec!["Image".to_string()] } else { Vec::new() }
+}
+
+/// Wait for `--network-idle`, if given, within the session's default timeout
+fn wait_for_network_idle(session: &BrowserSession, idle_ms: Option<u64>) -> browser_use::Result<()> {
+    if let Some(idle_ms) = idle_ms {
+        info!("Waiting for {} ms of network idle", idle_ms);
+        session.wait_for_network_idle(session.default_timeout().as_millis() as u64, idle_ms)?;
+    }
+    Ok(())
+}
+
+/// Commands of the `interact` shell
+const INTERACT_HELP: &str = "Commands:
+  goto <url>               navigate to a URL
+  click <selector>         click an element
+  type <selector> <text>   fill an input with text
+  snapshot                 print the AI snapshot of the page
+  markdown                 print the page as markdown
+  eval <js>                evaluate JavaScript and print the result
+  help                     show this list
+  quit                     close the browser and exit";
+
+/// Parse a line of the `interact` shell into a tool name and its parameters. Returns `None`
+/// for blank lines; `help` and `quit` are handled by the shell itself.
+fn parse_interact_command(line: &str) -> Result<Option<(&'static str, Value)>, String> {
+    let line = line.trim();
+    let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    let required = |what: &str| {
+        if rest.is_empty() { Err(format!("Usage: {} {}", command, what)) } else { Ok(rest) }
+    };
+
+    let parsed = match command {
+        "" => return Ok(None),
+        "goto" => ("navigate", json!({ "url": required("<url>")? })),
+        "click" => ("click", json!({ "selector": required("<selector>")? })),
+        "type" => {
+            let (selector, text) = required("<selector> <text>")?
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("Usage: {} <selector> <text>", command))?;
+            ("input", json!({ "selector": selector, "text": text.trim_start() }))
+        }
+        "snapshot" => ("snapshot", json!({})),
+        "markdown" => ("get_markdown", json!({})),
+        "eval" => ("evaluate", json!({ "code": required("<js>")?, "await_promise": true })),
+        other => return Err(format!("Unknown command '{}'. Type 'help' for a list.", other)),
+    };
+    Ok(Some(parsed))
+}
+
+/// Read commands from stdin until `quit` or end of input, running each on `session` and
+/// printing its result. Returns the number of commands run.
+fn interact(session: &BrowserSession) -> Result<usize, CliError> {
+    println!("{}", INTERACT_HELP);
+    let mut count = 0;
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+        let Some(line) = lines.next().transpose()? else { break };
+
+        match line.trim() {
+            "quit" | "exit" => break,
+            "help" => {
+                println!("{}", INTERACT_HELP);
+                continue;
+            }
+            _ => {}
+        }
+        let (tool, params) = match parse_interact_command(&line) {
+            Ok(Some(parsed)) => parsed,
+            Ok(None) => continue,
+            Err(message) => {
+                println!("{}", message);
+                continue;
+            }
+        };
+
+        count += 1;
+        match session.execute_tool(tool, params) {
+            Ok(result) if result.success => {
+                let data = result.data.unwrap_or_default();
+                // Print page content as is, anything else as JSON
+                match data.get("snapshot").or_else(|| data.get("markdown")).and_then(Value::as_str) {
+                    Some(text) => println!("{}", text),
+                    None => println!("{}", serde_json::to_string_pretty(&data)?),
+                }
+            }
+            Ok(result) => println!("Error: {}", result.error.unwrap_or_else(|| "Unknown error".to_string())),
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+    Ok(count)
+}
+
+/// Exit code used when `--timeout` expires, matching coreutils `timeout`
+const TIMEOUT_EXIT_CODE: i32 = 124;
+
+type CliError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Result envelope printed to stdout by `--json`, e.g. `{"status":"ok","url":"..."}`
+#[derive(Serialize)]
+struct CliOutput {
+    /// "ok" or "error"
+    status: &'static str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+
+    /// Command-specific fields, flattened into the envelope
+    #[serde(flatten)]
+    data: Value,
+}
+
+impl CliOutput {
+    fn ok(data: Value) -> Self {
+        Self { status: "ok", error: None, data }
+    }
+
+    fn error(message: String) -> Self {
+        Self { status: "error", error: Some(message), data: json!({}) }
+    }
+
+    fn print(&self) {
+        println!("{}", serde_json::to_string(self).expect("serializing CLI output never fails"));
+    }
+}
+
+/// Save `text` to `output`, or print it unless `--json` is set. Returns `data` plus either the
+/// saved path or, when nothing was saved, `value` under `key`.
+fn deliver(
+    output: Option<PathBuf>,
+    text: &str,
+    json: bool,
+    mut data: Value,
+    key: &str,
+    value: Value,
+) -> Result<Value, CliError> {
+    if let Some(path) = output {
+        fs::write(&path, text)?;
+        info!("Saved to {:?}", path);
+        data["path"] = json!(path);
+    } else {
+        if !json {
+            println!("{}", text);
+        }
+        data[key] = value;
+    }
+    Ok(data)
+}
+
+/// Process id of the browser launched by the running command (0 until launched), kept outside the
+/// command's thread so `--timeout` can kill the browser while that thread still owns the session
+static BROWSER_PID: AtomicU32 = AtomicU32::new(0);
+
+/// Launch a browser, applying `--timeout` as the session's default timeout
+fn launch(options: LaunchOptions, timeout: Option<Duration>) -> browser_use::Result<BrowserSession> {
+    let mut session = BrowserSession::launch(options)?;
+    if let Some(pid) = session.browser().get_process_id() {
+        BROWSER_PID.store(pid, Ordering::SeqCst);
+    }
+    if let Some(timeout) = timeout {
+        session.set_default_timeout(timeout);
+    }
+    Ok(session)
+}
+
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> Result<(), CliError> {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     let cli = Cli::parse();
+    let timeout = cli.timeout.map(Duration::from_secs);
+    let options = LaunchOptions::default().sandbox(false).headless(!cli.headed);
+
+    // The browser API is blocking, so run the command on its own thread to be able to time it out
+    let json = cli.json;
+    let command = tokio::task::spawn_blocking(move || run(cli.command, options, timeout, json));
+    let result = match timeout {
+        Some(limit) => match tokio::time::timeout(limit, command).await {
+            Ok(result) => result?,
+            Err(_) => {
+                let message = format!("Timed out after {}s", limit.as_secs());
+                error!("{}", message);
+                if json {
+                    CliOutput::error(message).print();
+                }
+                // process::exit skips destructors, so the session's Chrome would be left running
+                let pid = BROWSER_PID.load(Ordering::SeqCst);
+                if pid != 0
+                    && let Err(e) = kill_browser_process(pid)
+                {
+                    warn!("Failed to kill browser process {}: {}", pid, e);
+                }
+                std::process::exit(TIMEOUT_EXIT_CODE);
+            }
+        },
+        None => command.await?,
+    };
+
+    match result {
+        Ok(data) => {
+            if json {
+                CliOutput::ok(data).print();
+            }
+            Ok(())
+        }
+        Err(e) if json => {
+            error!("{}", e);
+            CliOutput::error(e.to_string()).print();
+            std::process::exit(1);
+        }
+        Err(e) => Err(e),
+    }
+}
 
-    match cli.command {
-        Commands::Navigate { url, human_emulation, wait_for_selector, load_session } => {
+/// Run a subcommand, launching its browser from `options`, and return its `--json` result fields
+fn run(command: Commands, options: LaunchOptions, timeout: Option<Duration>, json: bool) -> Result<Value, CliError> {
+    match command {
+        Commands::Navigate { url, human_emulation, wait_for_selector, load_session, block_images, network_idle } => {
+            let url = normalize_url(&url);
             info!("Navigating to: {}", url);
-            let options = LaunchOptions::default().sandbox(false);
+            let options = options.block_resources(blocked_resources(block_images));
             
-            let session = BrowserSession::launch(options)?;
+            let session = launch(options, timeout)?;
 
             if let Some(path) = load_session {
                 if path.exists() {
                     info!("Loading session from {:?}", path);
                     let data = fs::read_to_string(path)?;
                     let session_data: SessionData = serde_json::from_str(&data)?;
-                    
-                    // Convert cookies to CookieParam format
-                    let cookie_params: Vec<_> = session_data.cookies.into_iter().map(|c| {
-                        browser_use::tools::cookies::CookieParam {
-                            name: c.name,
-                            value: c.value,
-                            url: Some(url.clone()), // Scope to target URL
-                            domain: Some(c.domain),
-                            path: Some(c.path),
-                            secure: Some(c.secure),
-                            http_only: Some(c.http_only),
-                            same_site: None, // Simplified
-                            expires: Some(c.expires),
-                        }
-                    }).collect();
-                    
-                    session.set_cookies(cookie_params)?;
+                    session.set_cookies(cookie_params(session_data, &url))?;
                 } else {
                     warn!("Session file not found: {:?}", path);
                 }
@@ -189,16 +546,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             } else {
                 session.wait_for_navigation()?;
             }
+            wait_for_network_idle(&session, network_idle)?;
 
             info!("Navigation complete.");
+            Ok(json!({ "url": session.get_active_tab()?.get_url() }))
         }
-        Commands::Snapshot { url, include_styles, output } => {
-            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+        Commands::Snapshot { url, include_styles, block_images, network_idle, output } => {
+            let options = options.block_resources(blocked_resources(block_images));
+            let session = launch(options, timeout)?;
             
-            if let Some(u) = url {
+            if let Some(u) = &url {
                 info!("Navigating to {}", u);
-                session.navigate(&u)?;
+                session.navigate(u)?;
                 session.wait_for_navigation()?;
+                wait_for_network_idle(&session, network_idle)?;
             }
 
             if include_styles {
@@ -220,21 +581,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             
             let snapshot_yaml = render_aria_tree(&dom.root, RenderMode::Ai, None);
             
-            if let Some(path) = output {
-                fs::write(&path, snapshot_yaml)?;
-                info!("Snapshot saved to {:?}", path);
-            } else {
-                println!("{}", snapshot_yaml);
-            }
+            deliver(output, &snapshot_yaml, json, json!({ "url": url }), "snapshot", json!(snapshot_yaml))
         }
         Commands::Login { url, save_session } => {
             info!("Opening headed browser for login at {}", url);
-            let options = LaunchOptions::default().headless(false).sandbox(false);
-            let session = BrowserSession::launch(options)?;
+            // Always headed, so the user can log in by hand
+            let options = options.headless(false);
+            let session = launch(options, timeout)?;
             
             session.navigate(&url)?;
             
-            println!("Press Enter after you have logged in...");
+            // Keep stdout for the result with --json
+            if json {
+                eprintln!("Press Enter after you have logged in...");
+            } else {
+                println!("Press Enter after you have logged in...");
+            }
             let mut input = String::new();
             std::io::stdin().read_line(&mut input)?;
             
@@ -245,16 +607,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 cookies,
             };
             
-            let json = serde_json::to_string_pretty(&session_data)?;
-            fs::write(&save_session, json)?;
+            let session_json = serde_json::to_string_pretty(&session_data)?;
+            fs::write(&save_session, session_json)?;
             info!("Session saved to {:?}", save_session);
+            Ok(json!({ "url": url, "path": save_session, "cookies": session_data.cookies.len() }))
         }
-        Commands::Harvest { url, selector, scrolls, delay, output } => {
+        Commands::Harvest { url, selector, scrolls, delay, block_images, network_idle, output } => {
             info!("🚜 Harvesting from {} (selector: {}, scrolls: {})", url, selector, scrolls);
-            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+            let options = options.block_resources(blocked_resources(block_images));
+            let session = launch(options, timeout)?;
             
             session.navigate(&url)?;
             session.wait_for_navigation()?;
+            wait_for_network_idle(&session, network_idle)?;
             
             let tab = session.get_active_tab()?;
             let mut all_items: Vec<String> = Vec::new();
@@ -275,15 +640,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "#, selector.replace('\'', "\\'"));
                 
                 let result = tab.evaluate(&extract_js, false)?;
-                if let Some(value) = &result.value {
-                    if let Some(json_str) = value.as_str() {
-                        if let Ok(items) = serde_json::from_str::<Vec<String>>(json_str) {
-                            for item in items {
-                                if !item.is_empty() && !seen.contains(&item) {
-                                    seen.insert(item.clone());
-                                    all_items.push(item);
-                                }
-                            }
+                if let Some(value) = &result.value
+                    && let Some(json_str) = value.as_str()
+                    && let Ok(items) = serde_json::from_str::<Vec<String>>(json_str)
+                {
+                    for item in items {
+                        if !item.is_empty() && !seen.contains(&item) {
+                            seen.insert(item.clone());
+                            all_items.push(item);
                         }
                     }
                 }
@@ -298,19 +662,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("✅ Harvested {} unique items", all_items.len());
             
             let json_output = serde_json::to_string_pretty(&all_items)?;
-            if let Some(path) = output {
-                fs::write(&path, &json_output)?;
-                info!("Saved to {:?}", path);
-            } else {
-                println!("{}", json_output);
-            }
+            let data = json!({ "url": url, "count": all_items.len() });
+            deliver(output, &json_output, json, data, "items", json!(all_items))
         }
-        Commands::Markdown { url, output } => {
+        Commands::Markdown { url, block_images, network_idle, output } => {
             info!("Converting {} to markdown", url);
-            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+            let options = options.block_resources(blocked_resources(block_images));
+            let session = launch(options, timeout)?;
             
             session.navigate(&url)?;
             session.wait_for_navigation()?;
+            wait_for_network_idle(&session, network_idle)?;
             
             let result = session.execute_tool("get_markdown", serde_json::json!({}))?;
             
@@ -319,34 +681,122 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .and_then(|m| m.as_str())
                 .unwrap_or("Failed to extract markdown");
             
-            if let Some(path) = output {
-                fs::write(&path, markdown)?;
-                info!("Saved to {:?}", path);
-            } else {
-                println!("{}", markdown);
-            }
+            deliver(output, markdown, json, json!({ "url": url }), "markdown", json!(markdown))
         }
         Commands::Screenshot { url, output, full_page } => {
             info!("📸 Screenshotting {}", url);
-            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+            let session = launch(options, timeout)?;
 
             session.navigate(&url)?;
             session.wait_for_navigation()?;
 
-            let tab = session.get_active_tab()?;
-            let screenshot_data = tab.capture_screenshot(
-                headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
-                None,
-                None,
-                !full_page,
-            )?;
+            let screenshot_data = if full_page {
+                session.capture_full_page()?
+            } else {
+                session.get_active_tab()?.capture_screenshot(
+                    headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
+                    None,
+                    None,
+                    true,
+                )?
+            };
 
             fs::write(&output, &screenshot_data)?;
             info!("✅ Saved screenshot to {:?}", output);
+            Ok(json!({ "url": url, "path": output, "size_bytes": screenshot_data.len() }))
+        }
+        Commands::Evaluate { url, script, output } => {
+            let url = normalize_url(&url);
+            info!("Evaluating script on {}", url);
+            let session = launch(options, timeout)?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            let result = session.execute_tool("evaluate", serde_json::json!({
+                "code": script,
+                "await_promise": true,
+            }))?;
+            if !result.success {
+                return Err(result.error.unwrap_or_else(|| "Evaluation failed".to_string()).into());
+            }
+
+            let value = result.data.as_ref().and_then(|d| d.get("result")).cloned().unwrap_or_default();
+            let json_output = serde_json::to_string_pretty(&value)?;
+            deliver(output, &json_output, json, json!({ "url": url }), "result", value)
+        }
+        Commands::Extract { url, selector, format, output } => {
+            let url = normalize_url(&url);
+            info!("Extracting {} from {}", format, url);
+            let session = launch(options, timeout)?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            let result = session.execute_tool("extract", serde_json::json!({
+                "selector": selector,
+                "format": format,
+            }))?;
+            if !result.success {
+                return Err(result.error.unwrap_or_else(|| "Extraction failed".to_string()).into());
+            }
+
+            let content = result.data.as_ref().and_then(|d| d.get("content")).and_then(|c| c.as_str()).unwrap_or_default();
+            deliver(output, content, json, json!({ "url": url, "format": format }), "content", json!(content))
+        }
+        Commands::Pdf { url, output, landscape, no_background } => {
+            let url = normalize_url(&url);
+            info!("Printing {} to PDF", url);
+            let session = launch(options, timeout)?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            let pdf_data = session.print_pdf(landscape, !no_background)?;
+            fs::write(&output, &pdf_data)?;
+            info!("✅ Saved PDF to {:?}", output);
+            Ok(json!({ "url": url, "path": output, "size_bytes": pdf_data.len() }))
+        }
+        Commands::Archive { url, output } => {
+            let url = normalize_url(&url);
+            info!("Archiving {} as MHTML", url);
+            let session = launch(options, timeout)?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            let mhtml = session.capture_mhtml()?;
+            fs::write(&output, &mhtml)?;
+            info!("✅ Saved archive to {:?}", output);
+            Ok(json!({ "url": url, "path": output, "size_bytes": mhtml.len() }))
+        }
+        Commands::Cookies { url, set, output } => {
+            let url = normalize_url(&url);
+            let session = launch(options, timeout)?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            if let Some(path) = set {
+                info!("Setting cookies for {} from {:?}", url, path);
+                let data = fs::read_to_string(&path)?;
+                let session_data: SessionData = serde_json::from_str(&data)?;
+                let count = session_data.cookies.len();
+                session.set_cookies(cookie_params(session_data, &url))?;
+                info!("✅ Set {} cookies", count);
+                Ok(json!({ "url": url, "set": count }))
+            } else {
+                let session_data = SessionData { cookies: session.get_cookies()? };
+                info!("Found {} cookies for {}", session_data.cookies.len(), url);
+
+                let json_output = serde_json::to_string_pretty(&session_data)?;
+                let data = json!({ "url": url, "count": session_data.cookies.len() });
+                deliver(output, &json_output, json, data, "cookies", json!(session_data.cookies))
+            }
         }
         Commands::Sitemap { url, analyze_structure, max_pages, max_sitemaps, output } => {
             info!("🗺️  Analyzing sitemap for {}", url);
-            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+            let session = launch(options, timeout)?;
 
             let sitemap_result = browser_use::tools::sitemap::analyze_sitemap(
                 &session,
@@ -356,18 +806,67 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 max_sitemaps,
             )?;
 
+            info!("✅ Sitemap analysis complete: {} sitemaps, {} pages found",
+                  sitemap_result.sitemaps.len(), sitemap_result.pages.len());
+
             let json_output = serde_json::to_string_pretty(&sitemap_result)?;
-            if let Some(path) = output {
-                fs::write(&path, &json_output)?;
-                info!("✅ Saved sitemap analysis to {:?}", path);
-            } else {
-                println!("{}", json_output);
+            deliver(output, &json_output, json, json!({ "url": url }), "sitemap", json!(sitemap_result))
+        }
+        Commands::Schema { tool, output } => {
+            // Tools describe themselves without a browser, so none is launched
+            let descriptors = ToolRegistry::with_defaults().describe();
+            let schema = match &tool {
+                Some(name) => {
+                    let descriptor = descriptors.into_iter().find(|d| &d.name == name).ok_or_else(|| {
+                        format!("Unknown tool '{}'. Run `schema` without --tool to list all tools.", name)
+                    })?;
+                    json!(descriptor)
+                }
+                None => json!(descriptors),
+            };
+
+            let json_output = serde_json::to_string_pretty(&schema)?;
+            deliver(output, &json_output, json, json!({ "tool": tool }), "schema", schema)
+        }
+        Commands::Interact { url } => {
+            // Always headed, so the user can watch what the commands do
+            let session = launch(options.headless(false), timeout)?;
+            if let Some(url) = &url {
+                let url = normalize_url(url);
+                info!("Navigating to {}", url);
+                session.navigate(&url)?;
+                session.wait_for_navigation()?;
             }
 
-            info!("✅ Sitemap analysis complete: {} sitemaps, {} pages found",
-                  sitemap_result.sitemaps.len(), sitemap_result.pages.len());
+            let commands = interact(&session)?;
+            Ok(json!({ "url": url, "commands": commands }))
         }
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_interact_command() {
+        assert_eq!(parse_interact_command("   ").unwrap(), None);
+        assert_eq!(
+            parse_interact_command("click  #submit").unwrap(),
+            Some(("click", json!({ "selector": "#submit" })))
+        );
+        assert_eq!(
+            parse_interact_command("type #name Jane Doe").unwrap(),
+            Some(("input", json!({ "selector": "#name", "text": "Jane Doe" })))
+        );
+        assert_eq!(parse_interact_command("markdown").unwrap(), Some(("get_markdown", json!({}))));
+        assert_eq!(
+            parse_interact_command("eval document.title").unwrap(),
+            Some(("evaluate", json!({ "code": "document.title", "await_promise": true })))
+        );
+
+        assert!(parse_interact_command("click").is_err());
+        assert!(parse_interact_command("type #name").is_err());
+        assert!(parse_interact_command("dance").is_err());
+    }
 }
@@ -1,8 +1,12 @@
-use browser_use::{browser::LaunchOptions, mcp::BrowserServer};
+use browser_use::{browser::LaunchOptions,
+                  mcp::{BrowserServer, ShutdownHandle}};
 use clap::{Parser, ValueEnum};
-use log::{debug, info};
+use log::{debug, info, warn};
 use rmcp::{ServiceExt, transport::stdio};
-use std::io::{stdin, stdout};
+use std::{future::IntoFuture,
+          io::{stdin, stdout},
+          net::{IpAddr, SocketAddr},
+          sync::{Arc, Mutex}};
 
 #[cfg(feature = "mcp-server")]
 use rmcp::transport::{sse_server::{SseServer, SseServerConfig},
@@ -31,6 +35,14 @@ struct Cli {
     #[arg(long, value_name = "PATH")]
     executable_path: Option<String>,
 
+    /// Only expose tools that don't change the page (snapshots, markdown, screenshots...)
+    #[arg(long)]
+    read_only: bool,
+
+    /// Only allow navigating to these hosts, comma-separated (e.g. example.com,*.example.org)
+    #[arg(long, value_name = "DOMAINS", value_delimiter = ',')]
+    allowed_domains: Option<Vec<String>>,
+
     /// CDP endpoint URL for remote browser connection
     #[arg(long, value_name = "URL")]
     cdp_endpoint: Option<String>,
@@ -51,6 +63,10 @@ struct Cli {
     #[arg(long, short = 'p', default_value = "3000")]
     port: u16,
 
+    /// Address to bind SSE or HTTP transport to, e.g. 0.0.0.0 to accept remote connections (default: 127.0.0.1)
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
     /// SSE endpoint path (default: /sse)
     #[arg(long, default_value = "/sse")]
     sse_path: String,
@@ -73,10 +89,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
 
-    let options = LaunchOptions { headless: !cli.headed, ..Default::default() };
+    let options = LaunchOptions {
+        headless: !cli.headed,
+        read_only: cli.read_only,
+        allowed_domains: cli.allowed_domains,
+        ..Default::default()
+    };
 
     info!("Browser-use MCP Server v{}", env!("CARGO_PKG_VERSION"));
     info!("Browser mode: {}", if options.headless { "headless" } else { "headed" });
+    if options.read_only {
+        info!("Read-only mode: mutating tools are disabled");
+    }
 
     if let Some(ref path) = cli.executable_path {
         info!("Browser executable: {}", path);
@@ -103,47 +127,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .map_err(|e| format!("Failed to create browser server: {}", e))?;
             let server = service.serve(stdio()).await?;
 
-            // Set up signal handler for graceful shutdown
-            #[cfg(unix)]
-            {
-                let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
-                let mut sigint = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::interrupt())?;
-
-                tokio::select! {
-                    quit_reason = server.waiting() => {
-                        debug!("Server quit with reason: {:?}", quit_reason);
-                    }
-                    _ = sigterm.recv() => {
-                        info!("Received SIGTERM, shutting down gracefully...");
-                    }
-                    _ = sigint.recv() => {
-                        info!("Received SIGINT (Ctrl+C), shutting down gracefully...");
-                    }
-                }
-            }
-
-            #[cfg(windows)]
-            {
-                let mut ctrl_c = tokio::signal::windows::ctrl_c()?;
-                let mut ctrl_break = tokio::signal::windows::ctrl_break()?;
-
-                tokio::select! {
-                    quit_reason = server.waiting() => {
-                        debug!("Server quit with reason: {:?}", quit_reason);
-                    }
-                    _ = ctrl_c.recv() => {
-                        info!("Received Ctrl+C, shutting down gracefully...");
-                    }
-                    _ = ctrl_break.recv() => {
-                        info!("Received Ctrl+Break, shutting down gracefully...");
-                    }
+            tokio::select! {
+                quit_reason = server.waiting() => {
+                    debug!("Server quit with reason: {:?}", quit_reason);
                 }
-            }
-
-            #[cfg(not(any(unix, windows)))]
-            {
-                let quit_reason = server.waiting().await;
-                debug!("Server quit with reason: {:?}", quit_reason);
+                result = shutdown_signal() => result?,
             }
         }
         Transport::Sse => {
@@ -152,13 +140,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("SSE path: {}", cli.sse_path);
             info!("SSE POST path: {}", cli.sse_post_path);
 
-            let bind_addr = format!("127.0.0.1:{}", cli.port);
+            let bind_addr = bind_address(&cli.host, cli.port)?;
 
+            let ct = CancellationToken::new();
             let config = SseServerConfig {
-                bind: bind_addr.parse()?,
+                bind: bind_addr,
                 sse_path: cli.sse_path.clone(),
                 post_path: cli.sse_post_path.clone(),
-                ct: CancellationToken::new(),
+                ct: ct.clone(),
                 sse_keep_alive: None,
             };
 
@@ -167,24 +156,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("Ready to accept MCP connections at http://{}{}", bind_addr, cli.sse_path);
 
             // Register service factory for each connection
+            let browsers = ServedBrowsers::default();
+            let registry = browsers.clone();
             let _cancellation_token = sse_server.with_service(move || {
-                BrowserServer::with_options(options.clone()).expect("Failed to create browser server")
+                let server = BrowserServer::with_options(options.clone()).expect("Failed to create browser server");
+                registry.register(server.shutdown_handle());
+                server
             });
 
             // Start HTTP server with SSE router
             let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
-            axum::serve(listener, router.into_make_service()).await?;
+            tokio::spawn(cancel_on_signal(ct.clone()));
+            serve_until_cancelled(axum::serve(listener, router.into_make_service()), ct, &browsers).await?;
         }
         Transport::Http => {
             info!("Transport: HTTP streamable");
             info!("Port: {}", cli.port);
             info!("HTTP path: {}", cli.http_path);
 
-            let bind_addr = format!("127.0.0.1:{}", cli.port);
+            let bind_addr = bind_address(&cli.host, cli.port)?;
 
+            let browsers = ServedBrowsers::default();
+            let registry = browsers.clone();
             let service_factory = move || {
-                BrowserServer::with_options(options.clone())
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+                let server = BrowserServer::with_options(options.clone()).map_err(std::io::Error::other)?;
+                registry.register(server.shutdown_handle());
+                Ok(server)
             };
 
             let http_service =
@@ -195,9 +192,175 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("Ready to accept MCP connections at http://{}{}", bind_addr, cli.http_path);
 
             let listener = tokio::net::TcpListener::bind(bind_addr).await?;
-            axum::serve(listener, router).await?;
+            let ct = CancellationToken::new();
+            tokio::spawn(cancel_on_signal(ct.clone()));
+            serve_until_cancelled(axum::serve(listener, router), ct, &browsers).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Socket address for the SSE/HTTP transports, rejecting hosts that aren't IP addresses
+fn bind_address(host: &str, port: u16) -> Result<SocketAddr, String> {
+    let ip: IpAddr = host
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid --host '{}': expected an IP address such as 127.0.0.1", host))?;
+    if ip.is_unspecified() {
+        warn!("Binding to {}: the server is reachable from other hosts and has no authentication", ip);
+    }
+    Ok(SocketAddr::new(ip, port))
+}
+
+/// Resolve once the process is asked to terminate: SIGTERM or SIGINT on Unix, Ctrl+C or
+/// Ctrl+Break on Windows
+async fn shutdown_signal() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+        let mut sigint = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::interrupt())?;
+
+        tokio::select! {
+            _ = sigterm.recv() => {
+                info!("Received SIGTERM, shutting down gracefully...");
+            }
+            _ = sigint.recv() => {
+                info!("Received SIGINT (Ctrl+C), shutting down gracefully...");
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        let mut ctrl_c = tokio::signal::windows::ctrl_c()?;
+        let mut ctrl_break = tokio::signal::windows::ctrl_break()?;
+
+        tokio::select! {
+            _ = ctrl_c.recv() => {
+                info!("Received Ctrl+C, shutting down gracefully...");
+            }
+            _ = ctrl_break.recv() => {
+                info!("Received Ctrl+Break, shutting down gracefully...");
+            }
         }
     }
 
+    #[cfg(not(any(unix, windows)))]
+    std::future::pending::<()>().await;
+
     Ok(())
 }
+
+/// Cancel `ct` when a shutdown signal arrives
+#[cfg(feature = "mcp-server")]
+async fn cancel_on_signal(ct: CancellationToken) {
+    if let Err(e) = shutdown_signal().await {
+        log::error!("Failed to listen for shutdown signals: {}", e);
+        return;
+    }
+    ct.cancel();
+}
+
+/// Action run on shutdown, unless `pending` says there is nothing left to clean up
+struct Cleanup {
+    pending: Box<dyn Fn() -> bool + Send>,
+    run: Box<dyn FnOnce() + Send>,
+}
+
+/// Browsers launched for SSE/HTTP connections, so they can be quit on shutdown instead of
+/// being left behind as orphaned Chrome processes
+#[derive(Clone, Default)]
+struct ServedBrowsers {
+    cleanups: Arc<Mutex<Vec<Cleanup>>>,
+}
+
+impl ServedBrowsers {
+    /// Quit the browser behind `handle` on shutdown, as long as its server still exists
+    fn register(&self, handle: ShutdownHandle) {
+        let pending = handle.clone();
+        self.register_cleanup(move || pending.server_exists(), move || handle.shutdown());
+    }
+
+    /// Register `cleanup`, dropping the cleanups of earlier connections that are no longer pending
+    /// so the list does not grow with every connection
+    fn register_cleanup(
+        &self,
+        pending: impl Fn() -> bool + Send + 'static,
+        cleanup: impl FnOnce() + Send + 'static,
+    ) {
+        let mut cleanups = self.cleanups.lock().unwrap_or_else(|e| e.into_inner());
+        cleanups.retain(|cleanup| (cleanup.pending)());
+        cleanups.push(Cleanup { pending: Box::new(pending), run: Box::new(cleanup) });
+    }
+
+    /// Run all registered cleanups that are still pending
+    fn shutdown_all(&self) {
+        let cleanups = std::mem::take(&mut *self.cleanups.lock().unwrap_or_else(|e| e.into_inner()));
+        let cleanups: Vec<Cleanup> = cleanups.into_iter().filter(|cleanup| (cleanup.pending)()).collect();
+        info!("Closing {} served browser(s)", cleanups.len());
+        for cleanup in cleanups {
+            (cleanup.run)();
+        }
+    }
+}
+
+/// Run `serve` until it finishes or `ct` is cancelled, then quit all served browsers
+#[cfg(feature = "mcp-server")]
+async fn serve_until_cancelled<F>(serve: F, ct: CancellationToken, browsers: &ServedBrowsers) -> std::io::Result<()>
+where
+    F: IntoFuture<Output = std::io::Result<()>>,
+{
+    let result = tokio::select! {
+        result = serve.into_future() => result,
+        _ = ct.cancelled() => Ok(()),
+    };
+    // Quitting a browser blocks until its process has exited
+    let browsers = browsers.clone();
+    if let Err(e) = tokio::task::spawn_blocking(move || browsers.shutdown_all()).await {
+        log::error!("Failed to close served browsers: {}", e);
+    }
+    result
+}
+
+#[cfg(all(test, feature = "mcp-server"))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_bind_address() {
+        assert_eq!(bind_address("127.0.0.1", 3000).unwrap().to_string(), "127.0.0.1:3000");
+        assert_eq!(bind_address("0.0.0.0", 8080).unwrap().to_string(), "0.0.0.0:8080");
+        assert_eq!(bind_address("::", 3000).unwrap().to_string(), "[::]:3000");
+        assert!(bind_address("localhost", 3000).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_runs_cleanup() {
+        let browsers = ServedBrowsers::default();
+        let cleaned_up = Arc::new(AtomicBool::new(false));
+        let flag = cleaned_up.clone();
+        browsers.register_cleanup(|| true, move || flag.store(true, Ordering::SeqCst));
+
+        let ct = CancellationToken::new();
+        ct.cancel();
+        serve_until_cancelled(std::future::pending(), ct, &browsers).await.unwrap();
+
+        assert!(cleaned_up.load(Ordering::SeqCst));
+        assert!(browsers.cleanups.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_register_drops_finished_cleanups() {
+        let browsers = ServedBrowsers::default();
+        let ran = Arc::new(AtomicBool::new(false));
+        let flag = ran.clone();
+        browsers.register_cleanup(|| false, move || flag.store(true, Ordering::SeqCst));
+        browsers.register_cleanup(|| true, || {});
+        assert_eq!(browsers.cleanups.lock().unwrap().len(), 1);
+
+        browsers.shutdown_all();
+        assert!(!ran.load(Ordering::SeqCst));
+    }
+}
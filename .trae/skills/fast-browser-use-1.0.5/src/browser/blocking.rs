@@ -0,0 +1,159 @@
+//! Request blocking via the CDP Fetch domain
+//!
+//! Blocked requests are paused by Chrome and failed from the tab's request interceptor,
+//! so they never hit the network.
+
+use crate::error::{BrowserError, Result};
+use headless_chrome::{Tab,
+                      browser::tab::RequestPausedDecision,
+                      protocol::cdp::{Fetch::{FailRequest, events::RequestPausedEvent},
+                                      Network::{ErrorReason, ResourceType}}};
+use std::sync::{Arc, Mutex};
+
+/// What a session blocks, shared by all of its tabs
+#[derive(Clone, Default)]
+pub(crate) struct BlockRules {
+    /// Resource types to block (CDP names such as "Image")
+    pub(crate) resource_types: Arc<Mutex<Vec<String>>>,
+
+    /// Glob patterns matched against the full request URL
+    pub(crate) url_patterns: Arc<Mutex<Vec<String>>>,
+}
+
+impl BlockRules {
+    pub(crate) fn with_resource_types(types: Vec<String>) -> Self {
+        Self { resource_types: Arc::new(Mutex::new(types)), ..Default::default() }
+    }
+
+    /// Whether any rule is set, i.e. requests need to be intercepted at all
+    pub(crate) fn is_active(&self) -> bool {
+        let has_types = self.resource_types.lock().map(|t| !t.is_empty()).unwrap_or(false);
+        let has_patterns = self.url_patterns.lock().map(|p| !p.is_empty()).unwrap_or(false);
+        has_types || has_patterns
+    }
+
+    /// Whether a request should be failed
+    pub(crate) fn blocks(&self, resource_type: &ResourceType, url: &str) -> bool {
+        let type_blocked =
+            self.resource_types.lock().map(|t| resource_type_blocked(resource_type, &t)).unwrap_or(false);
+        type_blocked
+            || self.url_patterns.lock().map(|p| p.iter().any(|pattern| glob_match(pattern, url))).unwrap_or(false)
+    }
+}
+
+/// Whether `resource_type` is in `blocked` (CDP names such as "Image", compared case-insensitively)
+pub(crate) fn resource_type_blocked(resource_type: &ResourceType, blocked: &[String]) -> bool {
+    let name = format!("{:?}", resource_type);
+    blocked.iter().any(|b| b.eq_ignore_ascii_case(&name))
+}
+
+/// Match `text` against a glob `pattern`, where `*` matches any run of characters
+/// (including `/`) and `?` matches exactly one character
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen and the text position it is currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // Let the last `*` swallow one more character and retry
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Install the blocking interceptor on a tab and enable or disable the Fetch domain to match.
+///
+/// The interceptor reads the shared rules on every request, so later changes only need
+/// [`sync_fetch`] to be called again.
+pub(crate) fn install_request_blocking(tab: &Arc<Tab>, rules: BlockRules) -> Result<()> {
+    let interceptor_rules = rules.clone();
+    tab.enable_request_interception(Arc::new(move |_transport, _session_id, event: RequestPausedEvent| {
+        if interceptor_rules.blocks(&event.params.resource_Type, &event.params.request.url) {
+            RequestPausedDecision::Fail(FailRequest {
+                request_id: event.params.request_id,
+                error_reason: ErrorReason::BlockedByClient,
+            })
+        } else {
+            RequestPausedDecision::Continue(None)
+        }
+    }))
+    .map_err(|e| BrowserError::ChromeError(format!("Failed to install request interceptor: {}", e)))?;
+
+    sync_fetch(tab, rules.is_active())
+}
+
+/// Pause requests for interception only while something is blocked, since every paused
+/// request costs a round-trip
+pub(crate) fn sync_fetch(tab: &Tab, active: bool) -> Result<()> {
+    let result = if active { tab.enable_fetch(None, None) } else { tab.disable_fetch() };
+    result
+        .map(|_| ())
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to configure request interception: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_type_blocked() {
+        let blocked = vec!["Image".to_string(), "font".to_string()];
+        assert!(resource_type_blocked(&ResourceType::Image, &blocked));
+        assert!(resource_type_blocked(&ResourceType::Font, &blocked));
+        assert!(!resource_type_blocked(&ResourceType::Document, &blocked));
+        assert!(!resource_type_blocked(&ResourceType::Image, &[]));
+    }
+
+    #[test]
+    fn test_glob_match_tracker_domain() {
+        let pattern = "*://*.doubleclick.net/*";
+        assert!(glob_match(pattern, "https://ad.doubleclick.net/ddm/activity"));
+        assert!(glob_match(pattern, "http://stats.g.doubleclick.net/"));
+        assert!(!glob_match(pattern, "https://doubleclick.net/"));
+        assert!(!glob_match(pattern, "https://doubleclick.net.example.com/"));
+        assert!(!glob_match(pattern, "https://example.com/"));
+    }
+
+    #[test]
+    fn test_glob_match_wildcards() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "https://example.com/"));
+        assert!(glob_match("https://example.com/*.js", "https://example.com/static/app.js"));
+        assert!(!glob_match("https://example.com/*.js", "https://example.com/app.css"));
+        assert!(glob_match("https://example.com/?", "https://example.com/a"));
+        assert!(!glob_match("https://example.com/?", "https://example.com/"));
+        assert!(glob_match("*a*b*", "xxaxxbxx"));
+        assert!(!glob_match("exact", "exactly"));
+    }
+
+    #[test]
+    fn test_block_rules() {
+        let rules = BlockRules::default();
+        assert!(!rules.is_active());
+        assert!(!rules.blocks(&ResourceType::Script, "https://ad.doubleclick.net/x.js"));
+
+        rules.url_patterns.lock().unwrap().push("*://*.doubleclick.net/*".to_string());
+        assert!(rules.is_active());
+        assert!(rules.blocks(&ResourceType::Script, "https://ad.doubleclick.net/x.js"));
+        assert!(!rules.blocks(&ResourceType::Script, "https://example.com/x.js"));
+
+        let rules = BlockRules::with_resource_types(vec!["Image".to_string()]);
+        assert!(rules.is_active());
+        assert!(rules.blocks(&ResourceType::Image, "https://example.com/logo.png"));
+    }
+}
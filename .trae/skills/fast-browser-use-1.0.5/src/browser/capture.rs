@@ -0,0 +1,124 @@
+//! Capture of XHR and fetch response bodies via the CDP Network domain
+//!
+//! Bodies are fetched with `Network.getResponseBody` once a matching response has finished
+//! loading, so the page's own request is read rather than repeated.
+
+use crate::{browser::{blocking::glob_match,
+                      debug::{CapturedResponse, push_bounded}},
+            error::{BrowserError, Result}};
+use headless_chrome::{Tab, protocol::cdp::Network::ResourceType};
+use std::{sync::{Arc, Mutex},
+          time::{SystemTime, UNIX_EPOCH}};
+
+/// Most responses kept; the oldest are dropped first
+const MAX_CAPTURED_RESPONSES: usize = 100;
+
+/// Longest body kept per response, in characters; longer bodies are cut and marked as truncated
+const MAX_CAPTURED_BODY_CHARS: usize = 1_000_000;
+
+/// Name the capture handler is registered under on each tab
+const HANDLER_NAME: &str = "browser_use_capture";
+
+/// Which responses a session captures and what it captured so far, shared by all of its tabs
+#[derive(Clone, Default)]
+pub(crate) struct CaptureRules {
+    /// Glob patterns matched against the full response URL
+    pub(crate) url_patterns: Arc<Mutex<Vec<String>>>,
+
+    /// Captured responses, most recent last
+    pub(crate) responses: Arc<Mutex<Vec<CapturedResponse>>>,
+}
+
+impl CaptureRules {
+    /// Whether any pattern is set, i.e. responses need to be observed at all
+    pub(crate) fn is_active(&self) -> bool {
+        self.url_patterns.lock().map(|p| !p.is_empty()).unwrap_or(false)
+    }
+
+    /// Whether a response should be captured: only XHR and fetch responses whose URL matches
+    pub(crate) fn captures(&self, resource_type: &ResourceType, url: &str) -> bool {
+        matches!(resource_type, ResourceType::Xhr | ResourceType::Fetch)
+            && self.url_patterns.lock().map(|p| p.iter().any(|pattern| glob_match(pattern, url))).unwrap_or(false)
+    }
+}
+
+/// Cut `body` to at most `max_chars` characters; true if anything was cut
+fn truncate_body(body: &mut String, max_chars: usize) -> bool {
+    match body.char_indices().nth(max_chars) {
+        Some((end, _)) => {
+            body.truncate(end);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Install the capture handler on a tab if any pattern is set. This enables the Network domain,
+/// so tabs of sessions that never capture don't pay for its events.
+pub(crate) fn install_response_capture(tab: &Tab, rules: CaptureRules) -> Result<()> {
+    if !rules.is_active() {
+        return Ok(());
+    }
+
+    let handler_rules = rules.clone();
+    tab.register_response_handling(
+        HANDLER_NAME,
+        Box::new(move |event, fetch_body| {
+            if !handler_rules.captures(&event.Type, &event.response.url) {
+                return;
+            }
+            let mut body = match fetch_body() {
+                Ok(body) => body,
+                Err(e) => {
+                    log::debug!("Failed to get the body of {}: {}", event.response.url, e);
+                    return;
+                }
+            };
+            let truncated = truncate_body(&mut body.body, MAX_CAPTURED_BODY_CHARS);
+            let response = CapturedResponse {
+                url: event.response.url,
+                status: event.response.status,
+                mime_type: event.response.mime_type,
+                body: body.body,
+                base64_encoded: body.base_64_encoded,
+                truncated,
+                timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as f64).unwrap_or(0.0),
+            };
+            if let Ok(mut responses) = handler_rules.responses.lock() {
+                push_bounded(&mut responses, response, MAX_CAPTURED_RESPONSES);
+            }
+        }),
+    )
+    .map_err(|e| BrowserError::ChromeError(format!("Failed to install response capture: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_rules_match_xhr_and_fetch() {
+        let rules = CaptureRules::default();
+        assert!(!rules.is_active());
+        assert!(!rules.captures(&ResourceType::Fetch, "https://example.com/api/items"));
+
+        rules.url_patterns.lock().unwrap().push("*/api/*".to_string());
+        assert!(rules.is_active());
+        assert!(rules.captures(&ResourceType::Fetch, "https://example.com/api/items"));
+        assert!(rules.captures(&ResourceType::Xhr, "https://example.com/api/items?page=2"));
+        assert!(!rules.captures(&ResourceType::Fetch, "https://example.com/static/app.js"));
+        // Documents and scripts are not API responses even when the URL matches
+        assert!(!rules.captures(&ResourceType::Document, "https://example.com/api/docs"));
+        assert!(!rules.captures(&ResourceType::Script, "https://example.com/api/client.js"));
+    }
+
+    #[test]
+    fn test_truncate_body() {
+        let mut body = "héllo".to_string();
+        assert!(!truncate_body(&mut body, 5));
+        assert_eq!(body, "héllo");
+        assert!(truncate_body(&mut body, 2));
+        assert_eq!(body, "hé");
+    }
+}
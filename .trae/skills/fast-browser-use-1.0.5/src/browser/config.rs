@@ -1,4 +1,20 @@
-use std::path::PathBuf;
+use crate::dom::SelectorStrategy;
+use std::{collections::HashMap, path::PathBuf};
+
+/// How long navigation blocks before the page is considered loaded, like WebDriver's `pageLoadStrategy`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PageLoadStrategy {
+    /// Wait until the page has loaded and the network is almost idle
+    #[default]
+    Normal,
+
+    /// Wait until the DOM has been parsed (`DOMContentLoaded`), without waiting for images,
+    /// stylesheets or trackers
+    Eager,
+
+    /// Don't wait; return as soon as the navigation has started
+    None,
+}
 
 /// Options for launching a new browser instance
 #[derive(Debug, Clone)]
@@ -18,6 +34,53 @@ pub struct LaunchOptions {
     pub sandbox: bool,
 
     pub launch_timeout: u64,
+
+    /// How many more times to try launching when Chrome starts but can't be connected to
+    pub launch_retries: u32,
+
+    /// Default timeout in milliseconds for navigation and element waits
+    pub default_timeout_ms: u64,
+
+    /// How long navigation waits for the page to load
+    pub page_load_strategy: PageLoadStrategy,
+
+    /// Maximum number of console logs / network errors kept; the oldest are dropped first
+    pub max_log_entries: usize,
+
+    /// Resource types to block from loading (CDP names such as "Image", "Font", "Media")
+    pub block_resources: Vec<String>,
+
+    /// Custom user agent for all tabs
+    pub user_agent: Option<String>,
+
+    /// Extra HTTP headers sent with every request
+    pub extra_headers: HashMap<String, String>,
+
+    /// Locale (e.g. "de-DE") for `Accept-Language` and `Intl`
+    pub locale: Option<String>,
+
+    /// IANA timezone (e.g. "America/New_York")
+    pub timezone: Option<String>,
+
+    /// Additional Chrome command-line flags, appended after the built-in anti-detection flags
+    pub extra_args: Vec<String>,
+
+    /// Hide that Chrome is automated to avoid anti-bot detection: no `--enable-automation` flag,
+    /// the `AutomationControlled` blink feature disabled, and page patches such as removing
+    /// `navigator.webdriver`. Best-effort; dedicated detectors may still notice automation.
+    pub stealth: bool,
+
+    /// How selectors for indexed elements are built in DOM snapshots
+    pub selector_strategy: SelectorStrategy,
+
+    /// Refuse tools that change the page or browser state, allowing only reads such as snapshots
+    pub read_only: bool,
+
+    /// Hosts that navigation is restricted to (e.g. `example.com` or `*.example.com`); `None` allows all
+    pub allowed_domains: Option<Vec<String>>,
+
+    /// Screenshot the page when a tool fails and attach it to the failed result, for debugging
+    pub capture_on_error: bool,
 }
 
 impl Default for LaunchOptions {
@@ -30,6 +93,21 @@ impl Default for LaunchOptions {
             user_data_dir: None,
             sandbox: true,
             launch_timeout: 30000,
+            launch_retries: 2,
+            default_timeout_ms: 30000,
+            page_load_strategy: PageLoadStrategy::default(),
+            max_log_entries: 1000,
+            block_resources: Vec::new(),
+            user_agent: None,
+            extra_headers: HashMap::new(),
+            locale: None,
+            timezone: None,
+            extra_args: Vec::new(),
+            stealth: true,
+            selector_strategy: SelectorStrategy::default(),
+            read_only: false,
+            allowed_domains: None,
+            capture_on_error: false,
         }
     }
 }
@@ -76,6 +154,149 @@ impl LaunchOptions {
         self.launch_timeout = timeout_ms;
         self
     }
+
+    /// Builder method: set the number of launch retries (0 disables retrying)
+    pub fn launch_retries(mut self, retries: u32) -> Self {
+        self.launch_retries = retries;
+        self
+    }
+
+    /// Builder method: set default timeout for navigation and element waits
+    pub fn default_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.default_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Builder method: set how long navigation waits for the page to load
+    pub fn page_load_strategy(mut self, strategy: PageLoadStrategy) -> Self {
+        self.page_load_strategy = strategy;
+        self
+    }
+
+    /// Builder method: set the maximum number of buffered console logs / network errors
+    pub fn max_log_entries(mut self, max: usize) -> Self {
+        self.max_log_entries = max;
+        self
+    }
+
+    /// Builder method: block resource types (e.g. `["Image", "Font"]`) for faster loads
+    pub fn block_resources(mut self, types: Vec<String>) -> Self {
+        self.block_resources = types;
+        self
+    }
+
+    /// Builder method: set a custom user agent
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Builder method: set extra HTTP headers sent with every request
+    pub fn extra_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.extra_headers = headers;
+        self
+    }
+
+    /// Builder method: set the locale (e.g. "de-DE")
+    pub fn locale<S: Into<String>>(mut self, locale: S) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Builder method: set the timezone (e.g. "America/New_York")
+    pub fn timezone<S: Into<String>>(mut self, timezone: S) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Builder method: pass an extra flag to Chrome (e.g. "--disable-gpu"); may be called repeatedly
+    pub fn arg<S: Into<String>>(mut self, flag: S) -> Self {
+        self.extra_args.push(flag.into());
+        self
+    }
+
+    /// Builder method: enable/disable hiding automation from websites (enabled by default)
+    pub fn stealth(mut self, stealth: bool) -> Self {
+        self.stealth = stealth;
+        self
+    }
+
+    /// Builder method: prefer stable attributes such as `data-testid` when building element selectors
+    pub fn selector_strategy(mut self, strategy: SelectorStrategy) -> Self {
+        self.selector_strategy = strategy;
+        self
+    }
+
+    /// Builder method: only allow tools that don't change the page or browser state
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Builder method: only allow navigating to these hosts; `*.example.com` also matches subdomains
+    pub fn allowed_domains(mut self, domains: Vec<String>) -> Self {
+        self.allowed_domains = Some(domains);
+        self
+    }
+
+    /// Builder method: attach a screenshot of the page to the results of failed tools
+    pub fn capture_on_error(mut self, capture: bool) -> Self {
+        self.capture_on_error = capture;
+        self
+    }
+}
+
+/// Find a Chrome/Chromium binary in one of the standard install locations of this OS.
+///
+/// This complements headless_chrome's own detection, which only looks at `$CHROME` and `PATH`
+/// (plus a few app bundles on macOS).
+pub fn detect_chrome_path() -> Option<PathBuf> {
+    chrome_path_candidates(std::env::consts::OS, |name| std::env::var(name).ok()).into_iter().find(|p| p.is_file())
+}
+
+/// Standard install locations for `os` (as in `std::env::consts::OS`), most preferred first
+fn chrome_path_candidates(os: &str, env: impl Fn(&str) -> Option<String>) -> Vec<PathBuf> {
+    match os {
+        "macos" => {
+            let bundles = [
+                "Google Chrome.app/Contents/MacOS/Google Chrome",
+                "Chromium.app/Contents/MacOS/Chromium",
+                "Google Chrome Canary.app/Contents/MacOS/Google Chrome Canary",
+                "Microsoft Edge.app/Contents/MacOS/Microsoft Edge",
+            ];
+            let mut roots = vec!["/Applications".to_string()];
+            roots.extend(env("HOME").map(|home| format!("{}/Applications", home)));
+            roots
+                .iter()
+                .flat_map(|root| bundles.iter().map(move |bundle| PathBuf::from(format!("{}/{}", root, bundle))))
+                .collect()
+        }
+        "windows" => {
+            let installs = [
+                r"Google\Chrome\Application\chrome.exe",
+                r"Chromium\Application\chrome.exe",
+                r"Microsoft\Edge\Application\msedge.exe",
+            ];
+            ["PROGRAMFILES", "PROGRAMFILES(X86)", "LOCALAPPDATA"]
+                .iter()
+                .filter_map(|name| env(name))
+                .flat_map(|root| installs.iter().map(move |install| PathBuf::from(format!(r"{}\{}", root, install))))
+                .collect()
+        }
+        _ => [
+            "/usr/bin/google-chrome-stable",
+            "/usr/bin/google-chrome",
+            "/usr/bin/chromium",
+            "/usr/bin/chromium-browser",
+            "/opt/google/chrome/chrome",
+            "/usr/lib/chromium/chromium",
+            "/snap/bin/chromium",
+            "/var/lib/flatpak/exports/bin/org.chromium.Chromium",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect(),
+    }
 }
 
 /// Options for connecting to an existing browser instance
@@ -86,12 +307,16 @@ pub struct ConnectionOptions {
 
     /// Connection timeout in milliseconds (default: 10000)
     pub timeout: u64,
+
+    /// Session configuration (user agent, headers, locale, timezone, stealth, timeouts, ...) applied
+    /// to the connected browser's tabs; options that only affect starting Chrome are ignored
+    pub session: LaunchOptions,
 }
 
 impl ConnectionOptions {
     /// Create new ConnectionOptions with WebSocket URL
     pub fn new<S: Into<String>>(ws_url: S) -> Self {
-        Self { ws_url: ws_url.into(), timeout: 10000 }
+        Self { ws_url: ws_url.into(), timeout: 10000, session: LaunchOptions::default() }
     }
 
     /// Builder method: set connection timeout
@@ -99,6 +324,12 @@ impl ConnectionOptions {
         self.timeout = timeout_ms;
         self
     }
+
+    /// Builder method: set the session configuration, as for a launched browser
+    pub fn session(mut self, options: LaunchOptions) -> Self {
+        self.session = options;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +344,47 @@ mod tests {
         assert_eq!(opts.window_height, 720);
         assert!(opts.sandbox);
         assert_eq!(opts.launch_timeout, 30000);
+        assert_eq!(opts.launch_retries, 2);
+        assert_eq!(opts.default_timeout_ms, 30000);
+        assert_eq!(opts.max_log_entries, 1000);
+        assert!(opts.block_resources.is_empty());
+        assert!(opts.user_agent.is_none());
+        assert!(opts.extra_headers.is_empty());
+        assert!(opts.locale.is_none());
+        assert!(opts.timezone.is_none());
+        assert!(opts.extra_args.is_empty());
+    }
+
+    #[test]
+    fn test_chrome_path_candidates_macos() {
+        let candidates = chrome_path_candidates("macos", |name| (name == "HOME").then(|| "/Users/me".to_string()));
+        assert_eq!(candidates[0], PathBuf::from("/Applications/Google Chrome.app/Contents/MacOS/Google Chrome"));
+        assert!(
+            candidates
+                .contains(&PathBuf::from("/Users/me/Applications/Google Chrome.app/Contents/MacOS/Google Chrome"))
+        );
+    }
+
+    #[test]
+    fn test_chrome_path_candidates_windows() {
+        let env = |name: &str| match name {
+            "PROGRAMFILES" => Some(r"C:\Program Files".to_string()),
+            "LOCALAPPDATA" => Some(r"C:\Users\me\AppData\Local".to_string()),
+            _ => None,
+        };
+        let candidates = chrome_path_candidates("windows", env);
+        assert_eq!(candidates[0], PathBuf::from(r"C:\Program Files\Google\Chrome\Application\chrome.exe"));
+        assert!(candidates.contains(&PathBuf::from(r"C:\Users\me\AppData\Local\Google\Chrome\Application\chrome.exe")));
+        // Unset variables (here PROGRAMFILES(X86)) contribute nothing
+        assert_eq!(candidates.len(), 6);
+    }
+
+    #[test]
+    fn test_chrome_path_candidates_linux() {
+        let candidates = chrome_path_candidates("linux", |_| None);
+        assert_eq!(candidates[0], PathBuf::from("/usr/bin/google-chrome-stable"));
+        assert!(candidates.contains(&PathBuf::from("/usr/bin/chromium")));
+        assert!(candidates.iter().all(|p| p.is_absolute()));
     }
 
     #[test]
@@ -126,6 +398,62 @@ mod tests {
         assert_eq!(opts.launch_timeout, 60000);
     }
 
+    #[test]
+    fn test_launch_options_default_timeout() {
+        let opts = LaunchOptions::new().default_timeout_ms(5000);
+
+        assert_eq!(opts.default_timeout_ms, 5000);
+        assert_eq!(opts.launch_timeout, 30000);
+    }
+
+    #[test]
+    fn test_launch_options_page_load_strategy() {
+        assert_eq!(LaunchOptions::default().page_load_strategy, PageLoadStrategy::Normal);
+
+        let opts = LaunchOptions::new().page_load_strategy(PageLoadStrategy::Eager);
+        assert_eq!(opts.page_load_strategy, PageLoadStrategy::Eager);
+    }
+
+    #[test]
+    fn test_launch_options_block_resources() {
+        let opts = LaunchOptions::new().block_resources(vec!["Image".to_string(), "Font".to_string()]);
+        assert_eq!(opts.block_resources, vec!["Image", "Font"]);
+
+        let opts = opts.block_resources(Vec::new());
+        assert!(opts.block_resources.is_empty());
+    }
+
+    #[test]
+    fn test_launch_options_user_agent() {
+        let opts = LaunchOptions::new().user_agent("Mozilla/5.0 (X11; Linux x86_64) TestAgent/1.0");
+        assert_eq!(opts.user_agent.as_deref(), Some("Mozilla/5.0 (X11; Linux x86_64) TestAgent/1.0"));
+    }
+
+    #[test]
+    fn test_launch_options_extra_headers() {
+        let headers = HashMap::from([("X-Api-Key".to_string(), "secret".to_string())]);
+        let opts = LaunchOptions::new().extra_headers(headers.clone());
+        assert_eq!(opts.extra_headers, headers);
+    }
+
+    #[test]
+    fn test_launch_options_locale() {
+        let opts = LaunchOptions::new().locale("fr-FR");
+        assert_eq!(opts.locale.as_deref(), Some("fr-FR"));
+    }
+
+    #[test]
+    fn test_launch_options_timezone() {
+        let opts = LaunchOptions::new().timezone("America/New_York");
+        assert_eq!(opts.timezone.as_deref(), Some("America/New_York"));
+    }
+
+    #[test]
+    fn test_launch_options_extra_args() {
+        let opts = LaunchOptions::new().arg("--disable-gpu").arg("--lang=de-DE");
+        assert_eq!(opts.extra_args, vec!["--disable-gpu", "--lang=de-DE"]);
+    }
+
     #[test]
     fn test_connection_options() {
         let opts = ConnectionOptions::new("ws://localhost:9222").timeout(5000);
@@ -1,10 +1,25 @@
+use headless_chrome::protocol::cdp::{Page::events::JavascriptDialogOpeningEventParams,
+                                     Runtime::{ExceptionDetails, RemoteObject}};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConsoleLog {
     pub type_: String,
     pub text: String,
     pub timestamp: f64,
+
+    /// Structured value of each console argument
+    #[serde(default)]
+    pub args: Vec<Value>,
+
+    /// URL of the script that logged the message, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Line number (0-based) in `url` where the message was logged, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_number: Option<u32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -14,3 +29,319 @@ pub struct NetworkError {
     pub method: String,
     pub timestamp: f64,
 }
+
+/// An XHR or fetch response recorded by [`BrowserSession::capture_responses`](crate::BrowserSession::capture_responses)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CapturedResponse {
+    pub url: String,
+
+    /// HTTP status code
+    pub status: u32,
+
+    pub mime_type: String,
+
+    /// Response body as text, or base64 if `base64_encoded`
+    pub body: String,
+
+    pub base64_encoded: bool,
+
+    /// Whether the body was cut to the capture size limit
+    #[serde(default)]
+    pub truncated: bool,
+
+    /// Milliseconds since the Unix epoch when the response finished loading
+    pub timestamp: f64,
+}
+
+/// An uncaught JavaScript exception reported by the page
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsException {
+    /// Exception message (e.g. "TypeError: x is undefined")
+    pub message: String,
+
+    /// Stack trace, one frame per line, if available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack: Option<String>,
+
+    /// URL of the script that threw, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Line number (0-based) where the exception was thrown
+    pub line_number: u32,
+
+    /// Column number (0-based) where the exception was thrown
+    pub column_number: u32,
+
+    pub timestamp: f64,
+}
+
+impl JsException {
+    /// Build an entry from a `Runtime.exceptionThrown` event
+    pub(crate) fn from_details(details: &ExceptionDetails, timestamp: f64) -> Self {
+        // The exception's description is "Name: message\n    at frame..." for Error objects;
+        // thrown primitives only have the generic "Uncaught" text plus their value
+        let description = details.exception.as_ref().and_then(|e| e.description.clone());
+        let message = match (&description, &details.exception) {
+            (Some(description), _) => description.lines().next().unwrap_or_default().to_string(),
+            (None, Some(exception)) => format!("{} {}", details.text, remote_object_to_value(exception)),
+            (None, None) => details.text.clone(),
+        };
+
+        let stack = details.stack_trace.as_ref().filter(|trace| !trace.call_frames.is_empty()).map(|trace| {
+            trace
+                .call_frames
+                .iter()
+                .map(|frame| {
+                    let function_name =
+                        if frame.function_name.is_empty() { "<anonymous>" } else { frame.function_name.as_str() };
+                    format!("at {} ({}:{}:{})", function_name, frame.url, frame.line_number, frame.column_number)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        let url = details
+            .url
+            .clone()
+            .or_else(|| details.stack_trace.as_ref().and_then(|t| t.call_frames.first()).map(|f| f.url.clone()))
+            .filter(|url| !url.is_empty());
+
+        Self { message, stack, url, line_number: details.line_number, column_number: details.column_number, timestamp }
+    }
+}
+
+/// A JavaScript dialog (`alert`, `confirm`, `prompt` or `beforeunload`) opened by the page
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JavaScriptDialog {
+    /// Dialog type: "alert", "confirm", "prompt" or "beforeunload"
+    pub type_: String,
+
+    /// Message shown in the dialog
+    pub message: String,
+
+    /// URL of the frame that opened the dialog
+    pub url: String,
+
+    /// Default value of a `prompt` dialog
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_prompt: Option<String>,
+
+    /// Target ID of the tab showing the dialog
+    pub target_id: String,
+
+    /// Whether the dialog was accepted or dismissed; `None` while it is still open
+    #[serde(default)]
+    pub accepted: Option<bool>,
+}
+
+impl JavaScriptDialog {
+    /// Build an entry from a `Page.javascriptDialogOpening` event
+    pub(crate) fn from_event(params: &JavascriptDialogOpeningEventParams, target_id: &str) -> Self {
+        let type_ = serde_json::to_value(&params.Type)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| format!("{:?}", params.Type).to_lowercase());
+        Self {
+            type_,
+            message: params.message.clone(),
+            url: params.url.clone(),
+            default_prompt: params.default_prompt.clone(),
+            target_id: target_id.to_string(),
+            accepted: None,
+        }
+    }
+}
+
+/// How JavaScript dialogs are answered as soon as they open
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct DialogPolicy {
+    /// Press OK (`true`) or Cancel (`false`)
+    pub(crate) accept: bool,
+
+    /// Text entered into `prompt` dialogs when accepting
+    pub(crate) prompt_text: Option<String>,
+}
+
+/// Convert a CDP remote object into the closest JSON value.
+///
+/// Primitives are passed by value; objects are only available as a preview, which is
+/// turned into a JSON object of property name to (stringified) value.
+pub(crate) fn remote_object_to_value(object: &RemoteObject) -> Value {
+    if let Some(value) = &object.value {
+        return value.clone();
+    }
+
+    if let Some(preview) = &object.preview {
+        let properties = preview
+            .properties
+            .iter()
+            .map(|p| (p.name.clone(), p.value.clone().map(Value::String).unwrap_or(Value::Null)))
+            .collect::<serde_json::Map<_, _>>();
+        return Value::Object(properties);
+    }
+
+    object.unserializable_value.clone().or_else(|| object.description.clone()).map(Value::String).unwrap_or(Value::Null)
+}
+
+/// Push an entry onto a capture buffer, dropping the oldest entries once `max` is reached
+pub(crate) fn push_bounded<T>(buffer: &mut Vec<T>, entry: T, max: usize) {
+    if max == 0 {
+        return;
+    }
+    if buffer.len() >= max {
+        let overflow = buffer.len() + 1 - max;
+        buffer.drain(..overflow);
+    }
+    buffer.push(entry);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_bounded_drops_oldest() {
+        let mut buffer = Vec::new();
+        for i in 0..5 {
+            push_bounded(&mut buffer, i, 3);
+        }
+
+        assert_eq!(buffer, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_push_bounded_under_cap() {
+        let mut buffer = vec![1];
+        push_bounded(&mut buffer, 2, 3);
+
+        assert_eq!(buffer, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_remote_object_to_value_primitive() {
+        let object: RemoteObject = serde_json::from_value(serde_json::json!({
+            "type": "number",
+            "value": 42
+        }))
+        .unwrap();
+
+        assert_eq!(remote_object_to_value(&object), serde_json::json!(42));
+    }
+
+    #[test]
+    fn test_remote_object_to_value_object_preview() {
+        let object: RemoteObject = serde_json::from_value(serde_json::json!({
+            "type": "object",
+            "className": "Object",
+            "description": "Object",
+            "objectId": "1",
+            "preview": {
+                "type": "object",
+                "overflow": false,
+                "properties": [
+                    { "name": "id", "type": "number", "value": "7" },
+                    { "name": "name", "type": "string", "value": "alice" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(remote_object_to_value(&object), serde_json::json!({ "id": "7", "name": "alice" }));
+    }
+
+    #[test]
+    fn test_js_exception_from_details() {
+        let details: ExceptionDetails = serde_json::from_value(serde_json::json!({
+            "exceptionId": 1,
+            "text": "Uncaught",
+            "lineNumber": 3,
+            "columnNumber": 12,
+            "url": "https://example.com/app.js",
+            "stackTrace": {
+                "callFrames": [
+                    {
+                        "functionName": "render",
+                        "scriptId": "5",
+                        "url": "https://example.com/app.js",
+                        "lineNumber": 3,
+                        "columnNumber": 12
+                    },
+                    {
+                        "functionName": "",
+                        "scriptId": "5",
+                        "url": "https://example.com/app.js",
+                        "lineNumber": 10,
+                        "columnNumber": 1
+                    }
+                ]
+            },
+            "exception": {
+                "type": "object",
+                "subtype": "error",
+                "className": "TypeError",
+                "description": "TypeError: Cannot read properties of undefined (reading 'x')\n    at render (app.js:4:13)"
+            }
+        }))
+        .unwrap();
+
+        let exception = JsException::from_details(&details, 1000.0);
+
+        assert_eq!(exception.message, "TypeError: Cannot read properties of undefined (reading 'x')");
+        assert_eq!(exception.url.as_deref(), Some("https://example.com/app.js"));
+        assert_eq!(exception.line_number, 3);
+        assert_eq!(
+            exception.stack.as_deref(),
+            Some("at render (https://example.com/app.js:3:12)\nat <anonymous> (https://example.com/app.js:10:1)")
+        );
+    }
+
+    #[test]
+    fn test_js_exception_from_thrown_primitive() {
+        let details: ExceptionDetails = serde_json::from_value(serde_json::json!({
+            "exceptionId": 2,
+            "text": "Uncaught",
+            "lineNumber": 0,
+            "columnNumber": 0,
+            "exception": { "type": "string", "value": "boom" }
+        }))
+        .unwrap();
+
+        let exception = JsException::from_details(&details, 0.0);
+
+        assert_eq!(exception.message, "Uncaught \"boom\"");
+        assert!(exception.stack.is_none());
+        assert!(exception.url.is_none());
+    }
+
+    #[test]
+    fn test_remote_object_to_value_unserializable() {
+        let object: RemoteObject = serde_json::from_value(serde_json::json!({
+            "type": "number",
+            "unserializableValue": "NaN"
+        }))
+        .unwrap();
+
+        assert_eq!(remote_object_to_value(&object), serde_json::json!("NaN"));
+    }
+
+    #[test]
+    fn test_dialog_from_event() {
+        let params: JavascriptDialogOpeningEventParams = serde_json::from_value(serde_json::json!({
+            "url": "https://example.com/",
+            "frameId": "frame-1",
+            "message": "Your name?",
+            "type": "prompt",
+            "hasBrowserHandler": false,
+            "defaultPrompt": "guest"
+        }))
+        .unwrap();
+
+        let dialog = JavaScriptDialog::from_event(&params, "target-1");
+        assert_eq!(dialog.type_, "prompt");
+        assert_eq!(dialog.message, "Your name?");
+        assert_eq!(dialog.default_prompt.as_deref(), Some("guest"));
+        assert_eq!(dialog.target_id, "target-1");
+        assert_eq!(dialog.accepted, None);
+    }
+}
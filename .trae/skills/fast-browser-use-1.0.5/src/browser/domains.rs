@@ -0,0 +1,73 @@
+//! Restricting navigation to an allowlist of domains
+
+use crate::error::{BrowserError, Result};
+use url::Url;
+
+/// Whether `host` matches an allowlist `pattern`: either an exact host (`example.com`) or a
+/// wildcard (`*.example.com`) that matches the domain itself and all of its subdomains.
+/// Hosts are compared case-insensitively, ignoring a trailing dot.
+pub(crate) fn host_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim().trim_end_matches('.').to_ascii_lowercase();
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host == domain || host.strip_suffix(domain).is_some_and(|sub| sub.ends_with('.')),
+        None => host == pattern,
+    }
+}
+
+/// Fail unless `url` points to a host in `allowed`. `about:` URLs (e.g. a blank tab) are always
+/// allowed; other URLs without a host, such as `data:` or `file:` URLs, are rejected.
+pub(crate) fn check_url_allowed(url: &str, allowed: &[String]) -> Result<()> {
+    let parsed = Url::parse(url).map_err(|e| BrowserError::NavigationFailed(format!("Invalid URL {}: {}", url, e)))?;
+    if parsed.scheme() == "about" {
+        return Ok(());
+    }
+
+    match parsed.host_str() {
+        Some(host) if allowed.iter().any(|pattern| host_matches(pattern, host)) => Ok(()),
+        Some(host) => Err(BrowserError::NavigationFailed(format!(
+            "Navigation to {} blocked: '{}' is not in the allowed domains ({})",
+            url,
+            host,
+            allowed.join(", ")
+        ))),
+        None => Err(BrowserError::NavigationFailed(format!(
+            "Navigation to {} blocked: only hosts in the allowed domains ({}) can be opened",
+            url,
+            allowed.join(", ")
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_matches() {
+        assert!(host_matches("example.com", "example.com"));
+        assert!(host_matches("Example.COM", "example.com."));
+        assert!(!host_matches("example.com", "www.example.com"));
+
+        assert!(host_matches("*.example.com", "example.com"));
+        assert!(host_matches("*.example.com", "docs.example.com"));
+        assert!(host_matches("*.example.com", "a.b.example.com"));
+        assert!(!host_matches("*.example.com", "badexample.com"));
+        assert!(!host_matches("*.example.com", "example.com.evil.net"));
+    }
+
+    #[test]
+    fn test_check_url_allowed() {
+        let allowed = vec!["*.example.com".to_string(), "localhost".to_string()];
+
+        assert!(check_url_allowed("https://www.example.com/path?q=1", &allowed).is_ok());
+        assert!(check_url_allowed("http://localhost:8080/", &allowed).is_ok());
+        assert!(check_url_allowed("about:blank", &allowed).is_ok());
+
+        // Userinfo doesn't count as the host
+        let err = check_url_allowed("https://www.example.com@evil.net/", &allowed).unwrap_err();
+        assert!(err.to_string().contains("'evil.net' is not in the allowed domains"));
+        assert!(check_url_allowed("data:text/html,<h1>hi</h1>", &allowed).is_err());
+        assert!(check_url_allowed("not a url", &allowed).is_err());
+    }
+}
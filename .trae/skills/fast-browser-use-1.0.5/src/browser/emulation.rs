@@ -0,0 +1,134 @@
+//! Per-session overrides that every tab inherits, including tabs opened later
+
+use crate::error::{BrowserError, Result};
+use headless_chrome::{Tab,
+                      protocol::cdp::Emulation::{SetEmulatedMedia, SetLocaleOverride, SetTimezoneOverride}};
+use std::collections::HashMap;
+
+/// Overrides applied to each tab of a session
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TabOverrides {
+    /// Custom `User-Agent` (and `navigator.userAgent`)
+    pub(crate) user_agent: Option<String>,
+
+    /// Headers added to every request
+    pub(crate) extra_headers: HashMap<String, String>,
+
+    /// Locale (e.g. "de-DE") for `Accept-Language` and `Intl`
+    pub(crate) locale: Option<String>,
+
+    /// IANA timezone (e.g. "America/New_York")
+    pub(crate) timezone: Option<String>,
+
+    /// CSS media type (e.g. "print") used instead of "screen"
+    pub(crate) media: Option<String>,
+}
+
+impl TabOverrides {
+    /// Apply every override that is set to `tab`
+    pub(crate) fn apply(&self, tab: &Tab) -> Result<()> {
+        if let Some(user_agent) = &self.user_agent {
+            tab.set_user_agent(user_agent, None, None)
+                .map_err(|e| BrowserError::ChromeError(format!("Failed to set user agent: {}", e)))?;
+        }
+        if let Some(locale) = &self.locale {
+            apply_locale(tab, locale)?;
+        }
+        if let Some(timezone) = &self.timezone {
+            apply_timezone(tab, timezone)?;
+        }
+        if let Some(media) = &self.media {
+            apply_media(tab, media)?;
+        }
+        if !self.headers().is_empty() {
+            self.apply_headers(tab)?;
+        }
+        Ok(())
+    }
+
+    /// Headers to send with every request: the extra headers plus `Accept-Language` for the locale,
+    /// which takes precedence over an `Accept-Language` among the extra headers
+    pub(crate) fn headers(&self) -> HashMap<&str, &str> {
+        let mut headers: HashMap<&str, &str> = self
+            .extra_headers
+            .iter()
+            .filter(|(name, _)| self.locale.is_none() || !name.eq_ignore_ascii_case("Accept-Language"))
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        if let Some(locale) = &self.locale {
+            headers.insert("Accept-Language", locale);
+        }
+        headers
+    }
+
+    /// Replace the tab's extra headers with [`headers`](Self::headers); an empty map clears them
+    pub(crate) fn apply_headers(&self, tab: &Tab) -> Result<()> {
+        tab.set_extra_http_headers(self.headers())
+            .map_err(|e| BrowserError::ChromeError(format!("Failed to set extra HTTP headers: {}", e)))
+    }
+}
+
+/// Override the locale used by `Intl` and locale-dependent formatting
+pub(crate) fn apply_locale(tab: &Tab, locale: &str) -> Result<()> {
+    tab.call_method(SetLocaleOverride { locale: Some(locale.to_string()) })
+        .map(|_| ())
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to set locale '{}': {}", locale, e)))
+}
+
+/// Override the timezone seen by the page; unknown timezone IDs are reported as invalid arguments
+pub(crate) fn apply_timezone(tab: &Tab, timezone: &str) -> Result<()> {
+    tab.call_method(SetTimezoneOverride { timezone_id: timezone.to_string() }).map(|_| ()).map_err(|e| {
+        let reason = e.to_string();
+        if reason.to_lowercase().contains("invalid timezone") {
+            BrowserError::InvalidArgument(format!("Invalid timezone '{}': {}", timezone, reason))
+        } else {
+            BrowserError::ChromeError(format!("Failed to set timezone '{}': {}", timezone, reason))
+        }
+    })
+}
+
+/// Emulate a CSS media type such as "print"; an empty string restores the default screen media
+pub(crate) fn apply_media(tab: &Tab, media: &str) -> Result<()> {
+    tab.call_method(SetEmulatedMedia { media: Some(media.to_string()), features: None })
+        .map(|_| ())
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to emulate media '{}': {}", media, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_pass_through() {
+        let overrides = TabOverrides {
+            extra_headers: HashMap::from([
+                ("Authorization".to_string(), "Bearer token".to_string()),
+                ("X-Tenant".to_string(), "acme".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        let headers = overrides.headers();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get("Authorization"), Some(&"Bearer token"));
+        assert_eq!(headers.get("X-Tenant"), Some(&"acme"));
+        assert!(TabOverrides::default().headers().is_empty());
+    }
+
+    #[test]
+    fn test_headers_include_locale() {
+        let overrides = TabOverrides {
+            extra_headers: HashMap::from([
+                ("accept-language".to_string(), "en-US".to_string()),
+                ("X-Tenant".to_string(), "acme".to_string()),
+            ]),
+            locale: Some("de-DE".to_string()),
+            ..Default::default()
+        };
+
+        let headers = overrides.headers();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get("Accept-Language"), Some(&"de-DE"));
+        assert_eq!(headers.get("X-Tenant"), Some(&"acme"));
+    }
+}
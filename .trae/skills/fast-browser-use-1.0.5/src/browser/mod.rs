@@ -3,12 +3,19 @@
 //! This module provides functionality for launching and managing Chrome/Chromium browser instances.
 //! It includes configuration options, session management, and browser lifecycle control.
 
+mod blocking;
+mod capture;
 pub mod config;
 pub mod debug;
+mod domains;
+mod emulation;
+pub mod pool;
 pub mod session;
+mod stealth;
 
-pub use config::{ConnectionOptions, LaunchOptions};
-pub use session::BrowserSession;
+pub use config::{ConnectionOptions, LaunchOptions, PageLoadStrategy};
+pub use pool::BrowserPool;
+pub use session::{BrowserSession, kill_browser_process};
 
 use crate::error::Result;
 
@@ -0,0 +1,242 @@
+//! Bounded pool of reusable browser sessions
+//!
+//! Launching Chrome is expensive, so servers that handle many connections can share a fixed
+//! number of sessions instead of launching one per connection.
+
+use crate::{browser::{BrowserSession, LaunchOptions},
+            error::{BrowserError, Result}};
+use std::{sync::{Condvar, Mutex, MutexGuard},
+          time::{Duration, Instant}};
+
+/// Items of a [`Pool`]: the idle ones and how many are checked out
+struct Slots<T> {
+    idle: Vec<T>,
+    checked_out: usize,
+}
+
+/// Bounded checkout/checkin pool; items are created lazily up to `size`
+struct Pool<T> {
+    size: usize,
+    slots: Mutex<Slots<T>>,
+    available: Condvar,
+}
+
+impl<T> Pool<T> {
+    fn new(size: usize) -> Self {
+        Self { size, slots: Mutex::new(Slots { idle: Vec::new(), checked_out: 0 }), available: Condvar::new() }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Slots<T>> {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Check out an idle item that passes `healthy`, or `create` one while below the size limit,
+    /// waiting for a checkin otherwise. Unhealthy idle items are dropped and replaced.
+    fn acquire<C, H>(&self, timeout: Option<Duration>, create: C, healthy: H) -> Result<T>
+    where
+        C: Fn() -> Result<T>,
+        H: Fn(&T) -> bool,
+    {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut slots = self.lock();
+        loop {
+            if let Some(item) = slots.idle.pop() {
+                slots.checked_out += 1;
+                drop(slots);
+                if healthy(&item) {
+                    return Ok(item);
+                }
+                log::warn!("Dropping unhealthy pooled browser session");
+                drop(item);
+                return self.create_checked_out(create);
+            }
+
+            if slots.checked_out < self.size {
+                slots.checked_out += 1;
+                drop(slots);
+                return self.create_checked_out(create);
+            }
+
+            slots = match deadline {
+                None => self.available.wait(slots).unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        return Err(BrowserError::Timeout(format!(
+                            "No pooled browser session became available within {} ms",
+                            timeout.unwrap_or_default().as_millis()
+                        )));
+                    }
+                    self.available.wait_timeout(slots, remaining).unwrap_or_else(|e| e.into_inner()).0
+                }
+            };
+        }
+    }
+
+    /// Create an item for a slot that is already counted as checked out, freeing the slot again
+    /// if creation fails
+    fn create_checked_out<C>(&self, create: C) -> Result<T>
+    where
+        C: Fn() -> Result<T>,
+    {
+        create().inspect_err(|_| self.discard())
+    }
+
+    /// Check an item back in
+    fn release(&self, item: T) {
+        let mut slots = self.lock();
+        slots.checked_out = slots.checked_out.saturating_sub(1);
+        slots.idle.push(item);
+        drop(slots);
+        self.available.notify_one();
+    }
+
+    /// Give up the slot of a checked out item that won't be returned
+    fn discard(&self) {
+        let mut slots = self.lock();
+        slots.checked_out = slots.checked_out.saturating_sub(1);
+        drop(slots);
+        self.available.notify_one();
+    }
+
+    fn idle_count(&self) -> usize {
+        self.lock().idle.len()
+    }
+
+    /// Remove and return all idle items
+    fn drain_idle(&self) -> Vec<T> {
+        std::mem::take(&mut self.lock().idle)
+    }
+}
+
+/// A bounded set of browser sessions shared between callers with checkout/checkin semantics.
+///
+/// Sessions are launched on demand with the pool's launch options, up to `size` at a time.
+/// [`acquire`](Self::acquire) blocks while all of them are checked out, and health-checks an
+/// idle session before handing it out, relaunching it if the browser died.
+pub struct BrowserPool {
+    options: LaunchOptions,
+    pool: Pool<BrowserSession>,
+}
+
+impl BrowserPool {
+    /// Create a pool of at most `size` sessions launched with `options`. No browser is launched
+    /// until the first [`acquire`](Self::acquire).
+    pub fn new(size: usize, options: LaunchOptions) -> Result<Self> {
+        if size == 0 {
+            return Err(BrowserError::InvalidArgument("Browser pool size must be at least 1".to_string()));
+        }
+        Ok(Self { options, pool: Pool::new(size) })
+    }
+
+    /// Check out a session, waiting as long as it takes for one to become available
+    pub fn acquire(&self) -> Result<BrowserSession> {
+        self.pool.acquire(None, || BrowserSession::launch(self.options.clone()), BrowserSession::is_alive)
+    }
+
+    /// Check out a session, failing with [`BrowserError::Timeout`] if none became available in time
+    pub fn acquire_timeout(&self, timeout: Duration) -> Result<BrowserSession> {
+        self.pool.acquire(Some(timeout), || BrowserSession::launch(self.options.clone()), BrowserSession::is_alive)
+    }
+
+    /// Check a session back in so another caller can reuse it
+    pub fn release(&self, session: BrowserSession) {
+        self.pool.release(session);
+    }
+
+    /// Free the slot of a checked out session that won't be released (e.g. it was dropped),
+    /// so a new one can be launched in its place
+    pub fn discard(&self) {
+        self.pool.discard();
+    }
+
+    /// Maximum number of sessions
+    pub fn size(&self) -> usize {
+        self.pool.size
+    }
+
+    /// Number of launched sessions currently waiting to be checked out
+    pub fn idle_count(&self) -> usize {
+        self.pool.idle_count()
+    }
+
+    /// Launch options used for the pool's sessions
+    pub fn options(&self) -> &LaunchOptions {
+        &self.options
+    }
+
+    /// Quit the browsers of all idle sessions. Checked out sessions are not affected.
+    pub fn close_idle(&self) {
+        for session in self.pool.drain_idle() {
+            if let Err(e) = session.quit() {
+                log::warn!("Failed to quit pooled browser: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::{Arc,
+                     atomic::{AtomicUsize, Ordering}},
+              thread};
+
+    #[test]
+    fn test_pool_bounds_checkouts_under_contention() {
+        let pool = Arc::new(Pool::new(2));
+        let created = Arc::new(AtomicUsize::new(0));
+        let in_use = Arc::new(AtomicUsize::new(0));
+        let max_in_use = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (pool, created, in_use, max_in_use) =
+                    (pool.clone(), created.clone(), in_use.clone(), max_in_use.clone());
+                thread::spawn(move || {
+                    for _ in 0..5 {
+                        let item = pool
+                            .acquire(None, || Ok(created.fetch_add(1, Ordering::SeqCst)), |_| true)
+                            .expect("Failed to acquire");
+                        let now = in_use.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_in_use.fetch_max(now, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(2));
+                        in_use.fetch_sub(1, Ordering::SeqCst);
+                        pool.release(item);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(max_in_use.load(Ordering::SeqCst) <= 2);
+        // Items are reused rather than created per checkout
+        assert_eq!(created.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.idle_count(), 2);
+    }
+
+    #[test]
+    fn test_pool_timeout_and_unhealthy_replacement() {
+        let pool = Pool::new(1);
+        let item = pool.acquire(None, || Ok(1), |_| true).unwrap();
+
+        let err = pool.acquire(Some(Duration::from_millis(20)), || Ok(2), |_| true).unwrap_err();
+        assert!(matches!(err, BrowserError::Timeout(_)));
+
+        // An unhealthy idle item is replaced by a new one
+        pool.release(item);
+        assert_eq!(pool.acquire(None, || Ok(3), |item| *item != 1).unwrap(), 3);
+
+        // A failed creation frees its slot again
+        pool.discard();
+        assert!(pool.acquire(None, || Err(BrowserError::LaunchFailed("no chrome".to_string())), |_| true).is_err());
+        assert_eq!(pool.acquire(Some(Duration::from_millis(20)), || Ok(4), |_| true).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_browser_pool_rejects_zero_size() {
+        assert!(matches!(BrowserPool::new(0, LaunchOptions::default()), Err(BrowserError::InvalidArgument(_))));
+    }
+}
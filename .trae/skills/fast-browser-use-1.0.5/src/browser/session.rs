@@ -1,9 +1,109 @@
-use crate::{browser::{config::{ConnectionOptions, LaunchOptions}, debug::{ConsoleLog, NetworkError}},
-            dom::DomTree,
+use crate::{browser::{blocking::{BlockRules, install_request_blocking, sync_fetch},
+                      capture::{CaptureRules, install_response_capture},
+                      config::{ConnectionOptions, LaunchOptions, PageLoadStrategy, detect_chrome_path},
+                      debug::{CapturedResponse, ConsoleLog, DialogPolicy, JavaScriptDialog, JsException,
+                              NetworkError, push_bounded, remote_object_to_value},
+                      domains::check_url_allowed,
+                      emulation::{TabOverrides, apply_locale, apply_media, apply_timezone},
+                      stealth::install_stealth_script},
+            dom::{DomTree, ExtractOptions, SelectorStrategy},
             error::{BrowserError, Result},
             tools::{ToolContext, ToolRegistry, cookies::CookieParam}};
-use headless_chrome::{Browser, Tab, protocol::cdp::{Network::CookieParam as CdpCookieParam, types::Event}};
-use std::{ffi::OsStr, sync::{Arc, Mutex}, time::Duration};
+use headless_chrome::{Browser, Tab,
+                      protocol::cdp::{Browser::GetVersionReturnObject,
+                                      DOM, Emulation,
+                                      Network::{self, CookieParam as CdpCookieParam},
+                                      Page,
+                                      Runtime::{self, RemoteObject, RemoteObjectSubtype, RemoteObjectType},
+                                      types::{Event, Method}},
+                      types::PrintToPdfOptions};
+use std::{collections::{HashMap, HashSet}, ffi::OsStr, path::Path, sync::{Arc, Mutex, mpsc}, thread,
+          time::{Duration, Instant}};
+
+/// How often go_back/go_forward check whether the history navigation has happened
+const HISTORY_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Full size of the rendered document, used to size the viewport for full-page captures
+const PAGE_SIZE_JS: &str = "JSON.stringify({ \
+    width: Math.max(document.documentElement.scrollWidth, document.body ? document.body.scrollWidth : 0, window.innerWidth), \
+    height: Math.max(document.documentElement.scrollHeight, document.body ? document.body.scrollHeight : 0, window.innerHeight) \
+})";
+
+/// How often wait_for_new_target checks for new tabs
+const NEW_TARGET_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long is_alive waits for the browser to answer
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How long quit waits for the browser process to exit after killing it
+const QUIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Delay between checks whether the browser process has exited
+const QUIT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Delay before the first launch retry; doubles with each further attempt
+const LAUNCH_RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// How often wait_for_network_idle re-checks the page
+const NETWORK_IDLE_POLL_MS: u64 = 100;
+
+/// Name of the isolated world that [`evaluate_in_isolated_world`] runs scripts in
+const ISOLATED_WORLD_NAME: &str = "browser_use";
+
+/// Truthy once the document has been parsed (after `DOMContentLoaded`)
+const DOM_READY_JS: &str = "document.readyState !== 'loading'";
+
+/// Evaluates __EXPR__, reading exceptions as a falsy result. Syntax errors (also thrown by
+/// `querySelector` for invalid selectors) won't go away by polling, so they are rethrown.
+const WAIT_FOR_FUNCTION_JS: &str = r#"(function() {
+    try {
+        return (__EXPR__);
+    } catch (e) {
+        if (e && e.name === 'SyntaxError') throw e;
+        return undefined;
+    }
+})()"#;
+
+/// Number of resource entries of the page once it has loaded, -1 while it is still loading
+const NETWORK_RESOURCES_JS: &str =
+    "document.readyState === 'complete' ? performance.getEntriesByType('resource').length : -1";
+
+/// Truthy once no DOM mutation was observed for __QUIET_MS__ ms; the MutationObserver is
+/// installed on the first evaluation and disconnected once the page is stable
+const DOM_STABLE_JS: &str = r#"(() => {
+    const now = performance.now();
+    let state = window.__browserUseDomStable;
+    if (!state) {
+        state = window.__browserUseDomStable = { since: now };
+        state.observer = new MutationObserver(() => { state.since = performance.now(); });
+        state.observer.observe(document, { subtree: true, childList: true, attributes: true, characterData: true });
+    }
+    if (now - state.since < __QUIET_MS__) {
+        return false;
+    }
+    state.observer.disconnect();
+    delete window.__browserUseDomStable;
+    return true;
+})()"#;
+
+/// Disconnects the observer left behind by [`DOM_STABLE_JS`] when the wait timed out
+const DOM_STABLE_CLEANUP_JS: &str = r#"(() => {
+    const state = window.__browserUseDomStable;
+    if (state) {
+        state.observer.disconnect();
+        delete window.__browserUseDomStable;
+    }
+})()"#;
+
+/// `Accessibility.getFullAXTree` returning the raw JSON, since the generated AXNode types
+/// can reject property names and values added in newer Chrome versions
+#[derive(Debug, serde::Serialize)]
+struct GetFullAxTreeRaw {}
+
+impl Method for GetFullAxTreeRaw {
+    const NAME: &'static str = "Accessibility.getFullAXTree";
+    type ReturnObject = serde_json::Value;
+}
 
 /// Wrapper for Tab and Element to maintain proper lifetime relationships
 pub struct TabElement<'a> {
@@ -24,23 +124,79 @@ pub struct BrowserSession {
 
     /// Captured network errors
     network_errors: Arc<Mutex<Vec<NetworkError>>>,
+
+    /// Captured uncaught JavaScript exceptions
+    js_exceptions: Arc<Mutex<Vec<JsException>>>,
+
+    /// Default timeout for navigation and element waits
+    default_timeout: Duration,
+
+    /// How long navigation waits for the page to load
+    page_load_strategy: PageLoadStrategy,
+
+    /// How selectors for indexed elements are built in DOM snapshots
+    selector_strategy: SelectorStrategy,
+
+    /// Maximum number of console logs / network errors kept in memory
+    max_log_entries: usize,
+
+    /// Whether mutating tools are refused
+    read_only: bool,
+
+    /// Hosts that navigation is restricted to; `None` allows all
+    allowed_domains: Option<Vec<String>>,
+
+    /// Whether tabs get the stealth patches that hide automation from page scripts
+    stealth: bool,
+
+    /// Whether failed tools get a screenshot of the page attached
+    capture_on_error: bool,
+
+    /// Resource types and URL patterns whose requests are failed before they are sent
+    block_rules: BlockRules,
+
+    /// URL patterns of the XHR/fetch responses to record, and the recorded responses
+    capture_rules: CaptureRules,
+
+    /// Overrides (user agent, extra headers, locale, timezone, media) applied to every tab
+    overrides: Arc<Mutex<TabOverrides>>,
+
+    /// JavaScript dialogs opened by the page, most recent last
+    dialogs: Arc<Mutex<Vec<JavaScriptDialog>>>,
+
+    /// How dialogs are answered when they open; `None` leaves them open
+    dialog_policy: Arc<Mutex<Option<DialogPolicy>>>,
+
+    /// Target IDs of the tabs whose listeners are set up; any other tab is new (e.g. a popup)
+    adopted_targets: Mutex<HashSet<String>>,
+
+    /// Last mouse position set by a tool in each tab, by target ID
+    mouse_positions: Mutex<HashMap<String, (f64, f64)>>,
+
+    /// Identifiers of the scripts that re-add persistent injected styles, by style ID
+    style_scripts: Mutex<HashMap<String, String>>,
+
+    /// Configuration the session was launched or connected with
+    config: LaunchOptions,
 }
 
 impl BrowserSession {
     /// Helper to setup event listeners on a tab
-    fn setup_tab_listeners(
-        tab: &Arc<Tab>,
-        console_logs: Arc<Mutex<Vec<ConsoleLog>>>,
-        network_errors: Arc<Mutex<Vec<NetworkError>>>
-    ) -> Result<()> {
+    fn setup_tab_listeners(&self, tab: &Arc<Tab>) -> Result<()> {
         // Enable domains
         tab.enable_log().ok(); 
         tab.enable_debugger().ok(); 
         tab.enable_runtime().ok();
         // tab.enable_network().ok(); // Not available directly
         
-        let logs = console_logs.clone();
-        let errors = network_errors.clone();
+        let logs = self.console_logs.clone();
+        let errors = self.network_errors.clone();
+        let exceptions = self.js_exceptions.clone();
+        let dialogs = self.dialogs.clone();
+        let dialog_policy = self.dialog_policy.clone();
+        let max_log_entries = self.max_log_entries;
+        // Weak so the listener stored on the tab doesn't keep the tab alive
+        let dialog_tab = Arc::downgrade(tab);
         
         let _ = tab.add_event_listener(Arc::new(move |event: &Event| {
             match event {
@@ -50,36 +206,90 @@ impl BrowserSession {
                         .collect::<Vec<_>>()
                         .join(" ");
                         
+                    let args = e.params.args.iter().map(remote_object_to_value).collect();
+                    let frame = e.params.stack_trace.as_ref().and_then(|trace| trace.call_frames.first());
+
                     if let Ok(mut logs_guard) = logs.lock() {
-                        logs_guard.push(ConsoleLog {
+                        push_bounded(&mut logs_guard, ConsoleLog {
                             type_: format!("{:?}", e.params.Type),
                             text,
                             timestamp: e.params.timestamp,
-                        });
+                            args,
+                            url: frame.map(|f| f.url.clone()).filter(|url| !url.is_empty()),
+                            line_number: frame.map(|f| f.line_number),
+                        }, max_log_entries);
                     }
                 },
                 Event::LogEntryAdded(e) => {
                      if let Ok(mut logs_guard) = logs.lock() {
-                        logs_guard.push(ConsoleLog {
+                        push_bounded(&mut logs_guard, ConsoleLog {
                             type_: format!("{:?}", e.params.entry.level),
                             text: e.params.entry.text.clone(),
                             timestamp: e.params.entry.timestamp,
-                        });
+                            args: e.params.entry.args.iter().flatten().map(remote_object_to_value).collect(),
+                            url: e.params.entry.url.clone(),
+                            line_number: e.params.entry.line_number,
+                        }, max_log_entries);
                     }
                 },
                 Event::NetworkLoadingFailed(e) => {
                      if let Ok(mut errors_guard) = errors.lock() {
-                        errors_guard.push(NetworkError {
+                        push_bounded(&mut errors_guard, NetworkError {
                             url: "unknown".to_string(), // URL not directly available in LoadingFailed without tracking requests
                             error_text: e.params.error_text.clone(),
                             method: "unknown".to_string(),
                             timestamp: e.params.timestamp,
-                        });
+                        }, max_log_entries);
+                    }
+                },
+                Event::RuntimeExceptionThrown(e) => {
+                    if let Ok(mut exceptions_guard) = exceptions.lock() {
+                        push_bounded(
+                            &mut exceptions_guard,
+                            JsException::from_details(&e.params.exception_details, e.params.timestamp),
+                            max_log_entries,
+                        );
+                    }
+                },
+                Event::PageJavascriptDialogOpening(e) => {
+                    let Some(tab) = dialog_tab.upgrade() else { return };
+                    let mut dialog = JavaScriptDialog::from_event(&e.params, tab.get_target_id());
+                    let policy = dialog_policy.lock().ok().and_then(|policy| policy.clone());
+                    if let Some(policy) = policy {
+                        match respond_to_dialog(&tab, &policy) {
+                            Ok(()) => dialog.accepted = Some(policy.accept),
+                            Err(e) => log::warn!("Failed to handle {} dialog: {}", dialog.type_, e),
+                        }
+                    }
+                    log::info!(
+                        "JavaScript {} dialog: {:?} ({})",
+                        dialog.type_,
+                        dialog.message,
+                        match dialog.accepted {
+                            Some(true) => "accepted",
+                            Some(false) => "dismissed",
+                            None => "left open",
+                        }
+                    );
+                    if let Ok(mut dialogs_guard) = dialogs.lock() {
+                        push_bounded(&mut dialogs_guard, dialog, max_log_entries);
                     }
                 },
                 _ => {}
             }
         }));
+
+        if self.stealth
+            && let Err(e) = install_stealth_script(tab)
+        {
+            log::warn!("{}", e);
+        }
+
+        install_request_blocking(tab, self.block_rules.clone())?;
+        install_response_capture(tab, self.capture_rules.clone())?;
+
+        let overrides = self.overrides.lock().map(|o| o.clone()).unwrap_or_default();
+        overrides.apply(tab)?;
         Ok(())
     }
 
@@ -87,9 +297,9 @@ impl BrowserSession {
     pub fn launch(options: LaunchOptions) -> Result<Self> {
         let mut launch_opts = headless_chrome::LaunchOptions::default();
 
-        // Ignore default arguments to prevent detection by anti-bot services
-        launch_opts.ignore_default_args.push(OsStr::new("--enable-automation"));
-        launch_opts.args.push(OsStr::new("--disable-blink-features=AutomationControlled"));
+        let (args, ignored_default_args) = launch_flags(options.stealth, &options.extra_args);
+        launch_opts.args.extend(args);
+        launch_opts.ignore_default_args.extend(ignored_default_args);
 
         // Set the browser's idle timeout to 1 hour (default is 30 seconds) to prevent the session from closing too soon
         launch_opts.idle_browser_timeout = Duration::from_secs(60 * 60);
@@ -100,67 +310,102 @@ impl BrowserSession {
         // Set window size
         launch_opts.window_size = Some((options.window_width, options.window_height));
 
-        // Set Chrome binary path if provided
-        if let Some(path) = options.chrome_path {
+        // Set Chrome binary path if provided, otherwise fall back to the standard install
+        // locations when headless_chrome can't find Chrome on its own
+        if let Some(path) = &options.chrome_path {
+            check_chrome_path(path)?;
+            launch_opts.path = Some(path.clone());
+        } else if headless_chrome::browser::default_executable().is_err()
+            && let Some(path) = detect_chrome_path()
+        {
+            log::info!("Detected Chrome at {}", path.display());
             launch_opts.path = Some(path);
         }
 
         // Set user data directory if provided
-        if let Some(dir) = options.user_data_dir {
-            launch_opts.user_data_dir = Some(dir);
+        if let Some(dir) = &options.user_data_dir {
+            prepare_user_data_dir(dir)?;
+            launch_opts.user_data_dir = Some(dir.clone());
         }
 
         // Set sandbox mode
         launch_opts.sandbox = options.sandbox;
 
-        // Launch browser
-        let browser = Browser::new(launch_opts).map_err(|e| BrowserError::LaunchFailed(e.to_string()))?;
-
-        let console_logs = Arc::new(Mutex::new(Vec::new()));
-        let network_errors = Arc::new(Mutex::new(Vec::new()));
+        // Launch browser, retrying when Chrome starts but can't be connected to
+        let browser = retry_launch(options.launch_retries, LAUNCH_RETRY_BACKOFF, || Browser::new(launch_opts.clone()))?;
 
-        // Setup the initial tab
-        // headless_chrome creates one tab by default, but we can't easily get it without new_tab() or get_tabs()
-        // Wait, Browser::new() creates a browser.
-        // We usually do browser.new_tab() or get existing tabs.
-        // Let's get the tabs and setup listeners on them.
-        let mut tabs = browser.get_tabs().lock().map_err(|e| BrowserError::TabOperationFailed(e.to_string()))?.clone();
-        
-        if tabs.is_empty() {
+        // headless_chrome usually opens one tab on launch; make sure there is one to work in
+        let has_tabs =
+            !browser.get_tabs().lock().map_err(|e| BrowserError::TabOperationFailed(e.to_string()))?.is_empty();
+        if !has_tabs {
             browser.new_tab().map_err(|e| BrowserError::LaunchFailed(format!("Failed to create initial tab: {}", e)))?;
-            tabs = browser.get_tabs().lock().map_err(|e| BrowserError::TabOperationFailed(e.to_string()))?.clone();
-        }
-        
-        for tab in tabs {
-            Self::setup_tab_listeners(&tab, console_logs.clone(), network_errors.clone())?;
         }
 
-        Ok(Self { 
-            browser, 
-            tool_registry: ToolRegistry::with_defaults(),
-            console_logs,
-            network_errors
-        })
+        let session = Self::with_config(browser, options);
+        session.apply_session_config()?;
+        Ok(session)
     }
 
     /// Connect to an existing browser instance via WebSocket
+    ///
+    /// The session configuration in `options.session` is applied to the browser's tabs the same
+    /// way as for [`BrowserSession::launch`].
     pub fn connect(options: ConnectionOptions) -> Result<Self> {
         let browser = Browser::connect(options.ws_url).map_err(|e| BrowserError::ConnectionFailed(e.to_string()))?;
-        
-        let console_logs = Arc::new(Mutex::new(Vec::new()));
-        let network_errors = Arc::new(Mutex::new(Vec::new()));
 
-        let tabs = browser.get_tabs().lock().map_err(|e| BrowserError::TabOperationFailed(e.to_string()))?.clone();
+        let session = Self::with_config(browser, options.session);
+        session.apply_session_config()?;
+        Ok(session)
+    }
+
+    /// Session around `browser` configured by `config`, with no tabs set up yet
+    fn with_config(browser: Browser, config: LaunchOptions) -> Self {
+        Self {
+            browser,
+            tool_registry: ToolRegistry::with_defaults(),
+            console_logs: Arc::new(Mutex::new(Vec::new())),
+            network_errors: Arc::new(Mutex::new(Vec::new())),
+            js_exceptions: Arc::new(Mutex::new(Vec::new())),
+            default_timeout: Duration::from_millis(config.default_timeout_ms),
+            page_load_strategy: config.page_load_strategy,
+            selector_strategy: config.selector_strategy,
+            max_log_entries: config.max_log_entries,
+            read_only: config.read_only,
+            allowed_domains: config.allowed_domains.clone(),
+            stealth: config.stealth,
+            capture_on_error: config.capture_on_error,
+            block_rules: BlockRules::with_resource_types(config.block_resources.clone()),
+            capture_rules: CaptureRules::default(),
+            overrides: Arc::new(Mutex::new(TabOverrides {
+                user_agent: config.user_agent.clone(),
+                extra_headers: config.extra_headers.clone(),
+                locale: config.locale.clone(),
+                timezone: config.timezone.clone(),
+                media: None,
+            })),
+            dialogs: Arc::new(Mutex::new(Vec::new())),
+            dialog_policy: Arc::new(Mutex::new(None)),
+            adopted_targets: Mutex::new(HashSet::new()),
+            mouse_positions: Mutex::new(HashMap::new()),
+            style_scripts: Mutex::new(HashMap::new()),
+            config,
+        }
+    }
+
+    /// Apply the session configuration (user agent, headers, locale, timezone, stealth script,
+    /// request blocking) to every open tab and start capturing its events
+    fn apply_session_config(&self) -> Result<()> {
+        let tabs = self.browser.get_tabs().lock().map_err(|e| BrowserError::TabOperationFailed(e.to_string()))?.clone();
         for tab in tabs {
-            Self::setup_tab_listeners(&tab, console_logs.clone(), network_errors.clone())?;
+            self.adopt_tab(&tab)?;
         }
+        Ok(())
+    }
 
-        Ok(Self { 
-            browser, 
-            tool_registry: ToolRegistry::with_defaults(),
-            console_logs,
-            network_errors
-        })
+    /// Configuration the session was launched or connected with; settings changed later (e.g.
+    /// with set_user_agent) are not reflected here
+    pub fn config(&self) -> &LaunchOptions {
+        &self.config
     }
 
     /// Launch a browser with default options
@@ -173,15 +418,13 @@ impl BrowserSession {
         self.get_active_tab()
     }
 
-    /// Create a new tab and set it as active
-    pub fn new_tab(&mut self) -> Result<Arc<Tab>> {
+    /// Create a new tab with event listeners attached
+    pub fn new_tab(&self) -> Result<Arc<Tab>> {
         let tab = self
             .browser
             .new_tab()
             .map_err(|e| BrowserError::TabOperationFailed(format!("Failed to create tab: {}", e)))?;
-            
-        Self::setup_tab_listeners(&tab, self.console_logs.clone(), self.network_errors.clone())?;
-            
+        self.adopt_tab(&tab)?;
         Ok(tab)
     }
 
@@ -206,10 +449,10 @@ impl BrowserSession {
             let result = tab.evaluate("document.visibilityState === 'visible' && document.hasFocus()", false);
             match result {
                 Ok(remote_object) => {
-                    if let Some(value) = remote_object.value {
-                        if value.as_bool().unwrap_or(false) {
-                            return Ok(tab.clone());
-                        }
+                    if let Some(value) = remote_object.value
+                        && value.as_bool().unwrap_or(false)
+                    {
+                        return Ok(tab.clone());
                     }
                 }
                 Err(e) => {
@@ -224,10 +467,10 @@ impl BrowserSession {
             let result = tab.evaluate("document.visibilityState === 'visible'", false);
             match result {
                 Ok(remote_object) => {
-                    if let Some(value) = remote_object.value {
-                        if value.as_bool().unwrap_or(false) {
-                            return Ok(tab.clone());
-                        }
+                    if let Some(value) = remote_object.value
+                        && value.as_bool().unwrap_or(false)
+                    {
+                        return Ok(tab.clone());
                     }
                 }
                 Err(_) => continue,
@@ -239,7 +482,82 @@ impl BrowserSession {
             return Ok(tab.clone());
         }
 
-        Err(BrowserError::TabOperationFailed("No active tab found".to_string()))
+        // Every tab has been closed (e.g. a site closed its own window), so open a fresh one
+        // to keep the session usable
+        log::warn!("No open tabs left, opening a new tab to recover the session");
+        let tab = self
+            .browser
+            .new_tab()
+            .map_err(|e| BrowserError::TabOperationFailed(format!("No active tab found and failed to open one: {}", e)))?;
+        self.adopt_tab(&tab)?;
+
+        Ok(tab)
+    }
+
+    /// Wait for a tab this session didn't open itself, such as a `window.open` popup (e.g. an
+    /// OAuth login window), and set up its listeners.
+    ///
+    /// Popups opened before the call are returned too, as long as no earlier call picked them up.
+    pub fn wait_for_new_target(&self, timeout_ms: u64) -> Result<Arc<Tab>> {
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        loop {
+            // Pick up targets headless_chrome hasn't registered as tabs yet
+            self.browser.register_missing_tabs();
+
+            let adopted = self
+                .adopted_targets
+                .lock()
+                .map_err(|e| BrowserError::TabOperationFailed(format!("Failed to read known tabs: {}", e)))?
+                .clone();
+            if let Some(tab) = self.get_tabs()?.into_iter().find(|tab| !adopted.contains(tab.get_target_id())) {
+                self.adopt_tab(&tab)?;
+                return Ok(tab);
+            }
+
+            if Instant::now() >= deadline {
+                return Err(BrowserError::Timeout(format!("No new tab or popup opened within {} ms", timeout_ms)));
+            }
+            std::thread::sleep(NEW_TARGET_POLL_INTERVAL);
+        }
+    }
+
+    /// Set up listeners on a tab and remember it as one of the session's own
+    fn adopt_tab(&self, tab: &Arc<Tab>) -> Result<()> {
+        self.setup_tab_listeners(tab)?;
+        if let Ok(mut adopted) = self.adopted_targets.lock() {
+            adopted.insert(tab.get_target_id().clone());
+        }
+        Ok(())
+    }
+
+    /// Last position a tool moved the mouse to in the active tab, if any
+    pub fn mouse_position(&self) -> Option<(f64, f64)> {
+        let tab = self.tab().ok()?;
+        self.mouse_position_in(&tab)
+    }
+
+    /// Last position a tool moved the mouse to in `tab`, if any
+    pub(crate) fn mouse_position_in(&self, tab: &Tab) -> Option<(f64, f64)> {
+        self.mouse_positions.lock().ok()?.get(tab.get_target_id()).copied()
+    }
+
+    /// Remember where a tool moved the mouse to in `tab`, so later moves continue from there
+    pub(crate) fn set_mouse_position_in(&self, tab: &Tab, position: (f64, f64)) {
+        if let Ok(mut positions) = self.mouse_positions.lock() {
+            positions.insert(tab.get_target_id().clone(), position);
+        }
+    }
+
+    /// Remember the script that re-adds the injected style `style_id` in new documents
+    pub(crate) fn add_style_script(&self, style_id: &str, identifier: String) {
+        if let Ok(mut scripts) = self.style_scripts.lock() {
+            scripts.insert(style_id.to_string(), identifier);
+        }
+    }
+
+    /// Forget and return the script that re-adds the injected style `style_id`, if it has one
+    pub(crate) fn take_style_script(&self, style_id: &str) -> Option<String> {
+        self.style_scripts.lock().ok()?.remove(style_id)
     }
 
     /// Close the active tab
@@ -254,38 +572,406 @@ impl BrowserSession {
         &self.browser
     }
 
+    /// Ask Chrome for its version, failing if it doesn't answer within [`HEALTH_CHECK_TIMEOUT`]
+    pub fn version(&self) -> Result<GetVersionReturnObject> {
+        // Run on a detached thread so a hung browser can't block the caller past the timeout
+        let browser = self.browser.clone();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(browser.get_version());
+        });
+
+        match receiver.recv_timeout(HEALTH_CHECK_TIMEOUT) {
+            Ok(Ok(version)) => Ok(version),
+            Ok(Err(e)) => Err(BrowserError::ChromeError(format!("Failed to get browser version: {}", e))),
+            Err(_) => Err(BrowserError::Timeout(format!(
+                "Browser did not respond within {} ms",
+                HEALTH_CHECK_TIMEOUT.as_millis()
+            ))),
+        }
+    }
+
+    /// Whether Chrome still responds to CDP commands, e.g. to detect a crashed browser
+    /// before running a tool
+    pub fn is_alive(&self) -> bool {
+        self.version().is_ok()
+    }
+
     /// Navigate to a URL using the active tab
     pub fn navigate(&self, url: &str) -> Result<()> {
-        self.tab()?
-            .navigate_to(url)
+        self.navigate_in(&self.tab()?, url)
+    }
+
+    /// Navigate `tab` to a URL
+    pub(crate) fn navigate_in(&self, tab: &Arc<Tab>, url: &str) -> Result<()> {
+        self.check_url_allowed(url)?;
+        tab.navigate_to(url)
             .map_err(|e| BrowserError::NavigationFailed(format!("Failed to navigate to {}: {}", url, e)))?;
 
         Ok(())
     }
 
-    /// Wait for navigation to complete
+    /// Wait for navigation to complete, using the session's default timeout
     pub fn wait_for_navigation(&self) -> Result<()> {
+        self.wait_for_navigation_with_timeout(self.default_timeout)
+    }
+
+    /// Wait for navigation to complete within the given timeout
+    pub fn wait_for_navigation_with_timeout(&self, timeout: Duration) -> Result<()> {
+        self.wait_for_navigation_in(&self.tab()?, timeout)
+    }
+
+    /// Wait for navigation in `tab` to complete within the given timeout
+    pub(crate) fn wait_for_navigation_in(&self, tab: &Arc<Tab>, timeout: Duration) -> Result<()> {
+        tab.set_default_timeout(timeout);
+        let navigated = tab.wait_until_navigated().map(|_| ());
+        // Keep the tab's own default in sync with the session for headless_chrome's internal waits
+        tab.set_default_timeout(self.default_timeout);
+
+        navigated.map_err(|e| {
+            if e.downcast_ref::<headless_chrome::util::Timeout>().is_some() {
+                BrowserError::Timeout(format!("Navigation did not complete within {} ms", timeout.as_millis()))
+            } else {
+                BrowserError::NavigationFailed(format!("Failed to wait for navigation: {}", e))
+            }
+        })
+    }
+
+    /// Wait for the page in `tab` to load as far as the session's page-load strategy asks for
+    pub(crate) fn wait_for_page_load_in(&self, tab: &Arc<Tab>, timeout: Duration) -> Result<()> {
+        match self.page_load_strategy {
+            PageLoadStrategy::Normal => self.wait_for_navigation_in(tab, timeout),
+            PageLoadStrategy::Eager => {
+                // Page.navigate returns once the new document is committed, so readyState is the new page's
+                self.wait_for_function_in(tab, DOM_READY_JS, timeout.as_millis() as u64, NETWORK_IDLE_POLL_MS)
+                    .map(|_| ())
+                    .map_err(|e| match e {
+                        BrowserError::Timeout(_) => {
+                            BrowserError::Timeout(format!("DOM was not ready within {} ms", timeout.as_millis()))
+                        }
+                        e => e,
+                    })
+            }
+            PageLoadStrategy::None => Ok(()),
+        }
+    }
+
+    /// Get how long navigation waits for the page to load
+    pub fn page_load_strategy(&self) -> PageLoadStrategy {
+        self.page_load_strategy
+    }
+
+    /// Set how long navigation waits for the page to load
+    pub fn set_page_load_strategy(&mut self, strategy: PageLoadStrategy) {
+        self.page_load_strategy = strategy;
+    }
+
+    /// Get the default timeout for navigation and element waits
+    pub fn default_timeout(&self) -> Duration {
+        self.default_timeout
+    }
+
+    /// Set the default timeout for navigation and element waits
+    pub fn set_default_timeout(&mut self, timeout: Duration) {
+        self.default_timeout = timeout;
+    }
+
+    /// Get how selectors for indexed elements are built in DOM snapshots
+    pub fn selector_strategy(&self) -> SelectorStrategy {
+        self.selector_strategy
+    }
+
+    /// Set how selectors for indexed elements are built in DOM snapshots
+    pub fn set_selector_strategy(&mut self, strategy: SelectorStrategy) {
+        self.selector_strategy = strategy;
+    }
+
+    /// Whether tools that change the page or browser state are refused
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Refuse (or allow again) tools that change the page or browser state
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Whether failed tools get a screenshot of the page attached
+    pub fn capture_on_error(&self) -> bool {
+        self.capture_on_error
+    }
+
+    /// Attach (or stop attaching) a screenshot of the page to the results of failed tools
+    pub fn set_capture_on_error(&mut self, capture: bool) {
+        self.capture_on_error = capture;
+    }
+
+    /// Hosts that navigation is restricted to, if any
+    pub fn allowed_domains(&self) -> Option<&[String]> {
+        self.allowed_domains.as_deref()
+    }
+
+    /// Fail if `url` is outside the allowed domains
+    pub(crate) fn check_url_allowed(&self, url: &str) -> Result<()> {
+        match &self.allowed_domains {
+            Some(allowed) => check_url_allowed(url, allowed),
+            None => Ok(()),
+        }
+    }
+
+    /// Evaluate `js` in the active tab in a fresh isolated world: the script sees the page's DOM
+    /// but not its JavaScript globals, and its own declarations don't leak into the page or into
+    /// the next call
+    pub fn evaluate_isolated(&self, js: &str) -> Result<RemoteObject> {
+        let tab = self.tab()?;
+        self.evaluate_isolated_in(&tab, js)
+    }
+
+    /// [`evaluate_isolated`](Self::evaluate_isolated) in `tab` instead of the active tab
+    pub(crate) fn evaluate_isolated_in(&self, tab: &Tab, js: &str) -> Result<RemoteObject> {
+        evaluate_in_isolated_world(tab, js)
+    }
+
+    /// Repeatedly evaluate a JavaScript expression until it returns a truthy value.
+    ///
+    /// `js_expr` must be an expression; exceptions thrown while evaluating it count as a
+    /// falsy result, except syntax errors (including invalid CSS selectors passed to
+    /// `querySelector`), which fail right away with `BrowserError::InvalidArgument`. Returns the
+    /// final (truthy) value, or `BrowserError::Timeout` if the expression did not become truthy
+    /// within `timeout_ms`.
+    pub fn wait_for_function(&self, js_expr: &str, timeout_ms: u64, poll_ms: u64) -> Result<serde_json::Value> {
+        self.wait_for_function_in(&self.tab()?, js_expr, timeout_ms, poll_ms)
+    }
+
+    /// [`wait_for_function`](Self::wait_for_function) in `tab` instead of the active tab
+    pub(crate) fn wait_for_function_in(
+        &self,
+        tab: &Arc<Tab>,
+        js_expr: &str,
+        timeout_ms: u64,
+        poll_ms: u64,
+    ) -> Result<serde_json::Value> {
+        let wrapped = WAIT_FOR_FUNCTION_JS.replace("__EXPR__", js_expr);
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+
+        loop {
+            match tab.call_method(Runtime::Evaluate {
+                expression: wrapped.clone(),
+                object_group: None,
+                include_command_line_api: Some(false),
+                silent: Some(false),
+                context_id: None,
+                return_by_value: Some(false),
+                generate_preview: Some(true),
+                user_gesture: Some(false),
+                await_promise: Some(false),
+                throw_on_side_effect: None,
+                timeout: None,
+                disable_breaks: None,
+                repl_mode: None,
+                allow_unsafe_eval_blocked_by_csp: None,
+                unique_context_id: None,
+                serialization_options: None,
+            }) {
+                Ok(response) => {
+                    if let Some(details) = response.exception_details {
+                        let description = details.exception.as_ref().and_then(|e| e.description.clone());
+                        return Err(BrowserError::InvalidArgument(format!(
+                            "Expression `{}` is invalid: {}",
+                            js_expr,
+                            description.unwrap_or(details.text)
+                        )));
+                    }
+                    if is_truthy(&response.result) {
+                        return Ok(remote_object_to_value(&response.result));
+                    }
+                }
+                // The page may be between navigations; keep polling until the deadline
+                Err(e) => log::debug!("wait_for_function evaluation failed: {}", e),
+            }
+
+            if Instant::now() >= deadline {
+                return Err(BrowserError::Timeout(format!(
+                    "Expression `{}` was not truthy within {} ms",
+                    js_expr, timeout_ms
+                )));
+            }
+            std::thread::sleep(Duration::from_millis(poll_ms.max(1)));
+        }
+    }
+
+    /// Wait until the page has finished loading and no new resources were requested for
+    /// `idle_ms` milliseconds, or until `timeout_ms` elapses
+    pub fn wait_for_network_idle(&self, timeout_ms: u64, idle_ms: u64) -> Result<()> {
+        self.wait_for_network_idle_in(&self.tab()?, timeout_ms, idle_ms)
+    }
+
+    /// [`wait_for_network_idle`](Self::wait_for_network_idle) in `tab` instead of the active tab
+    pub(crate) fn wait_for_network_idle_in(&self, tab: &Arc<Tab>, timeout_ms: u64, idle_ms: u64) -> Result<()> {
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        let idle = Duration::from_millis(idle_ms);
+
+        // Resource count of the last poll and when it last changed; tracked here rather than in
+        // a page global so the wait leaves nothing behind for the page to see
+        let mut last: Option<(i64, Instant)> = None;
+        loop {
+            match tab.evaluate(NETWORK_RESOURCES_JS, false) {
+                Ok(object) => {
+                    let count = object.value.as_ref().and_then(|v| v.as_i64()).unwrap_or(-1);
+                    let now = Instant::now();
+                    let since = match last {
+                        Some((last_count, since)) if last_count == count => since,
+                        _ => {
+                            last = Some((count, now));
+                            now
+                        }
+                    };
+                    if count >= 0 && now.duration_since(since) >= idle {
+                        return Ok(());
+                    }
+                }
+                // The page may be between navigations; keep polling until the deadline
+                Err(e) => log::debug!("Network idle check failed: {}", e),
+            }
+
+            if Instant::now() >= deadline {
+                return Err(BrowserError::Timeout(format!("Network did not become idle within {} ms", timeout_ms)));
+            }
+            std::thread::sleep(Duration::from_millis(NETWORK_IDLE_POLL_MS));
+        }
+    }
+
+    /// Wait until the DOM has not changed for `quiet_ms` milliseconds, e.g. before taking a
+    /// snapshot of a page that is still animating or loading content.
+    ///
+    /// Returns `false` (not an error) when the page was still mutating after `timeout_ms`.
+    pub fn wait_for_dom_stable(&self, quiet_ms: u64, timeout_ms: u64) -> Result<bool> {
+        self.wait_for_dom_stable_in(&self.tab()?, quiet_ms, timeout_ms)
+    }
+
+    /// [`wait_for_dom_stable`](Self::wait_for_dom_stable) in `tab` instead of the active tab
+    pub(crate) fn wait_for_dom_stable_in(&self, tab: &Arc<Tab>, quiet_ms: u64, timeout_ms: u64) -> Result<bool> {
+        let stable_js = DOM_STABLE_JS.replace("__QUIET_MS__", &quiet_ms.to_string());
+        match self.wait_for_function_in(tab, &stable_js, timeout_ms, NETWORK_IDLE_POLL_MS) {
+            Ok(_) => Ok(true),
+            Err(BrowserError::Timeout(_)) => {
+                tab.evaluate(DOM_STABLE_CLEANUP_JS, false).ok();
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Capture a PNG of the whole page, including content below the fold.
+    ///
+    /// The viewport is temporarily resized to the document's scroll size so everything is
+    /// laid out and painted, then the device metrics override is cleared again.
+    pub fn capture_full_page(&self) -> Result<Vec<u8>> {
+        self.capture_full_page_in(&self.tab()?)
+    }
+
+    /// [`capture_full_page`](Self::capture_full_page) of `tab` instead of the active tab
+    pub(crate) fn capture_full_page_in(&self, tab: &Arc<Tab>) -> Result<Vec<u8>> {
+        let size = tab
+            .evaluate(PAGE_SIZE_JS, false)
+            .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to measure page: {}", e)))?
+            .value
+            .and_then(|v| v.as_str().and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok()))
+            .ok_or_else(|| BrowserError::ScreenshotFailed("Failed to measure page: no size returned".to_string()))?;
+        let width = size["width"].as_u64().unwrap_or(0) as u32;
+        let height = size["height"].as_u64().unwrap_or(0) as u32;
+
+        tab.call_method(Emulation::SetDeviceMetricsOverride {
+            width,
+            height,
+            device_scale_factor: 0.0,
+            mobile: false,
+            scale: None,
+            screen_width: None,
+            screen_height: None,
+            position_x: None,
+            position_y: None,
+            dont_set_visible_size: None,
+            screen_orientation: None,
+            viewport: None,
+            display_feature: None,
+            device_posture: None,
+        })
+        .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to resize viewport: {}", e)))?;
+
+        let capture = tab.capture_screenshot(Page::CaptureScreenshotFormatOption::Png, None, None, true);
+
+        // Restore the viewport even if the capture failed
+        if let Err(e) = tab.call_method(Emulation::ClearDeviceMetricsOverride(None)) {
+            log::warn!("Failed to restore viewport after full-page capture: {}", e);
+        }
+
+        capture.map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))
+    }
+
+    /// Print the active tab to PDF with Chrome's print settings (Letter, default margins)
+    pub fn print_pdf(&self, landscape: bool, print_background: bool) -> Result<Vec<u8>> {
+        let options = PrintToPdfOptions {
+            landscape: Some(landscape),
+            print_background: Some(print_background),
+            ..Default::default()
+        };
         self.tab()?
-            .wait_until_navigated()
-            .map_err(|e| BrowserError::NavigationFailed(format!("Navigation timeout: {}", e)))?;
+            .print_to_pdf(Some(options))
+            .map_err(|e| BrowserError::ChromeError(format!("Failed to print PDF: {}", e)))
+    }
 
-        Ok(())
+    /// Archive the active tab as MHTML: a single file with the page's CSS, images and frames inlined
+    pub fn capture_mhtml(&self) -> Result<String> {
+        self.capture_mhtml_in(&self.tab()?)
+    }
+
+    /// [`capture_mhtml`](Self::capture_mhtml) of `tab` instead of the active tab
+    pub(crate) fn capture_mhtml_in(&self, tab: &Arc<Tab>) -> Result<String> {
+        tab.call_method(Page::CaptureSnapshot { format: Some(Page::CaptureSnapshotFormatOption::Mhtml) })
+            .map(|snapshot| snapshot.data)
+            .map_err(|e| BrowserError::ChromeError(format!("Failed to capture MHTML snapshot: {}", e)))
+    }
+
+    /// Get Chrome's own accessibility tree of the active tab as the raw array of CDP `AXNode`s,
+    /// e.g. to compare against the snapshot built by the extraction script
+    pub fn get_cdp_accessibility_tree(&self) -> Result<serde_json::Value> {
+        self.get_cdp_accessibility_tree_in(&self.tab()?)
+    }
+
+    /// [`get_cdp_accessibility_tree`](Self::get_cdp_accessibility_tree) of `tab` instead of the active tab
+    pub(crate) fn get_cdp_accessibility_tree_in(&self, tab: &Arc<Tab>) -> Result<serde_json::Value> {
+        let mut tree = tab
+            .call_method(GetFullAxTreeRaw {})
+            .map_err(|e| BrowserError::ChromeError(format!("Failed to get accessibility tree: {}", e)))?;
+        Ok(tree.get_mut("nodes").map(serde_json::Value::take).unwrap_or_else(|| serde_json::json!([])))
     }
 
     /// Extract the DOM tree from the active tab
     pub fn extract_dom(&self) -> Result<DomTree> {
-        DomTree::from_tab(&self.tab()?)
+        self.extract_dom_with_prefix("")
     }
 
     /// Extract the DOM tree with a custom ref prefix (for iframe handling)
     pub fn extract_dom_with_prefix(&self, prefix: &str) -> Result<DomTree> {
-        DomTree::from_tab_with_prefix(&self.tab()?, prefix)
+        DomTree::from_tab_with_strategy(&self.tab()?, prefix, self.selector_strategy)
     }
 
-    /// Find an element by CSS selector using the provided tab
+    /// Extract the DOM tree with extraction limits such as a node budget; see [`ExtractOptions`]
+    pub fn extract_dom_with_options(&self, options: &ExtractOptions) -> Result<DomTree> {
+        DomTree::from_tab_with_options(&self.tab()?, self.selector_strategy, options)
+    }
+
+    /// Find an element by CSS selector using the provided tab.
+    ///
+    /// Falls back to the documents of same-origin iframes when the top document has no match, so
+    /// the element is bound to the frame it lives in. Cross-origin iframes run in their own
+    /// process and are not searched.
     pub fn find_element<'a>(&self, tab: &'a Arc<Tab>, css_selector: &str) -> Result<headless_chrome::Element<'a>> {
-        tab.find_element(css_selector)
-            .map_err(|e| BrowserError::ElementNotFound(format!("Element '{}' not found: {}", css_selector, e)))
+        tab.find_element(css_selector).or_else(|e| {
+            find_element_in_frames(tab, css_selector)
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("Element '{}' not found: {}", css_selector, e)))
+        })
     }
 
     /// Get the tool registry
@@ -304,41 +990,279 @@ impl BrowserSession {
         self.tool_registry.execute(name, params, &mut context)
     }
 
-    /// Navigate back in browser history
-    pub fn go_back(&self) -> Result<()> {
-        let go_back_js = r#"
-            (function() {
-                window.history.back();
-                return true;
-            })()
-        "#;
+    /// Navigate back in browser history.
+    ///
+    /// Returns `true` once the previous page has loaded, or `false` if there was no
+    /// history entry to go back to.
+    pub fn go_back(&self) -> Result<bool> {
+        self.go_back_in(&self.tab()?)
+    }
 
-        self.tab()?
-            .evaluate(go_back_js, false)
-            .map_err(|e| BrowserError::NavigationFailed(format!("Failed to go back: {}", e)))?;
+    /// [`go_back`](Self::go_back) in `tab` instead of the active tab
+    pub(crate) fn go_back_in(&self, tab: &Arc<Tab>) -> Result<bool> {
+        self.traverse_history(tab, -1, "back")
+    }
+
+    /// Navigate forward in browser history.
+    ///
+    /// Returns `true` once the next page has loaded, or `false` if there was no
+    /// history entry to go forward to.
+    pub fn go_forward(&self) -> Result<bool> {
+        self.go_forward_in(&self.tab()?)
+    }
+
+    /// [`go_forward`](Self::go_forward) in `tab` instead of the active tab
+    pub(crate) fn go_forward_in(&self, tab: &Arc<Tab>) -> Result<bool> {
+        self.traverse_history(tab, 1, "forward")
+    }
+
+    /// Move `offset` entries through the history of `tab` and wait, up to the default timeout,
+    /// until the navigation has happened. Returns `false` at once if there is no such entry.
+    fn traverse_history(&self, tab: &Arc<Tab>, offset: i64, direction: &str) -> Result<bool> {
+        let navigation_history = || {
+            tab.call_method(Page::GetNavigationHistory(None))
+                .map_err(|e| BrowserError::NavigationFailed(format!("Failed to read history: {}", e)))
+        };
+
+        let history = navigation_history()?;
+        let target = i64::from(history.current_index) + offset;
+        if target < 0 || target >= history.entries.len() as i64 {
+            log::debug!("No history entry to go {} to", direction);
+            return Ok(false);
+        }
+
+        tab.evaluate(&format!("window.history.go({}); true", offset), false)
+            .map_err(|e| BrowserError::NavigationFailed(format!("Failed to go {}: {}", direction, e)))?;
+
+        let deadline = Instant::now() + self.default_timeout;
+        while i64::from(navigation_history()?.current_index) != target {
+            if Instant::now() >= deadline {
+                return Err(BrowserError::Timeout(format!(
+                    "Going {} did not finish within {} ms",
+                    direction,
+                    self.default_timeout.as_millis()
+                )));
+            }
+            std::thread::sleep(HISTORY_POLL_INTERVAL);
+        }
+
+        // The history entry has changed; let the new page finish loading
+        self.wait_for_navigation_in(tab, self.default_timeout)?;
+
+        Ok(true)
+    }
+
+    /// Block requests of the given resource types (CDP names such as "Image", "Font", "Media")
+    /// in all tabs, including tabs opened later. Pass an empty list to stop blocking.
+    pub fn block_resource_types(&self, types: Vec<String>) -> Result<()> {
+        *self.block_rules.resource_types.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "block_resource_types".into(),
+            reason: "Failed to lock blocked resource types mutex".into(),
+        })? = types;
+
+        self.sync_request_blocking()
+    }
+
+    /// Block requests whose URL matches any of the glob patterns (e.g. `*://*.doubleclick.net/*`)
+    /// in all tabs, including tabs opened later. Pass an empty list to stop blocking.
+    pub fn block_urls(&self, patterns: Vec<String>) -> Result<()> {
+        *self.block_rules.url_patterns.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "block_urls".into(),
+            reason: "Failed to lock blocked URL patterns mutex".into(),
+        })? = patterns;
 
-        // Wait a moment for navigation
-        std::thread::sleep(std::time::Duration::from_millis(300));
+        self.sync_request_blocking()
+    }
+
+    /// Record the XHR and fetch responses whose URL matches the glob `url_pattern` (e.g.
+    /// `*/api/*`), including their bodies, in all tabs and tabs opened later. Patterns add up.
+    ///
+    /// The last 100 responses are kept; bodies longer than a million characters are cut and
+    /// marked as truncated.
+    pub fn capture_responses(&self, url_pattern: &str) -> Result<()> {
+        {
+            let mut patterns =
+                self.capture_rules.url_patterns.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+                    tool: "capture_responses".into(),
+                    reason: "Failed to lock captured URL patterns mutex".into(),
+                })?;
+            if !patterns.iter().any(|pattern| pattern == url_pattern) {
+                patterns.push(url_pattern.to_string());
+            }
+        }
 
+        for tab in self.get_tabs()? {
+            install_response_capture(&tab, self.capture_rules.clone())?;
+        }
         Ok(())
     }
 
-    /// Navigate forward in browser history
-    pub fn go_forward(&self) -> Result<()> {
-        let go_forward_js = r#"
-            (function() {
-                window.history.forward();
-                return true;
-            })()
-        "#;
+    /// Get the responses recorded since [`capture_responses`](Self::capture_responses) was
+    /// called, most recent last
+    pub fn get_captured_responses(&self) -> Result<Vec<CapturedResponse>> {
+        let responses = self.capture_rules.responses.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "get_captured_responses".into(),
+            reason: "Failed to lock captured responses mutex".into(),
+        })?;
+        Ok(responses.clone())
+    }
 
-        self.tab()?
-            .evaluate(go_forward_js, false)
-            .map_err(|e| BrowserError::NavigationFailed(format!("Failed to go forward: {}", e)))?;
+    /// Clear the recorded responses; capturing continues
+    pub fn clear_captured_responses(&self) -> Result<()> {
+        self.capture_rules
+            .responses
+            .lock()
+            .map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "clear_captured_responses".into(),
+                reason: "Failed to lock captured responses mutex".into(),
+            })?
+            .clear();
+        Ok(())
+    }
+
+    /// Override the user agent in all tabs, including tabs opened later
+    pub fn set_user_agent(&self, user_agent: &str) -> Result<()> {
+        let overrides = {
+            let mut overrides = self.overrides.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "set_user_agent".into(),
+                reason: "Failed to lock tab overrides mutex".into(),
+            })?;
+            overrides.user_agent = Some(user_agent.to_string());
+            overrides.clone()
+        };
+
+        for tab in self.get_tabs()? {
+            overrides.apply(&tab)?;
+        }
+        Ok(())
+    }
+
+    /// Send extra HTTP headers with every request in all tabs, including tabs opened later.
+    /// Replaces previously set headers; pass an empty map to clear them.
+    pub fn set_extra_headers(&self, headers: HashMap<String, String>) -> Result<()> {
+        let overrides = {
+            let mut overrides = self.overrides.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "set_extra_headers".into(),
+                reason: "Failed to lock tab overrides mutex".into(),
+            })?;
+            overrides.extra_headers = headers;
+            overrides.clone()
+        };
+
+        for tab in self.get_tabs()? {
+            overrides.apply_headers(&tab)?;
+        }
+        Ok(())
+    }
+
+    /// Emulate a locale (e.g. "de-DE") in all tabs, including tabs opened later: sends it as
+    /// `Accept-Language` and overrides the locale used by `Intl`.
+    ///
+    /// Already loaded pages keep the content they were served with; some sites need a reload
+    /// to pick up the new language.
+    pub fn set_locale(&self, locale: &str) -> Result<()> {
+        let overrides = {
+            let mut overrides = self.overrides.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "set_locale".into(),
+                reason: "Failed to lock tab overrides mutex".into(),
+            })?;
+            overrides.locale = Some(locale.to_string());
+            overrides.clone()
+        };
+
+        for tab in self.get_tabs()? {
+            apply_locale(&tab, locale)?;
+            overrides.apply_headers(&tab)?;
+        }
+        Ok(())
+    }
+
+    /// Emulate a timezone (IANA ID such as "America/New_York") in all tabs, including tabs
+    /// opened later. Returns `InvalidArgument` if Chrome does not know the timezone.
+    pub fn set_timezone(&self, timezone: &str) -> Result<()> {
+        // Apply first so an invalid timezone is never stored for future tabs
+        for tab in self.get_tabs()? {
+            apply_timezone(&tab, timezone)?;
+        }
+
+        self.overrides
+            .lock()
+            .map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "set_timezone".into(),
+                reason: "Failed to lock tab overrides mutex".into(),
+            })?
+            .timezone = Some(timezone.to_string());
+        Ok(())
+    }
+
+    /// Emulate a CSS media type in all tabs, e.g. `Some("print")` to render print stylesheets
+    /// for screenshots. `None` goes back to screen media.
+    pub fn set_emulated_media(&self, media: Option<&str>) -> Result<()> {
+        self.overrides
+            .lock()
+            .map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "set_emulated_media".into(),
+                reason: "Failed to lock tab overrides mutex".into(),
+            })?
+            .media = media.map(str::to_string);
+
+        for tab in self.get_tabs()? {
+            apply_media(&tab, media.unwrap_or_default())?;
+        }
+        Ok(())
+    }
+
+    /// Stop emulating print (or any other) media and render as screen again
+    pub fn reset_emulated_media(&self) -> Result<()> {
+        self.set_emulated_media(None)
+    }
+
+    /// Answer JavaScript dialogs (`alert`, `confirm`, `prompt`) in every tab as soon as they open,
+    /// pressing OK with `prompt_text` when `accept` is true and Cancel otherwise.
+    ///
+    /// Dialogs that are already open are answered right away and returned.
+    pub fn set_dialog_handler(&self, accept: bool, prompt_text: Option<String>) -> Result<Vec<JavaScriptDialog>> {
+        let policy = DialogPolicy { accept, prompt_text };
+        *self.dialog_policy.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "set_dialog_handler".into(),
+            reason: "Failed to lock dialog policy mutex".into(),
+        })? = Some(policy.clone());
+
+        let tabs = self.get_tabs()?;
+        let mut dialogs = self.dialogs.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "set_dialog_handler".into(),
+            reason: "Failed to lock dialogs mutex".into(),
+        })?;
+        let mut handled = Vec::new();
+        for dialog in dialogs.iter_mut().filter(|d| d.accepted.is_none()) {
+            let Some(tab) = tabs.iter().find(|tab| *tab.get_target_id() == dialog.target_id) else { continue };
+            // Fails if the page already closed the dialog, e.g. by navigating away
+            match respond_to_dialog(tab, &policy) {
+                Ok(()) => {
+                    dialog.accepted = Some(accept);
+                    handled.push(dialog.clone());
+                }
+                Err(e) => log::debug!("Could not answer {} dialog: {}", dialog.type_, e),
+            }
+        }
+        Ok(handled)
+    }
 
-        // Wait a moment for navigation
-        std::thread::sleep(std::time::Duration::from_millis(300));
+    /// Stop answering dialogs automatically; new dialogs stay open until a handler is set
+    pub fn clear_dialog_handler(&self) -> Result<()> {
+        *self.dialog_policy.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "clear_dialog_handler".into(),
+            reason: "Failed to lock dialog policy mutex".into(),
+        })? = None;
+        Ok(())
+    }
 
+    /// Turn request interception on or off in every tab to match the current block rules
+    fn sync_request_blocking(&self) -> Result<()> {
+        let active = self.block_rules.is_active();
+        for tab in self.get_tabs()? {
+            sync_fetch(&tab, active)?;
+        }
         Ok(())
     }
 
@@ -379,6 +1303,42 @@ impl BrowserSession {
         Ok(())
     }
 
+    /// Clear browser cookies, returning how many were removed.
+    ///
+    /// With `domain`, only cookies for that domain (and its subdomains) are deleted;
+    /// otherwise every cookie in the browser is cleared.
+    pub fn clear_cookies(&self, domain: Option<&str>) -> Result<usize> {
+        let tab = self.tab()?;
+        let all_cookies = || {
+            tab.call_method(Network::GetAllCookies(None))
+                .map(|r| r.cookies)
+                .map_err(|e| BrowserError::ChromeError(format!("Failed to get cookies: {}", e)))
+        };
+
+        let before = all_cookies()?;
+        match domain {
+            Some(domain) => {
+                for cookie in before.iter().filter(|c| cookie_domain_matches(&c.domain, domain)) {
+                    tab.call_method(Network::DeleteCookies {
+                        name: cookie.name.clone(),
+                        url: None,
+                        domain: Some(cookie.domain.clone()),
+                        path: Some(cookie.path.clone()),
+                        partition_key: None,
+                    })
+                    .map_err(|e| BrowserError::ChromeError(format!("Failed to delete cookie: {}", e)))?;
+                }
+            }
+            None => {
+                tab.call_method(Network::ClearBrowserCookies(None))
+                    .map_err(|e| BrowserError::ChromeError(format!("Failed to clear cookies: {}", e)))?;
+            }
+        }
+        let after = all_cookies()?;
+
+        Ok(before.len().saturating_sub(after.len()))
+    }
+
     /// Get console logs
     pub fn get_console_logs(&self) -> Result<Vec<ConsoleLog>> {
         let logs = self.console_logs.lock().map_err(|_| BrowserError::ToolExecutionFailed {
@@ -397,7 +1357,52 @@ impl BrowserSession {
         Ok(errors.clone())
     }
 
-    /// Close the browser
+    /// Get uncaught JavaScript exceptions
+    pub fn get_js_exceptions(&self) -> Result<Vec<JsException>> {
+        let exceptions = self.js_exceptions.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "get_js_exceptions".into(),
+            reason: "Failed to lock exceptions mutex".into()
+        })?;
+        Ok(exceptions.clone())
+    }
+
+    /// Get the JavaScript dialogs opened by the page, most recent last
+    pub fn get_dialogs(&self) -> Result<Vec<JavaScriptDialog>> {
+        let dialogs = self.dialogs.lock().map_err(|_| BrowserError::ToolExecutionFailed {
+            tool: "get_dialogs".into(),
+            reason: "Failed to lock dialogs mutex".into()
+        })?;
+        Ok(dialogs.clone())
+    }
+
+    /// Clear all captured console logs
+    pub fn clear_console_logs(&self) -> Result<()> {
+        self.console_logs
+            .lock()
+            .map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "clear_console_logs".into(),
+                reason: "Failed to lock logs mutex".into()
+            })?
+            .clear();
+        Ok(())
+    }
+
+    /// Clear all captured network errors
+    pub fn clear_network_errors(&self) -> Result<()> {
+        self.network_errors
+            .lock()
+            .map_err(|_| BrowserError::ToolExecutionFailed {
+                tool: "clear_network_errors".into(),
+                reason: "Failed to lock errors mutex".into()
+            })?
+            .clear();
+        Ok(())
+    }
+
+    /// Close all tabs of the browser.
+    ///
+    /// The Chrome process itself keeps running until the session is dropped;
+    /// use [`BrowserSession::quit`] to terminate it explicitly.
     pub fn close(&self) -> Result<()> {
         // Note: The Browser struct doesn't have a public close method in headless_chrome
         // The browser will be closed when the Browser instance is dropped
@@ -408,6 +1413,250 @@ impl BrowserSession {
         }
         Ok(())
     }
+
+    /// Terminate the browser process and wait for it to exit.
+    ///
+    /// Note: `quit` consumes the session, since the browser cannot be used afterwards.
+    /// For sessions created with [`BrowserSession::connect`] there is no owned process,
+    /// so this only disconnects from the browser. Fails if the process is still running
+    /// after it was killed.
+    pub fn quit(self) -> Result<()> {
+        let process_id = self.browser.get_process_id();
+        if let Err(e) = self.close() {
+            log::warn!("Failed to close tabs before quitting: {}", e);
+        }
+
+        // Dropping the last Browser handle kills the child process and blocks until it has
+        // exited, but other handles (e.g. a hung health check) can keep it alive
+        drop(self);
+
+        match process_id {
+            Some(pid) => kill_browser_process(pid),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Kill a launched browser process by id and wait for it to exit.
+///
+/// Use this when the owning [`BrowserSession`] is stuck on another thread and cannot be
+/// quit normally; otherwise prefer [`BrowserSession::quit`]. Fails if the process is still
+/// running after it was killed.
+pub fn kill_browser_process(pid: u32) -> Result<()> {
+    if process_running(pid) {
+        kill_process(pid)?;
+    }
+
+    let deadline = Instant::now() + QUIT_TIMEOUT;
+    while process_running(pid) {
+        if Instant::now() >= deadline {
+            return Err(BrowserError::ChromeError(format!(
+                "Browser process {} did not exit within {} ms",
+                pid,
+                QUIT_TIMEOUT.as_millis()
+            )));
+        }
+        thread::sleep(QUIT_POLL_INTERVAL);
+    }
+
+    log::info!("Browser process {} terminated", pid);
+    Ok(())
+}
+
+/// Forcefully kill the process `pid`
+fn kill_process(pid: u32) -> Result<()> {
+    #[cfg(windows)]
+    let status = std::process::Command::new("taskkill").args(["/F", "/T", "/PID", &pid.to_string()]).status();
+    #[cfg(not(windows))]
+    let status = std::process::Command::new("kill").args(["-KILL", &pid.to_string()]).status();
+
+    match status {
+        Ok(status) if status.success() || !process_running(pid) => Ok(()),
+        Ok(status) => Err(BrowserError::ChromeError(format!("Failed to kill browser process {}: {}", pid, status))),
+        Err(e) => Err(BrowserError::ChromeError(format!("Failed to kill browser process {}: {}", pid, e))),
+    }
+}
+
+/// Whether the process `pid` is still running. A process that has exited but was not reaped yet
+/// (a zombie, e.g. while another handle still owns the child) counts as gone.
+fn process_running(pid: u32) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        // The state follows the parenthesized command name, which may itself contain spaces
+        std::fs::read_to_string(format!("/proc/{}/stat", pid))
+            .is_ok_and(|stat| stat.rsplit_once(')').is_some_and(|(_, rest)| !rest.trim_start().starts_with('Z')))
+    }
+    #[cfg(all(unix, not(target_os = "linux")))]
+    {
+        std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", &pid.to_string()])
+            .output()
+            .is_ok_and(|out| out.status.success() && !String::from_utf8_lossy(&out.stdout).trim_start().starts_with('Z'))
+    }
+    #[cfg(windows)]
+    {
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+            .output()
+            .is_ok_and(|out| String::from_utf8_lossy(&out.stdout).contains(&pid.to_string()))
+    }
+}
+
+/// Evaluate `js` in a new isolated world of the main frame of `tab`. Each call gets its own
+/// world, so scripts can declare `const`s without clashing with the page or earlier calls.
+pub(crate) fn evaluate_in_isolated_world(tab: &Tab, js: &str) -> Result<RemoteObject> {
+    let frame_id = tab
+        .call_method(Page::GetFrameTree(None))
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to get frame tree: {}", e)))?
+        .frame_tree
+        .frame
+        .id;
+    let world = tab
+        .call_method(Page::CreateIsolatedWorld {
+            frame_id,
+            world_name: Some(ISOLATED_WORLD_NAME.to_string()),
+            grant_univeral_access: None,
+        })
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to create isolated world: {}", e)))?;
+
+    let response = tab
+        .call_method(Runtime::Evaluate {
+            expression: js.to_string(),
+            object_group: None,
+            include_command_line_api: Some(false),
+            silent: Some(false),
+            context_id: Some(world.execution_context_id),
+            return_by_value: Some(false),
+            generate_preview: Some(true),
+            user_gesture: Some(false),
+            await_promise: Some(false),
+            throw_on_side_effect: None,
+            timeout: None,
+            disable_breaks: None,
+            repl_mode: None,
+            allow_unsafe_eval_blocked_by_csp: None,
+            unique_context_id: None,
+            serialization_options: None,
+        })
+        .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+
+    if let Some(details) = response.exception_details {
+        let description = details.exception.as_ref().and_then(|e| e.description.clone());
+        return Err(BrowserError::EvaluationFailed(match description {
+            Some(description) => format!("{} {}", details.text, description),
+            None => details.text,
+        }));
+    }
+    Ok(response.result)
+}
+
+/// Fail early with the offending path when `chrome_path` doesn't point to a file
+fn check_chrome_path(path: &Path) -> Result<()> {
+    if path.is_file() {
+        Ok(())
+    } else if path.exists() {
+        Err(BrowserError::LaunchFailed(format!("Chrome path {} is not a file", path.display())))
+    } else {
+        Err(BrowserError::LaunchFailed(format!("Chrome binary not found at {}", path.display())))
+    }
+}
+
+/// Make sure the user data directory exists, creating it (and its parents) if needed
+fn prepare_user_data_dir(dir: &Path) -> Result<()> {
+    if dir.exists() && !dir.is_dir() {
+        return Err(BrowserError::LaunchFailed(format!("User data dir {} is not a directory", dir.display())));
+    }
+    std::fs::create_dir_all(dir)
+        .map_err(|e| BrowserError::LaunchFailed(format!("Failed to create user data dir {}: {}", dir.display(), e)))
+}
+
+/// Run `attempt` until it succeeds, retrying up to `retries` more times with exponential backoff
+/// as long as the failure is transient (see [`is_transient_launch_error`])
+fn retry_launch<T, E: std::fmt::Display>(
+    retries: u32,
+    backoff: Duration,
+    mut attempt: impl FnMut() -> std::result::Result<T, E>,
+) -> Result<T> {
+    let mut failures = 0;
+    loop {
+        let reason = match attempt() {
+            Ok(value) => return Ok(value),
+            Err(e) => e.to_string(),
+        };
+        if failures >= retries || !is_transient_launch_error(&reason) {
+            return Err(BrowserError::LaunchFailed(reason));
+        }
+        let delay = backoff * 2u32.pow(failures);
+        failures += 1;
+        log::warn!("Browser launch failed (attempt {}/{}): {}; retrying in {:?}", failures, retries + 1, reason, delay);
+        std::thread::sleep(delay);
+    }
+}
+
+/// Whether a launch error is worth retrying: Chrome started (or tried to) but the debugging
+/// connection couldn't be established. A missing or unusable binary fails the same way every time.
+fn is_transient_launch_error(reason: &str) -> bool {
+    let reason = reason.to_lowercase();
+    ["websocket url", "available ports", "already in use", "timed out", "connection"]
+        .iter()
+        .any(|pattern| reason.contains(pattern))
+}
+
+/// Whether a cookie's domain is `domain` or one of its subdomains (leading dots are ignored)
+fn cookie_domain_matches(cookie_domain: &str, domain: &str) -> bool {
+    let cookie_domain = cookie_domain.trim_start_matches('.');
+    let domain = domain.trim_start_matches('.');
+    cookie_domain.eq_ignore_ascii_case(domain)
+        || cookie_domain.to_ascii_lowercase().ends_with(&format!(".{}", domain.to_ascii_lowercase()))
+}
+
+/// Press OK or Cancel on the dialog open in `tab`
+fn respond_to_dialog(tab: &Tab, policy: &DialogPolicy) -> Result<()> {
+    let dialog = tab.get_dialog();
+    let result = if policy.accept { dialog.accept(policy.prompt_text.clone()) } else { dialog.dismiss() };
+    result.map_err(|e| BrowserError::ChromeError(format!("Failed to handle dialog: {}", e)))
+}
+
+/// First match for `css_selector` in the documents of the tab's iframes, in document order
+fn find_element_in_frames<'a>(tab: &'a Tab, css_selector: &str) -> Option<headless_chrome::Element<'a>> {
+    // Most pages have no frames; skip fetching the whole DOM for them
+    tab.find_element("iframe, frame").ok()?;
+
+    // The protocol's "whole subtree" depth is -1, which the generated u32 field can't hold
+    let depth = Some(i32::MAX as u32);
+    let root = tab.call_method(DOM::GetDocument { depth, pierce: Some(true) }).ok()?.root;
+    let mut documents = Vec::new();
+    collect_frame_documents(&root, &mut documents);
+    documents.into_iter().find_map(|node_id| tab.run_query_selector_on_node(node_id, css_selector).ok())
+}
+
+/// Node IDs of the documents of all (nested) frames below `node`
+fn collect_frame_documents(node: &DOM::Node, documents: &mut Vec<DOM::NodeId>) {
+    if let Some(document) = &node.content_document {
+        documents.push(document.node_id);
+        collect_frame_documents(document, documents);
+    }
+    for child in node.children.iter().chain(node.shadow_roots.iter()).flatten() {
+        collect_frame_documents(child, documents);
+    }
+}
+
+/// JavaScript truthiness of an evaluation result
+fn is_truthy(object: &RemoteObject) -> bool {
+    match (&object.Type, &object.subtype) {
+        (RemoteObjectType::Undefined, _) => false,
+        (RemoteObjectType::Object, Some(RemoteObjectSubtype::Null)) => false,
+        (RemoteObjectType::Object | RemoteObjectType::Function | RemoteObjectType::Symbol, _) => true,
+        _ => match (&object.value, &object.unserializable_value) {
+            (Some(serde_json::Value::Bool(b)), _) => *b,
+            (Some(serde_json::Value::Number(n)), _) => n.as_f64() != Some(0.0),
+            (Some(serde_json::Value::String(s)), _) => !s.is_empty(),
+            (Some(serde_json::Value::Null), _) => false,
+            (Some(_), _) => true,
+            (None, Some(unserializable)) => !matches!(unserializable.as_str(), "NaN" | "-0" | "0n"),
+            (None, None) => false,
+        },
+    }
 }
 
 impl Default for BrowserSession {
@@ -416,10 +1665,36 @@ impl Default for BrowserSession {
     }
 }
 
+/// Chrome flags to add to headless_chrome's defaults and default flags to drop. In stealth mode
+/// the automation flags are hidden to prevent detection by anti-bot services.
+fn launch_flags(stealth: bool, extra_args: &[String]) -> (Vec<&OsStr>, Vec<&OsStr>) {
+    let mut args = Vec::new();
+    let mut ignored_default_args = Vec::new();
+    if stealth {
+        ignored_default_args.push(OsStr::new("--enable-automation"));
+        args.push(OsStr::new("--disable-blink-features=AutomationControlled"));
+    }
+    args.extend(extra_args.iter().map(OsStr::new));
+    (args, ignored_default_args)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_launch_flags_stealth() {
+        let extra = vec!["--disable-gpu".to_string()];
+
+        let (args, ignored) = launch_flags(true, &extra);
+        assert_eq!(args, [OsStr::new("--disable-blink-features=AutomationControlled"), OsStr::new("--disable-gpu")]);
+        assert_eq!(ignored, [OsStr::new("--enable-automation")]);
+
+        let (args, ignored) = launch_flags(false, &extra);
+        assert_eq!(args, [OsStr::new("--disable-gpu")]);
+        assert!(ignored.is_empty());
+    }
+
     #[test]
     fn test_launch_options_builder() {
         let opts = LaunchOptions::new().headless(true).window_size(800, 600);
@@ -429,12 +1704,81 @@ mod tests {
         assert_eq!(opts.window_height, 600);
     }
 
+    #[test]
+    fn test_retry_launch_respects_retry_count() {
+        let mut calls = 0;
+        let result: Result<()> = retry_launch(2, Duration::ZERO, || {
+            calls += 1;
+            Err("Chrome launched, but didn't give us a WebSocket URL before we timed out")
+        });
+        assert!(matches!(result, Err(BrowserError::LaunchFailed(_))));
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result = retry_launch(2, Duration::ZERO, || {
+            calls += 1;
+            if calls < 2 { Err("There are no available ports between 8000 and 9000 for debugging") } else { Ok(calls) }
+        });
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_retry_launch_skips_bad_path_errors() {
+        let mut calls = 0;
+        let result: Result<()> = retry_launch(2, Duration::ZERO, || {
+            calls += 1;
+            Err("No such file or directory (os error 2)")
+        });
+        assert!(matches!(result, Err(BrowserError::LaunchFailed(reason)) if reason.contains("os error 2")));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_check_chrome_path() {
+        let dir = std::env::temp_dir().join(format!("browser-use-chrome-path-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let binary = dir.join("chrome");
+        std::fs::write(&binary, b"").unwrap();
+
+        assert!(check_chrome_path(&binary).is_ok());
+        let err = check_chrome_path(&dir.join("missing")).unwrap_err();
+        assert!(matches!(&err, BrowserError::LaunchFailed(reason) if reason.contains("missing")));
+        let err = check_chrome_path(&dir).unwrap_err();
+        assert!(matches!(&err, BrowserError::LaunchFailed(reason) if reason.contains("not a file")));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prepare_user_data_dir() {
+        let dir = std::env::temp_dir().join(format!("browser-use-user-data-{}", std::process::id()));
+        let profile = dir.join("nested").join("profile");
+
+        prepare_user_data_dir(&profile).unwrap();
+        assert!(profile.is_dir());
+        // Existing directories are left alone
+        prepare_user_data_dir(&profile).unwrap();
+
+        let file = dir.join("file");
+        std::fs::write(&file, b"").unwrap();
+        let err = prepare_user_data_dir(&file).unwrap_err();
+        assert!(matches!(&err, BrowserError::LaunchFailed(reason) if reason.contains("not a directory")));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_connection_options() {
         let opts = ConnectionOptions::new("ws://localhost:9222").timeout(5000);
 
         assert_eq!(opts.ws_url, "ws://localhost:9222");
         assert_eq!(opts.timeout, 5000);
+        assert!(opts.session.user_agent.is_none());
+
+        let opts = opts.session(LaunchOptions::new().user_agent("TestAgent/1.0").timezone("Asia/Tokyo").stealth(true));
+        assert_eq!(opts.session.user_agent.as_deref(), Some("TestAgent/1.0"));
+        assert_eq!(opts.session.timezone.as_deref(), Some("Asia/Tokyo"));
+        assert!(opts.session.stealth);
     }
 
     #[test]
@@ -466,8 +1810,7 @@ mod tests {
     #[test]
     #[ignore]
     fn test_new_tab() {
-        let mut session =
-            BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
 
         let result = session.new_tab();
         assert!(result.is_ok());
@@ -475,4 +1818,179 @@ mod tests {
         let tabs = session.get_tabs().expect("Failed to get tabs");
         assert!(tabs.len() >= 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(unix)]
+    fn test_process_running() {
+        assert!(process_running(std::process::id()));
+
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        assert!(!process_running(pid));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_quit_terminates_process() {
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        let pid = session.browser().get_process_id().expect("Launched browser should have a process");
+
+        session.quit().expect("Failed to quit browser");
+
+        // Signal 0 only checks whether the process still exists
+        #[cfg(unix)]
+        assert!(!std::process::Command::new("kill").args(["-0", &pid.to_string()]).status().unwrap().success());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_quit_kills_process_held_by_another_handle() {
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        let pid = session.browser().get_process_id().expect("Launched browser should have a process");
+
+        // E.g. the thread of a health check that never got an answer
+        let other_handle = session.browser().clone();
+        session.quit().expect("Failed to quit browser");
+        assert!(!process_running(pid));
+        drop(other_handle);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_recovers_after_all_tabs_closed() {
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+        session.close().expect("Failed to close tabs");
+        // Give the browser a moment to report the closed targets
+        std::thread::sleep(Duration::from_millis(500));
+
+        session.navigate("data:text/html,<h1>Recovered</h1>").expect("Failed to navigate after closing all tabs");
+        session.wait_for_navigation().expect("Failed to wait for navigation");
+
+        assert!(session.tab().unwrap().get_url().contains("Recovered"));
+    }
+
+    fn remote_object(json: serde_json::Value) -> RemoteObject {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn dom_node(node_id: u32, name: &str, extra: serde_json::Value) -> serde_json::Value {
+        let mut node = serde_json::json!({
+            "nodeId": node_id,
+            "backendNodeId": node_id,
+            "nodeType": 1,
+            "nodeName": name,
+            "localName": name.to_lowercase(),
+            "nodeValue": "",
+        });
+        node.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        node
+    }
+
+    #[test]
+    fn test_collect_frame_documents() {
+        let inner_frame = dom_node(
+            5,
+            "IFRAME",
+            serde_json::json!({ "contentDocument": dom_node(6, "#document", serde_json::json!({})) }),
+        );
+        let outer_document = dom_node(3, "#document", serde_json::json!({ "children": [inner_frame] }));
+        let root = dom_node(
+            1,
+            "#document",
+            serde_json::json!({
+                "children": [
+                    dom_node(2, "IFRAME", serde_json::json!({ "contentDocument": outer_document })),
+                    dom_node(4, "DIV", serde_json::json!({})),
+                ]
+            }),
+        );
+
+        let mut documents = Vec::new();
+        collect_frame_documents(&serde_json::from_value(root).unwrap(), &mut documents);
+        assert_eq!(documents, vec![3, 6]);
+    }
+
+    #[test]
+    fn test_cookie_domain_matches() {
+        assert!(cookie_domain_matches("example.com", "example.com"));
+        assert!(cookie_domain_matches(".example.com", "example.com"));
+        assert!(cookie_domain_matches("www.example.com", ".Example.com"));
+        assert!(!cookie_domain_matches("notexample.com", "example.com"));
+        assert!(!cookie_domain_matches("example.com", "www.example.com"));
+    }
+
+    #[test]
+    fn test_is_truthy() {
+        assert!(is_truthy(&remote_object(serde_json::json!({ "type": "boolean", "value": true }))));
+        assert!(!is_truthy(&remote_object(serde_json::json!({ "type": "boolean", "value": false }))));
+        assert!(is_truthy(&remote_object(serde_json::json!({ "type": "number", "value": 3 }))));
+        assert!(!is_truthy(&remote_object(serde_json::json!({ "type": "number", "value": 0 }))));
+        assert!(!is_truthy(&remote_object(serde_json::json!({ "type": "number", "unserializableValue": "NaN" }))));
+        assert!(is_truthy(&remote_object(serde_json::json!({ "type": "string", "value": "ready" }))));
+        assert!(!is_truthy(&remote_object(serde_json::json!({ "type": "string", "value": "" }))));
+        assert!(!is_truthy(&remote_object(serde_json::json!({ "type": "undefined" }))));
+        assert!(!is_truthy(&remote_object(serde_json::json!({ "type": "object", "subtype": "null", "value": null }))));
+        assert!(is_truthy(&remote_object(serde_json::json!({ "type": "object", "subtype": "node", "objectId": "1" }))));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_wait_for_function() {
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        session
+            .navigate("data:text/html,<script>setTimeout(function () { window.ready = 'yes'; }, 200);</script>")
+            .expect("Failed to navigate");
+
+        let value = session.wait_for_function("window.ready", 5000, 50).expect("Expression should become truthy");
+        assert_eq!(value, serde_json::json!("yes"));
+
+        let result = session.wait_for_function("window.neverSet", 200, 50);
+        assert!(matches!(result, Err(BrowserError::Timeout(msg)) if msg.contains("window.neverSet")));
+
+        // Syntax errors fail right away instead of running into the timeout
+        let started = Instant::now();
+        let result = session.wait_for_function("document.querySelector('##bad') !== null", 5000, 50);
+        assert!(matches!(result, Err(BrowserError::InvalidArgument(_))), "Expected invalid argument, got {:?}", result);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_wait_for_network_idle_leaves_no_page_state() {
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        session.navigate("data:text/html,<h1>Idle</h1>").expect("Failed to navigate");
+
+        session.wait_for_network_idle(5000, 100).expect("Page should become idle");
+
+        let globals = session
+            .tab()
+            .unwrap()
+            .evaluate("Object.keys(window).filter(k => k.startsWith('__browserUse')).length", false)
+            .unwrap();
+        assert_eq!(globals.value, Some(serde_json::json!(0)));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_wait_for_dom_stable() {
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        session
+            .navigate(
+                "data:text/html,<p id='n'>0</p><script>let i = setInterval(function () { \
+                 const n = document.getElementById('n'); n.textContent = Number(n.textContent) + 1; \
+                 if (n.textContent === '5') clearInterval(i); }, 50);</script>",
+            )
+            .expect("Failed to navigate");
+        session.wait_for_navigation().expect("Failed to wait for navigation");
+
+        assert!(session.wait_for_dom_stable(300, 5000).expect("Failed to wait for a stable DOM"));
+        let count = session.tab().unwrap().evaluate("document.getElementById('n').textContent", false).unwrap();
+        assert_eq!(count.value.unwrap(), "5");
+
+        // A page that never stops mutating times out without an error
+        session.tab().unwrap().evaluate("setInterval(() => document.body.append('.'), 20)", false).unwrap();
+        assert!(!session.wait_for_dom_stable(300, 600).expect("Timeout should not be an error"));
+    }
+}
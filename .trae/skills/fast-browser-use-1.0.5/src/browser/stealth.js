@@ -0,0 +1,61 @@
+// Hide the most common signs of an automated Chrome. Runs before any page script.
+(() => {
+  const define = (object, property, getter) => {
+    try {
+      Object.defineProperty(object, property, { get: getter, configurable: true });
+    } catch (e) {
+      // Property is locked down; leave it as is
+    }
+  };
+
+  // navigator.webdriver is true under automation; real browsers don't have it at all
+  try {
+    delete Object.getPrototypeOf(navigator).webdriver;
+  } catch (e) {}
+  define(navigator, "webdriver", () => undefined);
+
+  // Headless Chrome reports no plugins
+  if (navigator.plugins.length === 0) {
+    const plugins = [
+      { name: "PDF Viewer", filename: "internal-pdf-viewer", description: "Portable Document Format" },
+      { name: "Chrome PDF Viewer", filename: "internal-pdf-viewer", description: "Portable Document Format" },
+      { name: "Chromium PDF Viewer", filename: "internal-pdf-viewer", description: "Portable Document Format" },
+    ];
+    const list = Object.create(PluginArray.prototype);
+    plugins.forEach((plugin, i) => {
+      const entry = Object.create(Plugin.prototype);
+      define(entry, "name", () => plugin.name);
+      define(entry, "filename", () => plugin.filename);
+      define(entry, "description", () => plugin.description);
+      define(entry, "length", () => 0);
+      define(list, i, () => entry);
+    });
+    define(list, "length", () => plugins.length);
+    list.item = (i) => list[i] || null;
+    list.namedItem = (name) => list[plugins.findIndex((p) => p.name === name)] || null;
+    list.refresh = () => {};
+    define(navigator, "plugins", () => list);
+  }
+
+  // Headless Chrome may report an empty language list
+  if (!navigator.languages || navigator.languages.length === 0) {
+    const language = navigator.language || "en-US";
+    const languages = Object.freeze([language, language.split("-")[0]].filter((l, i, all) => all.indexOf(l) === i));
+    define(navigator, "languages", () => languages);
+  }
+
+  // Regular Chrome exposes window.chrome with a runtime object, headless Chrome doesn't
+  const chromeObject = window.chrome || {};
+  if (!window.chrome) {
+    define(window, "chrome", () => chromeObject);
+  }
+  if (!chromeObject.runtime) {
+    try {
+      chromeObject.runtime = {
+        connect: () => ({ onMessage: { addListener() {} }, postMessage() {}, disconnect() {} }),
+        sendMessage: () => {},
+        id: undefined,
+      };
+    } catch (e) {}
+  }
+})();
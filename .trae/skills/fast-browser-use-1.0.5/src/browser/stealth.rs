@@ -0,0 +1,22 @@
+//! Patches that hide common signs of automation from page scripts
+//!
+//! Stealth is best-effort: it covers the usual `navigator.webdriver`, plugin, language and
+//! `chrome.runtime` checks, but dedicated bot detectors can still tell an automated Chrome apart.
+
+use crate::error::{BrowserError, Result};
+use headless_chrome::{Tab, protocol::cdp::Page::AddScriptToEvaluateOnNewDocument};
+
+const STEALTH_JS: &str = include_str!("stealth.js");
+
+/// Run the stealth patches before any page script in every new document of `tab`, and in its
+/// current document right away. Returns the identifier of the registered script.
+pub(crate) fn install_stealth_script(tab: &Tab) -> Result<String> {
+    tab.call_method(AddScriptToEvaluateOnNewDocument {
+        source: STEALTH_JS.to_string(),
+        world_name: None,
+        include_command_line_api: None,
+        run_immediately: Some(true),
+    })
+    .map(|registered| registered.identifier)
+    .map_err(|e| BrowserError::ChromeError(format!("Failed to install stealth script: {}", e)))
+}
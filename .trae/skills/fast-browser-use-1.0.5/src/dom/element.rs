@@ -15,6 +15,10 @@ pub struct AriaNode {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<usize>,
 
+    /// Stable ref of the element, kept across snapshots as long as the element stays in the DOM
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub element_ref: Option<String>,
+
     /// Child nodes (can be AriaNode or text strings)
     #[serde(default)]
     pub children: Vec<AriaChild>,
@@ -82,7 +86,7 @@ pub enum AriaPressed {
 }
 
 /// Box/visibility information for an element
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct BoxInfo {
     /// Whether the element is visible (non-zero bounding box)
     #[serde(default)]
@@ -106,12 +110,6 @@ pub struct Rect {
     pub height: f64,
 }
 
-impl Default for BoxInfo {
-    fn default() -> Self {
-        Self { visible: false, cursor: None, rect: None }
-    }
-}
-
 impl AriaNode {
     /// Create a new AriaNode with minimal fields
     pub fn new(role: impl Into<String>, name: impl Into<String>) -> Self {
@@ -119,6 +117,7 @@ impl AriaNode {
             role: role.into(),
             name: name.into(),
             index: None,
+            element_ref: None,
             children: Vec::new(),
             props: HashMap::new(),
             box_info: BoxInfo::default(),
@@ -143,6 +142,12 @@ impl AriaNode {
         self
     }
 
+    /// Builder: set stable element ref
+    pub fn with_ref(mut self, element_ref: impl Into<String>) -> Self {
+        self.element_ref = Some(element_ref.into());
+        self
+    }
+
     /// Builder: add a child node
     pub fn with_child(mut self, child: AriaChild) -> Self {
         self.children.push(child);
@@ -198,7 +203,7 @@ impl AriaNode {
 
     /// Check if this node has pointer cursor
     pub fn has_pointer_cursor(&self) -> bool {
-        self.box_info.cursor.as_ref().map_or(false, |c| c == "pointer")
+        self.box_info.cursor.as_ref().is_some_and(|c| c == "pointer")
     }
 
     /// Check if this is a fragment or iframe
@@ -246,10 +251,10 @@ impl AriaNode {
         }
 
         for child in &self.children {
-            if let AriaChild::Node(node) = child {
-                if let Some(found) = node.find_by_index(index) {
-                    return Some(found);
-                }
+            if let AriaChild::Node(node) = child
+                && let Some(found) = node.find_by_index(index)
+            {
+                return Some(found);
             }
         }
 
@@ -263,10 +268,10 @@ impl AriaNode {
         }
 
         for child in &mut self.children {
-            if let AriaChild::Node(node) = child {
-                if let Some(found) = node.find_by_index_mut(index) {
-                    return Some(found);
-                }
+            if let AriaChild::Node(node) = child
+                && let Some(found) = node.find_by_index_mut(index)
+            {
+                return Some(found);
             }
         }
 
@@ -4,7 +4,47 @@ JSON.stringify(
   (function () {
     "use strict";
 
+    // Which stable attribute buildSelector prefers: "css", "data-testid" or "aria-label"
+    const SELECTOR_STRATEGY = "__SELECTOR_STRATEGY__";
+    const REF_ATTRIBUTE = "data-browseruse-ref";
+    const REF_COUNTER_ATTRIBUTE = "data-browseruse-ref-counter";
+    // Node budget (0 = unlimited). Once it is spent only actionable elements
+    // are kept, up to the same number again, after which traversal stops
+    const MAX_NODES = __MAX_NODES__;
+    const ACTIONABLE_ROLES = [
+      "button",
+      "link",
+      "textbox",
+      "searchbox",
+      "checkbox",
+      "radio",
+      "combobox",
+      "listbox",
+      "option",
+      "menuitem",
+      "menuitemcheckbox",
+      "menuitemradio",
+      "tab",
+      "slider",
+      "spinbutton",
+      "switch",
+    ];
+
     let currentIndex = 0;
+    let nodeCount = 0;
+    let truncated = false;
+
+    function overBudget() {
+      return MAX_NODES > 0 && nodeCount >= MAX_NODES;
+    }
+
+    function isActionable(ariaNode) {
+      return (
+        ariaNode.index !== undefined &&
+        (ACTIONABLE_ROLES.includes(ariaNode.role) ||
+          ariaNode.box.cursor === "pointer")
+      );
+    }
 
     // Helper: normalize whitespace
     function normalizeWhiteSpace(text) {
@@ -363,6 +403,25 @@ JSON.stringify(
 
       // Assign sequential index
       ariaNode.index = currentIndex++;
+
+      // Assign a ref that survives re-snapshots: reuse the element's ref from
+      // an earlier extraction, otherwise tag it with a fresh one
+      const element = ariaNode.element;
+      if (element && element.setAttribute) {
+        let ref = element.getAttribute(REF_ATTRIBUTE);
+        if (!ref) {
+          // The counter lives in the DOM rather than on window: each extraction
+          // runs in a fresh isolated world, but the document is shared
+          const counter =
+            Number(
+              document.documentElement.getAttribute(REF_COUNTER_ATTRIBUTE) || 0,
+            ) + 1;
+          document.documentElement.setAttribute(REF_COUNTER_ATTRIBUTE, counter);
+          ref = "e" + counter;
+          element.setAttribute(REF_ATTRIBUTE, ref);
+        }
+        ariaNode.ref = ref;
+      }
     }
 
     // Convert element to AriaNode
@@ -489,10 +548,20 @@ JSON.stringify(
       if (visited.has(node)) return;
       visited.add(node);
 
+      // Stop once the budget is spent twice over
+      if (MAX_NODES > 0 && nodeCount >= 2 * MAX_NODES) {
+        truncated = true;
+        return;
+      }
+
       // Handle text nodes
       if (node.nodeType === 3) {
         // TEXT_NODE
         if (!parentElementVisible) return;
+        if (overBudget()) {
+          truncated = true;
+          return;
+        }
 
         const text = node.nodeValue;
         // Skip text inside textbox
@@ -524,10 +593,18 @@ JSON.stringify(
         }
       }
 
-      // Convert to aria node
-      const childAriaNode = toAriaNode(element);
+      // Convert to aria node; past the budget only actionable elements are kept,
+      // attached to the closest kept ancestor
+      let childAriaNode = toAriaNode(element);
+      if (childAriaNode && overBudget() && !isActionable(childAriaNode)) {
+        truncated = true;
+        // Give back the index it was just assigned so indices stay dense
+        if (childAriaNode.index !== undefined) currentIndex--;
+        childAriaNode = null;
+      }
       if (childAriaNode) {
         ariaNode.children.push(childAriaNode);
+        nodeCount++;
       }
 
       // Process element (add CSS content, children, etc.)
@@ -704,6 +781,7 @@ JSON.stringify(
 
       // Include index if present
       if (ariaNode.index !== undefined) result.index = ariaNode.index;
+      if (ariaNode.ref !== undefined) result.ref = ariaNode.ref;
       if (ariaNode.active) result.active = true;
       if (ariaNode.checked !== undefined) result.checked = ariaNode.checked;
       if (ariaNode.disabled !== undefined) result.disabled = ariaNode.disabled;
@@ -731,8 +809,13 @@ JSON.stringify(
       return result;
     }
 
-    // Collect selectors and iframe indices
-    function collectSelectorsAndIframes(ariaNode, selectors, iframeIndices) {
+    // Collect selectors, refs and iframe indices
+    function collectSelectorsAndIframes(
+      ariaNode,
+      selectors,
+      refs,
+      iframeIndices,
+    ) {
       if (ariaNode.index !== undefined && ariaNode.element) {
         // Store CSS selector for element at its index position
         const selector = buildSelector(ariaNode.element);
@@ -742,6 +825,10 @@ JSON.stringify(
         }
         selectors[ariaNode.index] = selector;
 
+        if (ariaNode.ref !== undefined) {
+          refs[ariaNode.ref] = "[" + REF_ATTRIBUTE + '="' + ariaNode.ref + '"]';
+        }
+
         if (ariaNode.role === "iframe") {
           iframeIndices.push(ariaNode.index);
         }
@@ -749,13 +836,48 @@ JSON.stringify(
 
       for (const child of ariaNode.children) {
         if (typeof child !== "string") {
-          collectSelectorsAndIframes(child, selectors, iframeIndices);
+          collectSelectorsAndIframes(child, selectors, refs, iframeIndices);
         }
       }
     }
 
+    // Selector for an element's stable attribute under the current strategy,
+    // if the element has one and it is unique in the document
+    function stableAttributeSelector(element) {
+      const attribute =
+        SELECTOR_STRATEGY === "data-testid"
+          ? "data-testid"
+          : SELECTOR_STRATEGY === "aria-label"
+            ? "aria-label"
+            : null;
+      const value = attribute && element.getAttribute(attribute);
+      if (!value) {
+        return null;
+      }
+
+      const selector =
+        element.tagName.toLowerCase() +
+        "[" +
+        attribute +
+        '="' +
+        CSS.escape(value) +
+        '"]';
+      try {
+        return document.querySelectorAll(selector).length === 1
+          ? selector
+          : null;
+      } catch (e) {
+        return null;
+      }
+    }
+
     // Build CSS selector for element
     function buildSelector(element) {
+      const stable = stableAttributeSelector(element);
+      if (stable) {
+        return stable;
+      }
+
       if (element.id) {
         return "#" + element.id;
       }
@@ -764,6 +886,13 @@ JSON.stringify(
       let current = element;
 
       while (current && current !== document.body) {
+        // Anchor the path at the closest ancestor with a stable attribute
+        const anchor = current !== element && stableAttributeSelector(current);
+        if (anchor) {
+          path.unshift(anchor);
+          break;
+        }
+
         let selector = current.tagName.toLowerCase();
 
         if (current.className && typeof current.className === "string") {
@@ -797,8 +926,10 @@ JSON.stringify(
       const rootElement = document.body || document.documentElement;
       const visited = new Set();
 
-      // Reset index counter
+      // Reset index counter and node budget
       currentIndex = 0;
+      nodeCount = 0;
+      truncated = false;
 
       // Create root fragment node
       const snapshot = {
@@ -818,10 +949,11 @@ JSON.stringify(
       normalizeStringChildren(snapshot);
       normalizeGenericRoles(snapshot);
 
-      // Collect selectors and iframe indices
+      // Collect selectors, refs and iframe indices
       const selectors = [];
+      const refs = {};
       const iframeIndices = [];
-      collectSelectorsAndIframes(snapshot, selectors, iframeIndices);
+      collectSelectorsAndIframes(snapshot, selectors, refs, iframeIndices);
 
       // Serialize and return
       const serialized = serializeAriaNode(snapshot);
@@ -829,7 +961,9 @@ JSON.stringify(
       return {
         root: serialized,
         selectors: selectors,
+        refs: refs,
         iframeIndices: iframeIndices,
+        truncated: truncated,
       };
     } catch (error) {
       return {
@@ -842,6 +976,7 @@ JSON.stringify(
           box_info: { visible: false },
         },
         selectors: [],
+        refs: {},
         iframeIndices: [],
       };
     }
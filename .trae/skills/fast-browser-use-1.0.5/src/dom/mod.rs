@@ -10,5 +10,5 @@ pub mod tree;
 pub mod yaml;
 
 pub use element::{AriaChild, AriaNode, BoundingBox, ElementNode};
-pub use tree::DomTree;
+pub use tree::{DomTree, ExtractOptions, SelectorStrategy};
 pub use yaml::{yaml_escape_key_if_needed, yaml_escape_value_if_needed};
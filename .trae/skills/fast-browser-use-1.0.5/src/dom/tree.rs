@@ -1,7 +1,63 @@
-use crate::{dom::element::{AriaChild, AriaNode},
+use crate::{browser::session::evaluate_in_isolated_world,
+            dom::element::{AriaChild, AriaNode},
             error::{BrowserError, Result}};
 use headless_chrome::Tab;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, thread, time::Duration};
+
+/// Number of times the extraction script is run before an empty or unparseable result is an error
+const EXTRACT_ATTEMPTS: usize = 3;
+
+/// Delay between extraction attempts
+const EXTRACT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Attribute the extraction script tags indexed elements with to give them a stable ref
+const REF_ATTRIBUTE: &str = "data-browseruse-ref";
+
+/// How the extraction script builds the CSS selector stored for each indexed element
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectorStrategy {
+    /// Element id, otherwise a structural path of tag, first class and `:nth-child`
+    #[default]
+    Css,
+
+    /// Prefer a unique `data-testid` on the element or its closest ancestor, then fall back to `Css`
+    DataTestId,
+
+    /// Prefer a unique `aria-label` on the element or its closest ancestor, then fall back to `Css`
+    AriaLabel,
+}
+
+impl SelectorStrategy {
+    /// Name of the strategy as understood by the extraction script
+    fn as_js(self) -> &'static str {
+        match self {
+            SelectorStrategy::Css => "css",
+            SelectorStrategy::DataTestId => "data-testid",
+            SelectorStrategy::AriaLabel => "aria-label",
+        }
+    }
+}
+
+/// Options controlling how much of the page the extraction script walks
+#[derive(Debug, Clone, Default)]
+pub struct ExtractOptions {
+    /// Cap on the number of nodes in the tree, to bound the cost of extracting huge pages.
+    ///
+    /// Once the budget is spent, text and non-interactive elements are skipped and only
+    /// actionable elements (buttons, links, form controls, ...) are kept, up to the same number
+    /// again, so interactive elements are prioritized over content. `DomTree::truncated` reports
+    /// whether anything was left out. `None` extracts the whole page.
+    pub max_nodes: Option<usize>,
+}
+
+impl ExtractOptions {
+    /// Builder: set the node budget
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+}
 
 /// Represents the ARIA snapshot of a web page
 /// Based on Playwright's AriaSnapshot structure
@@ -13,8 +69,14 @@ pub struct DomTree {
     /// Array of CSS selectors indexed by element index
     pub selectors: Vec<String>,
 
+    /// CSS selectors keyed by stable element ref (see `AriaNode::element_ref`)
+    pub refs: HashMap<String, String>,
+
     /// List of iframe indices (for multi-frame snapshots)
     pub iframe_indices: Vec<usize>,
+
+    /// Whether extraction stopped early because `ExtractOptions::max_nodes` was reached
+    pub truncated: bool,
 }
 
 /// Snapshot extraction response from JavaScript
@@ -22,14 +84,34 @@ pub struct DomTree {
 struct SnapshotResponse {
     root: AriaNode,
     selectors: Vec<String>,
+    #[serde(default)]
+    refs: HashMap<String, String>,
     #[serde(rename = "iframeIndices")]
     iframe_indices: Vec<usize>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+/// Parse the value returned by the extraction script, a JSON string of a [`SnapshotResponse`]
+fn parse_snapshot(value: Option<serde_json::Value>) -> Result<SnapshotResponse> {
+    // Get the JSON string value
+    let json_value =
+        value.ok_or_else(|| BrowserError::DomParseFailed("No value returned from DOM extraction".to_string()))?;
+
+    // The JavaScript returns a JSON string, so we need to parse it as a string first
+    let json_str: String = serde_json::from_value(json_value)
+        .map_err(|e| BrowserError::DomParseFailed(format!("Failed to get JSON string: {}", e)))?;
+
+    // Then parse the JSON string into SnapshotResponse
+    serde_json::from_str(&json_str)
+        .map_err(|e| BrowserError::DomParseFailed(format!("Failed to parse snapshot JSON: {}", e)))
 }
 
 impl DomTree {
     /// Create a new DomTree from an AriaNode
     pub fn new(root: AriaNode) -> Self {
-        let mut tree = Self { root, selectors: Vec::new(), iframe_indices: Vec::new() };
+        let mut tree =
+            Self { root, selectors: Vec::new(), refs: HashMap::new(), iframe_indices: Vec::new(), truncated: false };
         tree.rebuild_maps();
         tree
     }
@@ -40,30 +122,49 @@ impl DomTree {
     }
 
     /// Build DOM tree from a browser tab with a ref prefix (for iframe handling)
-    pub fn from_tab_with_prefix(tab: &Arc<Tab>, _ref_prefix: &str) -> Result<Self> {
-        // Note: ref_prefix is deprecated but kept for API compatibility
-        // JavaScript code to extract ARIA snapshot
-        let js_code = include_str!("extract_dom.js");
-
-        // Execute JavaScript to extract DOM
-        let result = tab
-            .evaluate(js_code, false)
-            .map_err(|e| BrowserError::DomParseFailed(format!("Failed to execute DOM extraction script: {}", e)))?;
-
-        // Get the JSON string value
-        let json_value = result
-            .value
-            .ok_or_else(|| BrowserError::DomParseFailed("No value returned from DOM extraction".to_string()))?;
-
-        // The JavaScript returns a JSON string, so we need to parse it as a string first
-        let json_str: String = serde_json::from_value(json_value)
-            .map_err(|e| BrowserError::DomParseFailed(format!("Failed to get JSON string: {}", e)))?;
+    pub fn from_tab_with_prefix(tab: &Arc<Tab>, ref_prefix: &str) -> Result<Self> {
+        Self::from_tab_with_strategy(tab, ref_prefix, SelectorStrategy::default())
+    }
 
-        // Then parse the JSON string into SnapshotResponse
-        let response: SnapshotResponse = serde_json::from_str(&json_str)
-            .map_err(|e| BrowserError::DomParseFailed(format!("Failed to parse snapshot JSON: {}", e)))?;
+    /// Build DOM tree from a browser tab, choosing how element selectors are built
+    pub fn from_tab_with_strategy(tab: &Arc<Tab>, _ref_prefix: &str, strategy: SelectorStrategy) -> Result<Self> {
+        // Note: ref_prefix is deprecated but kept for API compatibility
+        Self::from_tab_with_options(tab, strategy, &ExtractOptions::default())
+    }
 
-        Ok(Self { root: response.root, selectors: response.selectors, iframe_indices: response.iframe_indices })
+    /// Build DOM tree from a browser tab with a selector strategy and extraction limits
+    pub fn from_tab_with_options(tab: &Arc<Tab>, strategy: SelectorStrategy, options: &ExtractOptions) -> Result<Self> {
+        // JavaScript code to extract ARIA snapshot
+        let js_code = include_str!("extract_dom.js")
+            .replace("__SELECTOR_STRATEGY__", strategy.as_js())
+            .replace("__MAX_NODES__", &options.max_nodes.unwrap_or(0).to_string());
+
+        // Empty or unparseable results are transient (e.g. the page was replacing its document
+        // while the script ran), so the extraction is re-run a few times before giving up
+        let mut attempt = 1;
+        let response = loop {
+            // Execute JavaScript to extract DOM, isolated from the page's globals
+            let result = evaluate_in_isolated_world(tab, &js_code)
+                .map_err(|e| BrowserError::DomParseFailed(format!("Failed to execute DOM extraction script: {}", e)))?;
+
+            match parse_snapshot(result.value) {
+                Ok(response) => break response,
+                Err(e) if attempt < EXTRACT_ATTEMPTS => {
+                    log::debug!("DOM extraction attempt {}/{} failed: {}; retrying", attempt, EXTRACT_ATTEMPTS, e);
+                    attempt += 1;
+                    thread::sleep(EXTRACT_RETRY_DELAY);
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        Ok(Self {
+            root: response.root,
+            selectors: response.selectors,
+            refs: response.refs,
+            iframe_indices: response.iframe_indices,
+            truncated: response.truncated,
+        })
     }
 
     /// Rebuild the selectors array by traversing the tree
@@ -76,28 +177,29 @@ impl DomTree {
         let max_index = self.find_max_index(&self.root.clone());
 
         // Resize selectors array if needed
-        if let Some(max_idx) = max_index {
-            if self.selectors.len() <= max_idx {
-                self.selectors.resize(max_idx + 1, String::new());
-            }
+        if let Some(max_idx) = max_index
+            && self.selectors.len() <= max_idx
+        {
+            self.selectors.resize(max_idx + 1, String::new());
         }
 
-        // Collect iframe indices
+        // Collect iframe indices and ref selectors
         let root = self.root.clone();
         self.collect_iframe_indices(&root);
+        self.collect_refs(&root);
     }
 
     fn find_max_index(&self, node: &AriaNode) -> Option<usize> {
         let mut max = node.index;
 
         for child in &node.children {
-            if let AriaChild::Node(child_node) = child {
-                if let Some(child_max) = self.find_max_index(child_node) {
-                    max = match max {
-                        Some(current) => Some(current.max(child_max)),
-                        None => Some(child_max),
-                    };
-                }
+            if let AriaChild::Node(child_node) = child
+                && let Some(child_max) = self.find_max_index(child_node)
+            {
+                max = match max {
+                    Some(current) => Some(current.max(child_max)),
+                    None => Some(child_max),
+                };
             }
         }
 
@@ -105,10 +207,10 @@ impl DomTree {
     }
 
     fn collect_iframe_indices(&mut self, node: &AriaNode) {
-        if let Some(index) = node.index {
-            if node.role == "iframe" {
-                self.iframe_indices.push(index);
-            }
+        if let Some(index) = node.index
+            && node.role == "iframe"
+        {
+            self.iframe_indices.push(index);
         }
 
         for child in &node.children {
@@ -118,11 +220,31 @@ impl DomTree {
         }
     }
 
+    fn collect_refs(&mut self, node: &AriaNode) {
+        if let Some(element_ref) = &node.element_ref {
+            self.refs.entry(element_ref.clone()).or_insert_with(|| format!("[{}=\"{}\"]", REF_ATTRIBUTE, element_ref));
+        }
+
+        for child in &node.children {
+            if let AriaChild::Node(child_node) = child {
+                self.collect_refs(child_node);
+            }
+        }
+    }
+
     /// Get CSS selector for a given index
     pub fn get_selector(&self, index: usize) -> Option<&String> {
         self.selectors.get(index).filter(|s| !s.is_empty())
     }
 
+    /// Get CSS selector for a stable element ref
+    ///
+    /// Unlike indices, which are reassigned on every snapshot, a ref keeps pointing at the same
+    /// element after the DOM changes.
+    pub fn get_selector_by_ref(&self, element_ref: &str) -> Option<&String> {
+        self.refs.get(element_ref)
+    }
+
     /// Get all interactive element indices
     pub fn interactive_indices(&self) -> Vec<usize> {
         let mut indices = Vec::new();
@@ -217,6 +339,17 @@ impl DomTree {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_selector_strategy_names() {
+        assert_eq!(SelectorStrategy::default(), SelectorStrategy::Css);
+        assert_eq!(SelectorStrategy::DataTestId.as_js(), "data-testid");
+        assert_eq!(SelectorStrategy::AriaLabel.as_js(), "aria-label");
+        assert_eq!(
+            serde_json::from_value::<SelectorStrategy>(serde_json::json!("data_test_id")).unwrap(),
+            SelectorStrategy::DataTestId
+        );
+    }
+
     fn create_test_tree() -> AriaNode {
         let mut root = AriaNode::fragment();
 
@@ -257,6 +390,28 @@ mod tests {
         assert_eq!(tree.count_nodes(), 4);
     }
 
+    #[test]
+    fn test_parse_snapshot() {
+        let err = parse_snapshot(None).unwrap_err();
+        assert!(matches!(&err, BrowserError::DomParseFailed(reason) if reason.contains("No value returned")));
+        assert!(parse_snapshot(Some(serde_json::json!("{\"root\":"))).is_err());
+
+        let json = serde_json::json!({ "root": AriaNode::fragment(), "selectors": [], "iframeIndices": [] });
+        let response = parse_snapshot(Some(serde_json::json!(json.to_string()))).unwrap();
+        assert_eq!(response.root, AriaNode::fragment());
+        assert!(!response.truncated);
+    }
+
+    #[test]
+    fn test_get_selector_by_ref() {
+        let mut root = AriaNode::fragment();
+        root.children.push(AriaChild::Node(Box::new(AriaNode::new("button", "Save").with_index(0).with_ref("e7"))));
+        let tree = DomTree::new(root);
+
+        assert_eq!(tree.get_selector_by_ref("e7").map(String::as_str), Some("[data-browseruse-ref=\"e7\"]"));
+        assert!(tree.get_selector_by_ref("e8").is_none());
+    }
+
     #[test]
     fn test_interactive_indices() {
         let root = create_test_tree();
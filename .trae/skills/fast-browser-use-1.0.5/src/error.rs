@@ -68,6 +68,45 @@ pub enum BrowserError {
     IoError(#[from] std::io::Error),
 }
 
+impl BrowserError {
+    /// Stable, machine-readable code identifying the error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            BrowserError::LaunchFailed(_) => "launch_failed",
+            BrowserError::ConnectionFailed(_) => "connection_failed",
+            BrowserError::Timeout(_) => "timeout",
+            BrowserError::SelectorInvalid(_) => "invalid_selector",
+            BrowserError::ElementNotFound(_) => "element_not_found",
+            BrowserError::DomParseFailed(_) => "dom_parse_failed",
+            BrowserError::ToolExecutionFailed { .. } => "tool_execution_failed",
+            BrowserError::InvalidArgument(_) => "invalid_argument",
+            BrowserError::NavigationFailed(_) => "navigation_failed",
+            BrowserError::EvaluationFailed(_) => "evaluation_failed",
+            BrowserError::ScreenshotFailed(_) => "screenshot_failed",
+            BrowserError::DownloadFailed(_) => "download_failed",
+            BrowserError::TabOperationFailed(_) => "tab_operation_failed",
+            BrowserError::ChromeError(_) => "chrome_error",
+            BrowserError::JsonError(_) => "json_error",
+            BrowserError::IoError(_) => "io_error",
+        }
+    }
+
+    /// Whether the error means the connection to Chrome was lost (e.g. the browser crashed),
+    /// rather than that the operation itself failed
+    pub fn is_connection_error(&self) -> bool {
+        if matches!(self, BrowserError::ConnectionFailed(_)) {
+            return true;
+        }
+        // headless_chrome errors only survive as text inside the other variants
+        let message = self.to_string();
+        CONNECTION_LOST_MESSAGES.iter().any(|lost| message.contains(lost))
+    }
+}
+
+/// Messages of headless_chrome errors raised when its connection to Chrome is gone
+const CONNECTION_LOST_MESSAGES: &[&str] =
+    &["underlying connection is closed", "receiving on a closed channel", "sending on a closed channel"];
+
 /// Result type alias for browser-use operations
 pub type Result<T> = std::result::Result<T, BrowserError>;
 
@@ -94,6 +133,27 @@ mod tests {
         assert_eq!(err.to_string(), "Tool 'navigate' execution failed: Invalid URL");
     }
 
+    #[test]
+    fn test_error_code() {
+        assert_eq!(BrowserError::Timeout("wait".to_string()).code(), "timeout");
+        assert_eq!(BrowserError::ElementNotFound("#a".to_string()).code(), "element_not_found");
+        assert_eq!(BrowserError::InvalidArgument("x".to_string()).code(), "invalid_argument");
+        assert_eq!(
+            BrowserError::ToolExecutionFailed { tool: "click".to_string(), reason: "boom".to_string() }.code(),
+            "tool_execution_failed"
+        );
+    }
+
+    #[test]
+    fn test_is_connection_error() {
+        assert!(BrowserError::ConnectionFailed("refused".to_string()).is_connection_error());
+        let closed = "Unable to make method calls because underlying connection is closed";
+        let navigation = BrowserError::NavigationFailed(format!("Failed to navigate to about:blank: {}", closed));
+        assert!(navigation.is_connection_error());
+        assert!(!BrowserError::Timeout("wait".to_string()).is_connection_error());
+        assert!(!BrowserError::ElementNotFound("#a".to_string()).is_connection_error());
+    }
+
     #[test]
     fn test_json_error_conversion() {
         let json_err = serde_json::from_str::<serde_json::Value>("invalid json");
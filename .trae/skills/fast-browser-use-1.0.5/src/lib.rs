@@ -6,10 +6,10 @@ pub mod tools;
 #[cfg(feature = "mcp-handler")]
 pub mod mcp;
 
-pub use browser::{BrowserSession, ConnectionOptions, LaunchOptions};
-pub use dom::{BoundingBox, DomTree, ElementNode};
+pub use browser::{BrowserPool, BrowserSession, ConnectionOptions, LaunchOptions, PageLoadStrategy};
+pub use dom::{BoundingBox, DomTree, ElementNode, ExtractOptions, SelectorStrategy};
 pub use error::{BrowserError, Result};
-pub use tools::{Tool, ToolContext, ToolRegistry, ToolResult};
+pub use tools::{Tool, ToolContext, ToolDescriptor, ToolObserver, ToolRegistry, ToolResult};
 
 #[cfg(feature = "mcp-handler")]
 pub use mcp::BrowserServer;
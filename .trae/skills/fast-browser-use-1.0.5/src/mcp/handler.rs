@@ -1,12 +1,22 @@
 //! ServerHandler implementation for BrowserSession
 
-use crate::browser::BrowserSession;
-use log::debug;
-use rmcp::{ServerHandler,
-           handler::server::tool::ToolRouter,
-           model::{ServerCapabilities, ServerInfo},
-           tool_handler};
-use std::sync::{Arc, Mutex};
+use crate::{browser::{BrowserPool, BrowserSession, LaunchOptions},
+            tools::ToolResult};
+use log::{debug, error, info, warn};
+use rmcp::{ErrorData as McpError, RoleServer, ServerHandler,
+           handler::server::tool::{ToolCallContext, ToolRouter},
+           model::{CallToolRequestParam, CallToolResult, ListToolsResult, PaginatedRequestParam, ServerCapabilities,
+                   ServerInfo},
+           service::RequestContext};
+use serde_json::Value;
+use std::{collections::HashSet,
+          sync::{Arc, Mutex, MutexGuard, Weak}};
+
+/// Strings in logged tool parameters longer than this (e.g. base64 file contents) are truncated
+const MAX_LOGGED_STRING_LEN: usize = 200;
+
+/// Browser session of a server; `None` once it has been shut down
+type SharedSession = Arc<Mutex<Option<BrowserSession>>>;
 
 /// MCP Server wrapper for BrowserSession
 ///
@@ -14,29 +24,242 @@ use std::sync::{Arc, Mutex};
 /// for MCP tool execution.
 #[derive(Clone)]
 pub struct BrowserServer {
-    session: Arc<Mutex<BrowserSession>>,
+    session: SharedSession,
+
+    /// Options the browser was launched with, reused to relaunch it after a crash
+    options: LaunchOptions,
+
+    /// Held only for its `Drop`, which returns the session to its pool once the last clone of
+    /// the server is dropped
+    _lease: Option<Arc<PoolLease>>,
     tool_router: ToolRouter<Self>,
+
+    /// Tools removed from `tool_router` by [`with_tool_filter`](Self::with_tool_filter)
+    disabled_tools: Arc<HashSet<String>>,
+}
+
+/// Session checked out of a [`BrowserPool`] for the lifetime of a server
+struct PoolLease {
+    pool: Arc<BrowserPool>,
+    session: Option<SharedSession>,
+}
+
+impl Drop for PoolLease {
+    fn drop(&mut self) {
+        // The server's own handle to the session is dropped before its lease, so this is the last
+        // one; a session that was shut down only frees its slot
+        match self.session.take().map(Arc::try_unwrap) {
+            Some(Ok(session)) => match session.into_inner().unwrap_or_else(|e| e.into_inner()) {
+                Some(session) => self.pool.release(session),
+                None => self.pool.discard(),
+            },
+            _ => self.pool.discard(),
+        }
+    }
+}
+
+/// Handle that shuts down a server's browser without keeping the server alive, e.g. for
+/// cleaning up all served browsers when the process receives SIGTERM
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    session: Weak<Mutex<Option<BrowserSession>>>,
+}
+
+impl ShutdownHandle {
+    /// Quit the browser if the server still exists and hasn't been shut down yet
+    pub fn shutdown(&self) {
+        if let Some(session) = self.session.upgrade() {
+            shutdown_session(&session);
+        }
+    }
+
+    /// Whether the server (or one of its clones) still exists, so there may be a browser to shut down
+    pub fn server_exists(&self) -> bool {
+        self.session.strong_count() > 0
+    }
+}
+
+/// Log every tool call of `session` at info level, as an audit trail of what the agent did
+fn with_audit_log(mut session: BrowserSession) -> BrowserSession {
+    session.tool_registry_mut().set_observer(Box::new(log_tool_call));
+    session
+}
+
+fn log_tool_call(name: &str, params: &Value, result: &ToolResult) {
+    let params = truncate_for_log(params);
+    match &result.error {
+        None => info!("Tool call: {} {} succeeded", name, params),
+        Some(error) => info!("Tool call: {} {} failed: {}", name, params, error),
+    }
+}
+
+/// Copy of `value` with long strings shortened to [`MAX_LOGGED_STRING_LEN`] characters
+fn truncate_for_log(value: &Value) -> Value {
+    match value {
+        Value::String(s) if s.chars().count() > MAX_LOGGED_STRING_LEN => {
+            let kept: String = s.chars().take(MAX_LOGGED_STRING_LEN).collect();
+            Value::String(format!("{}... ({} chars)", kept, s.chars().count()))
+        }
+        Value::Array(items) => Value::Array(items.iter().map(truncate_for_log).collect()),
+        Value::Object(map) => Value::Object(map.iter().map(|(k, v)| (k.clone(), truncate_for_log(v))).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Take the browser out of `session` and quit it
+fn shutdown_session(session: &SharedSession) {
+    let session = session.lock().unwrap_or_else(|e| e.into_inner()).take();
+    if let Some(session) = session
+        && let Err(e) = session.quit()
+    {
+        error!("Failed to quit browser: {}", e);
+    }
 }
 
 impl BrowserServer {
     /// Create a new browser server with default launch options
     pub fn new() -> Result<Self, String> {
-        let session = BrowserSession::new().map_err(|e| format!("Failed to launch browser: {}", e))?;
-
-        Ok(Self { session: Arc::new(Mutex::new(session)), tool_router: Self::tool_router() })
+        Self::with_options(LaunchOptions::default())
     }
 
     /// Create a new browser server with custom launch options
-    pub fn with_options(options: crate::browser::LaunchOptions) -> Result<Self, String> {
-        let session = BrowserSession::launch(options).map_err(|e| format!("Failed to launch browser: {}", e))?;
+    pub fn with_options(options: LaunchOptions) -> Result<Self, String> {
+        let session =
+            BrowserSession::launch(options.clone()).map_err(|e| format!("Failed to launch browser: {}", e))?;
+
+        Ok(Self {
+            session: Arc::new(Mutex::new(Some(with_audit_log(session)))),
+            options,
+            _lease: None,
+            tool_router: Self::tool_router(),
+            disabled_tools: Arc::default(),
+        })
+    }
+
+    /// Create a browser server that only exposes some of its tools.
+    ///
+    /// Tools are named by their MCP names, with or without the `browser_` prefix (e.g.
+    /// `browser_evaluate` or `evaluate`). With `allow`, only the listed tools are exposed; tools
+    /// in `deny` are never exposed. Filtered tools are neither advertised nor callable. Since
+    /// `browser_batch` runs tools by their internal names, it is disabled too whenever any tool
+    /// is filtered out.
+    pub fn with_tool_filter(
+        options: LaunchOptions,
+        allow: Option<Vec<String>>,
+        deny: Vec<String>,
+    ) -> Result<Self, String> {
+        let mut router = Self::tool_router();
+        let disabled = disabled_tools(&router, allow.as_deref(), &deny)?;
+        for name in &disabled {
+            router.remove_route(name);
+        }
+        if !disabled.is_empty() {
+            info!("Disabled tools: {}", disabled.iter().cloned().collect::<Vec<_>>().join(", "));
+        }
+
+        let mut server = Self::with_options(options)?;
+        server.tool_router = router;
+        server.disabled_tools = Arc::new(disabled);
+        Ok(server)
+    }
+
+    /// Create a browser server on a session checked out of `pool`, blocking until one is
+    /// available. The session is checked back in when the server (and all its clones) is dropped.
+    pub fn from_pool(pool: Arc<BrowserPool>) -> Result<Self, String> {
+        let session = pool.acquire().map_err(|e| format!("Failed to acquire browser from pool: {}", e))?;
+        let session = Arc::new(Mutex::new(Some(with_audit_log(session))));
+        let options = pool.options().clone();
+        let lease = PoolLease { pool, session: Some(session.clone()) };
 
-        Ok(Self { session: Arc::new(Mutex::new(session)), tool_router: Self::tool_router() })
+        Ok(Self {
+            session,
+            options,
+            _lease: Some(Arc::new(lease)),
+            tool_router: Self::tool_router(),
+            disabled_tools: Arc::default(),
+        })
     }
 
-    /// Get a reference to the browser session (blocking lock)
-    pub(crate) fn session(&self) -> std::sync::MutexGuard<'_, BrowserSession> {
+    /// Get a reference to the browser session (blocking lock); `None` after [`shutdown`](Self::shutdown).
+    pub(crate) fn session(&self) -> MutexGuard<'_, Option<BrowserSession>> {
         self.session.lock().expect("Failed to lock browser session")
     }
+
+    /// Relaunch the browser in `session` with the original launch options if it stopped
+    /// responding (e.g. Chrome crashed), returning whether it was relaunched. Tabs and settings
+    /// changed at runtime are not restored.
+    ///
+    /// For a server created with [`from_pool`](Self::from_pool), the relaunched browser is no
+    /// longer the session the pool launched: the server launched it, but it is still checked into
+    /// the pool in place of the crashed one when the server is dropped.
+    pub(crate) fn relaunch_if_dead(&self, session: &mut Option<BrowserSession>) -> bool {
+        let Some(current) = session.as_mut() else { return false };
+        if current.is_alive() {
+            return false;
+        }
+
+        warn!("Browser is not responding, relaunching it");
+        match BrowserSession::launch(self.options.clone()) {
+            Ok(relaunched) => {
+                *current = with_audit_log(relaunched);
+                info!("Browser relaunched");
+                true
+            }
+            Err(e) => {
+                error!("Failed to relaunch browser: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Quit the browser. Tools called afterwards fail; a pooled session frees its pool slot.
+    pub fn shutdown(&self) {
+        shutdown_session(&self.session);
+    }
+
+    /// Get a handle that can shut down this server's browser later without keeping it alive
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle { session: Arc::downgrade(&self.session) }
+    }
+
+    /// Reject calls to tools disabled by the tool filter
+    fn check_tool_enabled(&self, name: &str) -> Result<(), McpError> {
+        if self.disabled_tools.contains(name) {
+            return Err(McpError::invalid_params(format!("Tool '{}' is disabled on this server", name), None));
+        }
+        Ok(())
+    }
+}
+
+/// Names of the tools in `router` that `allow`/`deny` filter out, failing on names that match
+/// no tool
+fn disabled_tools(
+    router: &ToolRouter<BrowserServer>,
+    allow: Option<&[String]>,
+    deny: &[String],
+) -> Result<HashSet<String>, String> {
+    let names: Vec<String> = router.list_all().into_iter().map(|tool| tool.name.into_owned()).collect();
+    let resolve = |list: &[String]| -> Result<HashSet<String>, String> {
+        list.iter()
+            .map(|name| {
+                let name = name.trim();
+                let full = if name.starts_with("browser_") { name.to_string() } else { format!("browser_{}", name) };
+                names.contains(&full).then_some(full).ok_or_else(|| format!("Unknown tool '{}' in tool filter", name))
+            })
+            .collect()
+    };
+
+    let allowed = allow.map(resolve).transpose()?;
+    let denied = resolve(deny)?;
+    let mut disabled: HashSet<String> = names
+        .iter()
+        .filter(|name| denied.contains(*name) || allowed.as_ref().is_some_and(|allowed| !allowed.contains(*name)))
+        .cloned()
+        .collect();
+    if !disabled.is_empty() {
+        disabled.insert("browser_batch".to_string());
+    }
+    Ok(disabled)
 }
 
 impl Default for BrowserServer {
@@ -51,7 +274,7 @@ impl Drop for BrowserServer {
     }
 }
 
-#[tool_handler]
+// Implemented by hand rather than with `#[tool_handler]` so calls to filtered tools get a clear error
 impl ServerHandler for BrowserServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
@@ -60,4 +283,163 @@ impl ServerHandler for BrowserServer {
             ..Default::default()
         }
     }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        self.check_tool_enabled(&request.name)?;
+        self.tool_router.call(ToolCallContext::new(self, request, context)).await
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore]
+    fn test_tool_call_relaunches_dead_browser() {
+        let server = BrowserServer::with_options(LaunchOptions::new().headless(true)).unwrap();
+        let pid = server
+            .session()
+            .as_ref()
+            .unwrap()
+            .browser()
+            .get_process_id()
+            .expect("Launched browser should have a process");
+
+        std::process::Command::new("kill").arg("-9").arg(pid.to_string()).status().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+
+        // The call fails on the lost connection, relaunches the browser and is retried once
+        let params = serde_json::from_value(serde_json::json!({ "url": "about:blank" })).unwrap();
+        assert!(server.browser_navigate(rmcp::handler::server::wrapper::Parameters(params)).is_ok());
+
+        let session = server.session();
+        let session = session.as_ref().unwrap();
+        assert!(session.is_alive());
+        assert_ne!(session.browser().get_process_id(), Some(pid));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_tool_call_records_duration() {
+        let server = BrowserServer::with_options(LaunchOptions::new().headless(true)).unwrap();
+        let duration = Arc::new(Mutex::new(None));
+        let observed = duration.clone();
+        server.session().as_mut().unwrap().tool_registry_mut().set_observer(Box::new(move |_, _, result| {
+            *observed.lock().unwrap() = result.metadata.get("duration_ms").cloned();
+        }));
+
+        let params = serde_json::from_value(serde_json::json!({ "url": "about:blank" })).unwrap();
+        assert!(server.browser_navigate(rmcp::handler::server::wrapper::Parameters(params)).is_ok());
+        assert!(duration.lock().unwrap().as_ref().is_some_and(Value::is_u64));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_from_pool_returns_session_on_drop() {
+        let pool = Arc::new(BrowserPool::new(1, LaunchOptions::new().headless(true)).unwrap());
+
+        let server = BrowserServer::from_pool(pool.clone()).unwrap();
+        let pid = server.session().as_ref().unwrap().browser().get_process_id();
+        let clone = server.clone();
+        drop(server);
+        assert_eq!(pool.idle_count(), 0);
+
+        drop(clone);
+        assert_eq!(pool.idle_count(), 1);
+
+        // The next server reuses the same browser
+        let server = BrowserServer::from_pool(pool.clone()).unwrap();
+        assert_eq!(server.session().as_ref().unwrap().browser().get_process_id(), pid);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_shutdown_handle_quits_browser() {
+        let server = BrowserServer::with_options(LaunchOptions::new().headless(true)).unwrap();
+        let handle = server.shutdown_handle();
+
+        handle.shutdown();
+        assert!(server.session().is_none());
+
+        // Shutting down again, or after the server is gone, is a no-op
+        server.shutdown();
+        drop(server);
+        handle.shutdown();
+    }
+
+    #[test]
+    #[ignore]
+    fn test_close_tool_quits_browser() {
+        let server = BrowserServer::with_options(LaunchOptions::new().headless(true)).unwrap();
+        let pid = server.session().as_ref().unwrap().browser().get_process_id().unwrap();
+
+        let params = rmcp::handler::server::wrapper::Parameters(crate::tools::close::CloseParams {});
+        assert!(server.browser_close(params).is_ok());
+        assert!(server.session().is_none());
+
+        // Signal 0 only checks whether the process still exists
+        #[cfg(unix)]
+        assert!(!std::process::Command::new("kill").args(["-0", &pid.to_string()]).status().unwrap().success());
+    }
+
+    #[test]
+    fn test_truncate_for_log() {
+        let blob = "A".repeat(10_000);
+        let params = serde_json::json!({ "files": [{ "name": "a.png", "data": blob }], "count": 1 });
+
+        let logged = truncate_for_log(&params);
+        let data = logged["files"][0]["data"].as_str().unwrap();
+        assert!(data.len() < 300);
+        assert!(data.ends_with("... (10000 chars)"));
+        assert_eq!(logged["files"][0]["name"], "a.png");
+        assert_eq!(logged["count"], 1);
+    }
+
+    #[test]
+    fn test_disabled_tools() {
+        let router = BrowserServer::tool_router();
+        let all = router.list_all().len();
+
+        assert!(disabled_tools(&router, None, &[]).unwrap().is_empty());
+
+        let disabled = disabled_tools(&router, None, &["evaluate".to_string()]).unwrap();
+        assert_eq!(disabled, HashSet::from(["browser_evaluate".to_string(), "browser_batch".to_string()]));
+
+        let disabled =
+            disabled_tools(&router, Some(&["browser_navigate".to_string(), "snapshot".to_string()]), &[]).unwrap();
+        assert_eq!(disabled.len(), all - 2);
+        assert!(!disabled.contains("browser_navigate") && !disabled.contains("browser_snapshot"));
+
+        assert!(disabled_tools(&router, None, &["no_such_tool".to_string()]).is_err());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_denied_tool_is_rejected() {
+        let server = BrowserServer::with_tool_filter(
+            LaunchOptions::new().headless(true),
+            None,
+            vec!["browser_evaluate".to_string()],
+        )
+        .unwrap();
+
+        assert!(!server.tool_router.has_route("browser_evaluate"));
+        assert!(server.tool_router.has_route("browser_navigate"));
+        let err = server.check_tool_enabled("browser_evaluate").unwrap_err();
+        assert!(err.message.contains("disabled"));
+        assert!(server.check_tool_enabled("browser_navigate").is_ok());
+    }
 }
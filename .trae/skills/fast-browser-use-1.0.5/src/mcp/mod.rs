@@ -3,29 +3,55 @@
 //! This module provides rmcp-compatible tools by wrapping the existing tool implementations.
 
 pub mod handler;
-pub use handler::BrowserServer;
+pub use handler::{BrowserServer, ShutdownHandle};
 
-use crate::tools::{self, Tool, ToolContext, ToolResult as InternalToolResult};
+use crate::{browser::BrowserSession,
+            error::BrowserError,
+            tools::{self, Tool, ToolContext, ToolResult as InternalToolResult}};
 use rmcp::{ErrorData as McpError,
            handler::server::wrapper::Parameters,
            model::{CallToolResult, Content},
            tool, tool_router};
 
-/// Convert internal ToolResult to MCP CallToolResult
-fn convert_result(result: InternalToolResult) -> Result<CallToolResult, McpError> {
-    if result.success {
-        let text = if let Some(data) = result.data {
-            serde_json::to_string_pretty(&data).unwrap_or_else(|_| data.to_string())
-        } else {
-            "Success".to_string()
-        };
-        Ok(CallToolResult::success(vec![Content::text(text)]))
-    } else {
-        let error_msg = result.error.unwrap_or_else(|| "Unknown error".to_string());
-        Err(McpError::internal_error(error_msg, None))
+/// Convert internal ToolResult (or the error that prevented one) to MCP CallToolResult
+fn convert_result(result: Result<InternalToolResult, BrowserError>) -> Result<CallToolResult, McpError> {
+    match result {
+        Ok(result) if result.success => {
+            let text = if let Some(data) = result.data {
+                serde_json::to_string_pretty(&data).unwrap_or_else(|_| data.to_string())
+            } else {
+                "Success".to_string()
+            };
+            Ok(CallToolResult::success(vec![Content::text(text)]))
+        }
+        Ok(mut result) => {
+            let error_msg = result.error.unwrap_or_else(|| "Unknown error".to_string());
+            let mut data = serde_json::Map::new();
+            if let Some(code) = result.error_code {
+                data.insert("error_code".to_string(), serde_json::json!(code));
+            }
+            if let Some(screenshot) = result.metadata.remove(tools::ERROR_SCREENSHOT_KEY) {
+                data.insert("screenshot".to_string(), screenshot);
+            }
+            let data = (!data.is_empty()).then_some(serde_json::Value::Object(data));
+            Err(McpError::internal_error(error_msg, data))
+        }
+        // The error code lets clients tell e.g. a (retryable) timeout from a hard failure
+        Err(e) => Err(McpError::internal_error(e.to_string(), Some(serde_json::json!({ "error_code": e.code() })))),
     }
 }
 
+/// Run `tool` for an MCP call on `session`, attaching a screenshot when it fails
+fn run_tool(
+    tool: &dyn tools::DynTool,
+    params: serde_json::Value,
+    session: &BrowserSession,
+) -> Result<InternalToolResult, BrowserError> {
+    let mut context = ToolContext::new(session);
+    // Through DynTool, so MCP results are timed like registry calls
+    tools::with_error_screenshot(tool.execute(params, &mut context), &context)
+}
+
 /// Macro to register MCP tools by automatically generating wrapper functions
 macro_rules! register_mcp_tools {
     ($($mcp_name:ident => $tool_type:ty, $description:expr);* $(;)?) => {
@@ -37,11 +63,33 @@ macro_rules! register_mcp_tools {
                     &self,
                     params: Parameters<<$tool_type as Tool>::Params>,
                 ) -> Result<CallToolResult, McpError> {
-                    let session = self.session();
-                    let mut context = ToolContext::new(&*session);
                     let tool = <$tool_type>::default();
-                    let result = tool.execute_typed(params.0, &mut context)
-                        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+                    let params = serde_json::to_value(&params.0)
+                        .map_err(|e| McpError::invalid_params(format!("Invalid parameters: {}", e), None))?;
+                    let result = {
+                        let mut session = self.session();
+                        let current = session
+                            .as_ref()
+                            .ok_or_else(|| McpError::internal_error("Browser has been shut down", None))?;
+                        let mut result = run_tool(&tool, params.clone(), current);
+                        // A lost connection usually means Chrome crashed: relaunch it and retry once
+                        if result.as_ref().is_err_and(BrowserError::is_connection_error)
+                            && self.relaunch_if_dead(&mut session)
+                            && let Some(relaunched) = session.as_ref()
+                        {
+                            result = run_tool(&tool, params.clone(), relaunched);
+                        }
+                        if let Some(session) = session.as_ref() {
+                            session.tool_registry().notify(tool.name(), &params, &result);
+                        }
+                        result
+                    };
+
+                    // The close tool can only close tabs of the borrowed session; terminate the
+                    // browser process here, where the session is owned
+                    if tool.name() == "close" && result.as_ref().is_ok_and(|result| result.success) {
+                        self.shutdown();
+                    }
                     convert_result(result)
                 }
             )*
@@ -56,26 +104,112 @@ register_mcp_tools! {
     browser_go_back => tools::go_back::GoBackTool, "Navigate back in browser history";
     browser_go_forward => tools::go_forward::GoForwardTool, "Navigate forward in browser history";
     browser_close => tools::close::CloseTool, "Close the browser when the task is complete";
+    browser_health => tools::health::HealthTool, "Check whether the browser is still alive and responsive; returns its version and the number of open tabs";
 
     // ---- Page Content and Extraction ----
-    browser_get_markdown => tools::markdown::GetMarkdownTool, "Get the markdown content of the current page (use this tool only for information extraction; for interaction use the snapshot tool instead)";
-    browser_snapshot => tools::snapshot::SnapshotTool, "Get a snapshot of the current page with indexed interactive elements for interaction";
+    browser_get_markdown => tools::markdown::GetMarkdownTool, "Get the markdown content of the current page (use this tool only for information extraction; for interaction use the snapshot tool instead); set trigger_lazy on feed-style pages to scroll through and load lazy images and content first, at the cost of extra latency";
+    browser_get_html_source => tools::html_source::HtmlSourceTool, "Get the full HTML of the current page: the rendered DOM (default) or, with rendered=false, the HTML originally sent by the server";
+    browser_save_mhtml => tools::mhtml::MhtmlTool, "Archive the current page as a single self-contained MHTML file (CSS, images and frames inlined) for offline reading; saved to path if given, otherwise returned";
+    browser_snapshot => tools::snapshot::SnapshotTool, "Get a snapshot of the current page with indexed interactive elements for interaction; set wait_stable on dynamic pages to wait until the DOM stops changing first, and max_chars to cap the size of large snapshots";
+    browser_page_context => tools::context::PageContextTool, "Get the URL, title, snapshot (with indexed interactive elements, as browser_snapshot) and the most recent console and network errors of the current page in one call; use it at the start of a turn instead of separate calls. snapshot, console_errors and network_errors turn sections off";
+    browser_interactive_list => tools::interactive_list::InteractiveListTool, "Get a flat list of the visible interactive elements of the current page ({index, role, name, selector, rect}), a compact alternative to browser_snapshot";
+    browser_describe_element => tools::describe_element::DescribeElementTool, "Get the tag, attributes, computed styles and box model (content, padding, border and margin rects) of an element specified by CSS selector or index (index obtained from browser_snapshot tool), e.g. to debug layout or visibility; styles picks the computed CSS properties to return";
+    browser_cdp_accessibility_tree => tools::accessibility::CdpAccessibilityTreeTool, "Get Chrome's native accessibility tree of the current page (raw CDP AXNode array), e.g. to diagnose differences from browser_snapshot";
     browser_screenshot => tools::screenshot::ScreenshotTool, "Capture a screenshot of the current page";
+    browser_visual_diff => tools::visual_diff::VisualDiffTool, "Compare the current viewport against a baseline PNG and report the percentage of changed pixels, passing when it is within the threshold";
     // browser_get_text => tools::extract::ExtractContentTool, "Extract text or HTML content from the page or an element";
     browser_evaluate => tools::evaluate::EvaluateTool, "Execute JavaScript code in the browser context";
+    browser_capture_responses => tools::responses::CaptureResponsesTool, "Start recording the XHR and fetch responses (with bodies) whose URL matches a glob such as */api/*, e.g. before navigating or clicking on an API-driven page; read them with browser_get_captured_responses";
+    browser_get_captured_responses => tools::responses::GetCapturedResponsesTool, "Get the XHR and fetch responses recorded since browser_capture_responses ({url, status, mime_type, body, base64_encoded, truncated}), most recent last, to read the JSON a page fetched without requesting it again; the last 100 are kept and bodies are cut at a million characters";
 
     // ---- Interaction ----
-    browser_click => tools::click::ClickTool, "Click on an element specified by CSS selector or index (index obtained from browser_snapshot tool)";
+    browser_click => tools::click::ClickTool, "Click on an element specified by CSS selector, index or ref (index and ref obtained from browser_snapshot tool; prefer ref, which stays valid after the page changes)";
+    browser_click_at => tools::click_at::ClickAtTool, "Click at viewport coordinates in CSS pixels (e.g. from a snapshot with include_coordinates) for canvas, map or video controls that have no element to target; button is left (default), right or middle";
+    browser_mouse_move => tools::mouse_move::MouseMoveTool, "Move the mouse to viewport coordinates in CSS pixels, in evenly spaced steps from where the last mouse tool left it; use it to reveal hover menus or to build drag and canvas interactions";
     browser_hover => tools::hover::HoverTool, "Hover over an element specified by CSS selector or index (index obtained from browser_snapshot tool)";
+    browser_hover_capture => tools::hover_capture::HoverCaptureTool, "Hover over an element specified by CSS selector or index (index obtained from browser_snapshot tool) and return a base64 PNG screenshot once tooltips or menus have appeared";
+    browser_highlight => tools::highlight::HighlightTool, "Briefly flash an overlay over an element specified by CSS selector or index (index obtained from browser_snapshot tool), e.g. to show what is about to be clicked in a headed browser; returns the element rect";
+    browser_inject_css => tools::inject_css::InjectCssTool, "Add CSS to the page, e.g. to hide cookie banners and overlays before a screenshot or to force a dark theme; with persist it is re-added after every navigation. Returns an ID for browser_remove_css";
+    browser_remove_css => tools::inject_css::RemoveCssTool, "Remove CSS added by browser_inject_css, given the ID it returned";
     browser_select => tools::select::SelectTool, "Select an option in a dropdown element by CSS selector or index (index obtained from browser_snapshot tool)";
-    browser_input_fill => tools::input::InputTool, "Type text into an input element specified by CSS selector or index (index obtained from browser_snapshot tool)";
+    browser_set_checked => tools::check::CheckTool, "Check or uncheck a checkbox or radio button specified by CSS selector or index (index obtained from browser_snapshot tool); only clicks when the state differs, and returns the final checked state";
+    browser_submit_form => tools::submit::SubmitFormTool, "Submit the form that contains an element specified by CSS selector or index (index obtained from browser_snapshot tool), e.g. after filling its inputs; runs validation and submit handlers like a user would, and returns the form's action URL and method";
+    browser_input_fill => tools::input::InputTool, "Type text into an input element specified by CSS selector, index or ref (index and ref obtained from browser_snapshot tool; prefer ref, which stays valid after the page changes)";
     browser_press_key => tools::press_key::PressKeyTool, "Press a key on the keyboard";
-    browser_scroll => tools::scroll::ScrollTool, "Scroll the page by a specified amount or to the bottom";
+    browser_type_text => tools::type_text::TypeTextTool, "Type text into whatever element currently has focus (e.g. a contenteditable or a canvas game) without selecting it first; set delay_ms to type character by character as key events";
+    browser_scroll => tools::scroll::ScrollTool, "Scroll the page by a specified amount or to the bottom; with target [x, y] a mouse wheel scrolls the container under that viewport point instead (e.g. a chat panel or modal)";
+    browser_dismiss_banners => tools::dismiss_banners::DismissBannersTool, "Click away a cookie or consent banner that blocks the page, matching known consent managers (OneTrust, Cookiebot, ...) and common accept texts; texts and selectors add your own matches. Returns whether and which banner was dismissed";
     browser_wait => tools::wait::WaitTool, "Wait for an element to appear on the page";
+    browser_wait_and_read => tools::wait_and_read::WaitAndReadTool, "Wait for an element specified by CSS selector to appear with non-empty text (e.g. a search result or status message), then return its text and how long it took, in one call";
+    browser_batch => tools::batch::BatchTool, "Run a sequence of tools (e.g. click, input, click) in one call; each action is {tool, params} using the internal tool names such as 'click' or 'input'";
 
     // ---- Tab Management ----
     browser_new_tab => tools::new_tab::NewTabTool, "Open a new tab and navigate to the specified URL";
-    browser_tab_list => tools::tab_list::TabListTool, "Get the list of all browser tabs with their titles and URLs";
+    browser_open_in_new_tab => tools::open_in_new_tab::OpenInNewTabTool, "Open a link specified by CSS selector or index (index obtained from browser_snapshot tool) in a new background tab";
+    browser_tab_list => tools::tab_list::TabListTool, "Get the list of browser tabs with their titles and URLs; title_contains and url_contains (case-insensitive) narrow it down to find a tab before switching to it";
     browser_switch_tab => tools::switch_tab::SwitchTabTool, "Switch to a specific tab by index";
-    browser_close_tab => tools::close_tab::CloseTabTool, "Close the current active tab";
+    browser_close_tab => tools::close_tab::CloseTabTool, "Close the current active tab, or the tab at index (from browser_tab_list) without switching to it first";
+    browser_wait_for_popup => tools::popup::WaitForPopupTool, "Wait for the page to open a popup or new tab (e.g. an OAuth login window after clicking 'Sign in with ...') and switch to it";
+
+    // ---- Clipboard ----
+    browser_read_clipboard => tools::clipboard::ReadClipboardTool, "Read the text currently on the clipboard (e.g. after clicking a copy button)";
+    browser_write_clipboard => tools::clipboard::WriteClipboardTool, "Write text to the clipboard so it can be pasted into the page";
+
+    // ---- Permissions ----
+    browser_grant_permissions => tools::permissions::GrantPermissionsTool, "Grant permissions (geolocation, notifications, camera, microphone, clipboard, ...) for an origin or all origins so the site doesn't block on a permission prompt";
+    browser_reset_permissions => tools::permissions::ResetPermissionsTool, "Reset all granted permissions to the browser defaults";
+
+    // ---- Cookies ----
+    browser_clear_cookies => tools::cookies::ClearCookiesTool, "Clear browser cookies, or only those of the given domain; returns the number of cookies removed";
+
+    // ---- Session Storage ----
+    browser_get_session_storage => tools::session_storage::GetSessionStorageTool, "Get a sessionStorage item by key, or all items if no key is given";
+    browser_set_session_storage => tools::session_storage::SetSessionStorageTool, "Set a sessionStorage item on the current page";
+    browser_remove_session_storage => tools::session_storage::RemoveSessionStorageTool, "Remove a sessionStorage item from the current page";
+    browser_clear_session_storage => tools::session_storage::ClearSessionStorageTool, "Clear all sessionStorage items of the current page";
+
+    // ---- Debugging ----
+    browser_get_js_exceptions => tools::debug::GetJsExceptionsTool, "Get uncaught JavaScript exceptions thrown by the page, with message, stack and script URL";
+    browser_handle_dialog => tools::dialog::HandleDialogTool, "Accept or dismiss JavaScript alert/confirm/prompt dialogs: answers any dialog that is open now and every one that opens later, and returns the dialog messages";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_result_success() {
+        let result = convert_result(Ok(InternalToolResult::success_with(serde_json::json!({ "ok": true }))));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_convert_result_timeout_is_distinct() {
+        let err = convert_result(Err(BrowserError::Timeout("Element '#late' not found within 10 ms".to_string())))
+            .unwrap_err();
+        assert!(err.message.starts_with("Operation timed out"));
+        assert_eq!(err.data, Some(serde_json::json!({ "error_code": "timeout" })));
+
+        let err = convert_result(Err(BrowserError::ElementNotFound("#missing".to_string()))).unwrap_err();
+        assert_eq!(err.data, Some(serde_json::json!({ "error_code": "element_not_found" })));
+    }
+
+    #[test]
+    fn test_convert_result_failure_error_code() {
+        let result = InternalToolResult::failure("Tool 'nope' not found").with_error_code("tool_not_found");
+        let err = convert_result(Ok(result)).unwrap_err();
+        assert_eq!(err.message, "Tool 'nope' not found");
+        assert_eq!(err.data, Some(serde_json::json!({ "error_code": "tool_not_found" })));
+    }
+
+    #[test]
+    fn test_convert_result_failure_screenshot() {
+        let result = InternalToolResult::from_error(&BrowserError::ElementNotFound("#missing".to_string()))
+            .with_metadata(tools::ERROR_SCREENSHOT_KEY, serde_json::json!("iVBORw0KGgo="));
+        let err = convert_result(Ok(result)).unwrap_err();
+        assert_eq!(
+            err.data,
+            Some(serde_json::json!({ "error_code": "element_not_found", "screenshot": "iVBORw0KGgo=" }))
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the cdp_accessibility_tree tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CdpAccessibilityTreeParams {}
+
+/// Tool for reading Chrome's native accessibility tree, for comparison with the snapshot
+#[derive(Default)]
+pub struct CdpAccessibilityTreeTool;
+
+impl Tool for CdpAccessibilityTreeTool {
+    type Params = CdpAccessibilityTreeParams;
+
+    fn name(&self) -> &str {
+        "cdp_accessibility_tree"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get Chrome's native accessibility tree of the current page as raw CDP AXNodes"
+    }
+
+    fn execute_typed(&self, _params: CdpAccessibilityTreeParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let nodes = context.session.get_cdp_accessibility_tree_in(&context.tab()?)?;
+        Ok(ToolResult::success_with(nodes))
+    }
+}
@@ -35,10 +35,13 @@ impl Tool for AnnotateTool {
         "annotate"
     }
 
+    fn description(&self) -> &str {
+        "Overlay index labels on the interactive elements of the current page"
+    }
+
     fn execute_typed(&self, params: AnnotateParams, context: &mut ToolContext) -> Result<ToolResult> {
         // 1. Capture screenshot
         let screenshot_data = context
-            .session
             .tab()?
             .capture_screenshot(
                 headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
@@ -90,19 +93,21 @@ impl Tool for AnnotateTool {
         // We traverse the DOM tree
         let mut queue = vec![&dom.root];
         while let Some(node) = queue.pop() {
-            if let Some(index) = node.index {
-                if let Some(rect) = &node.box_info.rect {
-                    if rect.width > 0.0 && rect.height > 0.0 && rect.x >= 0.0 && rect.y >= 0.0 {
-                        // Check if rect is within viewport roughly
-                         if rect.x < width as f64 && rect.y < height as f64 {
-                             valid_indices.push((index, rect.clone()));
-                             
-                             // Add to selector map
-                             if let Some(selector) = dom.get_selector(index) {
-                                 map.insert(index.to_string(), selector.clone());
-                             }
-                         }
-                    }
+            if let Some(index) = node.index
+                && let Some(rect) = &node.box_info.rect
+                && rect.width > 0.0
+                && rect.height > 0.0
+                && rect.x >= 0.0
+                && rect.y >= 0.0
+                // Check if rect is within viewport roughly
+                && rect.x < width as f64
+                && rect.y < height as f64
+            {
+                valid_indices.push((index, rect.clone()));
+
+                // Add to selector map
+                if let Some(selector) = dom.get_selector(index) {
+                    map.insert(index.to_string(), selector.clone());
                 }
             }
             
@@ -119,11 +124,11 @@ impl Tool for AnnotateTool {
              // Draw yellow box
              let x = rect.x as i32;
              let y = rect.y as i32;
-             let w = rect.width as u32;
-             let h = rect.height as u32;
+             let _w = rect.width as u32;
+             let _h = rect.height as u32;
              
              // Define color: Yellow with alpha
-             let color = Rgba([255, 255, 0, 128]); // Semi-transparent yellow
+             let _color = Rgba([255, 255, 0, 128]); // Semi-transparent yellow
              let border_color = Rgba([255, 0, 0, 255]); // Red border
              
              // Draw filled rect (marker)
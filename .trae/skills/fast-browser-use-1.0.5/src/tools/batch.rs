@@ -0,0 +1,71 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A single step of a batch
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchAction {
+    /// Name of the tool to run (e.g. "click", "input")
+    pub tool: String,
+
+    /// Parameters for the tool
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Parameters for the batch tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchParams {
+    /// Actions to run in order
+    pub actions: Vec<BatchAction>,
+
+    /// Stop at the first failing action (default: true)
+    #[serde(default = "default_stop_on_error")]
+    pub stop_on_error: bool,
+}
+
+fn default_stop_on_error() -> bool {
+    true
+}
+
+/// Tool for running several tools sequentially in one call
+#[derive(Default)]
+pub struct BatchTool;
+
+impl Tool for BatchTool {
+    type Params = BatchParams;
+
+    fn name(&self) -> &str {
+        "batch"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Run a sequence of tools in order, sharing the page state between steps"
+    }
+
+    fn execute_typed(&self, params: BatchParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if let Some(nested) = params.actions.iter().find(|a| a.tool == self.name()) {
+            return Err(BrowserError::InvalidArgument(format!("'{}' actions cannot be nested", nested.tool)));
+        }
+
+        let total = params.actions.len();
+        let actions = params.actions.into_iter().map(|a| (a.tool, a.params)).collect();
+        // Run through the session's registry so custom tools resolve and each action is observed
+        let session = context.session;
+        let results = session.tool_registry().execute_batch(actions, context, params.stop_on_error);
+        let failed = results.iter().filter(|r| !r.success).count();
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "results": results,
+            "completed": results.len(),
+            "total": total,
+            "failed": failed
+        })))
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{dom::element::AriaChecked,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, utils::with_retry}};
+use headless_chrome::Element;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the check tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CheckParams {
+    /// CSS selector (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Desired state: true to check, false to uncheck
+    pub checked: bool,
+}
+
+/// Tool for setting a checkbox or radio button to a definite state
+#[derive(Default)]
+pub struct CheckTool;
+
+/// Current state of a native checkbox/radio, or of an element with `aria-checked`; null otherwise
+const READ_CHECKED_JS: &str = "function() {
+    if (typeof this.checked === 'boolean') return this.checked;
+    const aria = this.getAttribute('aria-checked');
+    return aria === 'true' ? true : aria === 'false' ? false : null;
+}";
+
+/// Set a native input's state directly and fire the events a user click would
+const SET_CHECKED_JS: &str = "function(checked) {
+    if (typeof this.checked !== 'boolean') return false;
+    this.checked = checked;
+    this.dispatchEvent(new Event('input', { bubbles: true }));
+    this.dispatchEvent(new Event('change', { bubbles: true }));
+    return true;
+}";
+
+impl Tool for CheckTool {
+    type Params = CheckParams;
+
+    fn name(&self) -> &str {
+        "check"
+    }
+
+    fn description(&self) -> &str {
+        "Check or uncheck a checkbox or radio button specified by CSS selector or index, clicking only if needed"
+    }
+
+    fn execute_typed(&self, params: CheckParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Validate that exactly one selector method is provided
+        match (&params.selector, &params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "check".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "check".to_string(),
+                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let tab = context.tab()?;
+        let (element, css_selector, snapshot_state) = if let Some(selector) = params.selector {
+            (context.session.find_element(&tab, &selector)?, selector, None)
+        } else if let Some(index) = params.index {
+            let (element, css_selector) = with_retry(context, index, |context, selector| {
+                Ok((context.session.find_element(&tab, selector)?, selector.to_string()))
+            })?;
+            // The snapshot already knows the state of indexed elements
+            let snapshot_state = context.get_dom()?.find_node_by_index(index).and_then(|node| match node.checked {
+                Some(AriaChecked::Bool(checked)) => Some(checked),
+                _ => None,
+            });
+            (element, css_selector, snapshot_state)
+        } else {
+            unreachable!("Validation above ensures one field is Some")
+        };
+
+        let current = match snapshot_state {
+            Some(checked) => Some(checked),
+            None => read_checked(&element)?,
+        };
+
+        let mut changed = false;
+        if current != Some(params.checked) {
+            element
+                .click()
+                .map_err(|e| BrowserError::ToolExecutionFailed { tool: "check".to_string(), reason: e.to_string() })?;
+            changed = true;
+        }
+
+        // A stale snapshot, an overlay swallowing the click or a radio that can't be unchecked by
+        // clicking leaves the wrong state; set it directly in that case
+        let mut state = read_checked(&element)?;
+        if state.is_some() && state != Some(params.checked) {
+            let set = element
+                .call_js_fn(SET_CHECKED_JS, vec![serde_json::json!(params.checked)], false)
+                .map_err(|e| BrowserError::ToolExecutionFailed { tool: "check".to_string(), reason: e.to_string() })?;
+            if set.value == Some(serde_json::Value::Bool(true)) {
+                changed = true;
+                state = read_checked(&element)?;
+            }
+        }
+
+        match state {
+            Some(checked) if checked == params.checked => Ok(ToolResult::success_with(serde_json::json!({
+                "selector": css_selector,
+                "checked": checked,
+                "changed": changed
+            }))),
+            Some(checked) => Err(BrowserError::ToolExecutionFailed {
+                tool: "check".to_string(),
+                reason: format!(
+                    "Element '{}' is still {}",
+                    css_selector,
+                    if checked { "checked" } else { "unchecked" }
+                ),
+            }),
+            None => Err(BrowserError::ToolExecutionFailed {
+                tool: "check".to_string(),
+                reason: format!("Element '{}' is not a checkbox or radio button", css_selector),
+            }),
+        }
+    }
+}
+
+fn read_checked(element: &Element) -> Result<Option<bool>> {
+    let result = element
+        .call_js_fn(READ_CHECKED_JS, vec![], false)
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: "check".to_string(), reason: e.to_string() })?;
+    Ok(result.value.and_then(|v| v.as_bool()))
+}
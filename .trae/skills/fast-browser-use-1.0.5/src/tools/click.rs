@@ -1,18 +1,41 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult}};
+            tools::{Tool, ToolContext, ToolResult,
+                    human_mouse::move_mouse_to_element,
+                    utils::{with_ref_retry, with_retry}}};
+use headless_chrome::{Element, Tab};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the click tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClickParams {
-    /// CSS selector (use either this or index, not both)
+    /// CSS selector (use exactly one of selector, index or ref)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub selector: Option<String>,
 
-    /// Element index from DOM tree (use either this or selector, not both)
+    /// Element index from DOM tree (use exactly one of selector, index or ref)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<usize>,
+
+    /// Stable element ref from DOM tree; unlike index it stays valid after the page changes
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub element_ref: Option<String>,
+
+    /// Move the mouse to the element along a curved, slightly jittery path before clicking, like
+    /// a person would; helps on sites that flag instant clicks (default: false)
+    #[serde(default)]
+    pub emulate_human: bool,
+}
+
+/// Click `element`, first moving the mouse to it like a person if `emulate_human` is set
+fn click_element(context: &ToolContext, tab: &Tab, element: &Element, emulate_human: bool) -> Result<()> {
+    if emulate_human {
+        move_mouse_to_element(context.session, tab, element)?;
+    }
+    element
+        .click()
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: "click".to_string(), reason: e.to_string() })?;
+    Ok(())
 }
 
 /// Tool for clicking elements
@@ -26,57 +49,64 @@ impl Tool for ClickTool {
         "click"
     }
 
+    fn description(&self) -> &str {
+        "Click on an element specified by CSS selector, index or ref"
+    }
+
     fn execute_typed(&self, params: ClickParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Validate that exactly one selector method is provided
-        match (&params.selector, &params.index) {
-            (Some(_), Some(_)) => {
+        match (&params.selector, &params.index, &params.element_ref) {
+            (None, None, None) => {
                 return Err(BrowserError::ToolExecutionFailed {
                     tool: "click".to_string(),
-                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                    reason: "Must specify one of 'selector', 'index' or 'ref'.".to_string(),
                 });
             }
-            (None, None) => {
+            (Some(_), None, None) | (None, Some(_), None) | (None, None, Some(_)) => {}
+            _ => {
                 return Err(BrowserError::ToolExecutionFailed {
                     tool: "click".to_string(),
-                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                    reason: "Cannot specify more than one of 'selector', 'index' and 'ref'. Use only one.".to_string(),
                 });
             }
-            _ => {}
         }
 
         if let Some(selector) = params.selector {
             // CSS selector path
-            let tab = context.session.tab()?;
+            let tab = context.tab()?;
             let element = context.session.find_element(&tab, &selector)?;
-            element
-                .click()
-                .map_err(|e| BrowserError::ToolExecutionFailed { tool: "click".to_string(), reason: e.to_string() })?;
+            click_element(context, &tab, &element, params.emulate_human)?;
 
             Ok(ToolResult::success_with(serde_json::json!({
                 "selector": selector,
                 "method": "css"
             })))
         } else if let Some(index) = params.index {
-            // Index path - convert index to CSS selector
-            let css_selector = {
-                let dom = context.get_dom()?;
-                let selector = dom
-                    .get_selector(index)
-                    .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
-                selector.clone()
-            };
-
-            let tab = context.session.tab()?;
-            let element = context.session.find_element(&tab, &css_selector)?;
-            element
-                .click()
-                .map_err(|e| BrowserError::ToolExecutionFailed { tool: "click".to_string(), reason: e.to_string() })?;
+            // Index path - convert index to CSS selector, re-resolving if the DOM went stale
+            let tab = context.tab()?;
+            let (element, css_selector) = with_retry(context, index, |context, selector| {
+                Ok((context.session.find_element(&tab, selector)?, selector.to_string()))
+            })?;
+            click_element(context, &tab, &element, params.emulate_human)?;
 
             Ok(ToolResult::success_with(serde_json::json!({
                 "index": index,
                 "selector": css_selector,
                 "method": "index"
             })))
+        } else if let Some(element_ref) = params.element_ref {
+            // Ref path - refs survive re-snapshots, so this is the same element even if indices shifted
+            let tab = context.tab()?;
+            let (element, css_selector) = with_ref_retry(context, &element_ref, |context, selector| {
+                Ok((context.session.find_element(&tab, selector)?, selector.to_string()))
+            })?;
+            click_element(context, &tab, &element, params.emulate_human)?;
+
+            Ok(ToolResult::success_with(serde_json::json!({
+                "ref": element_ref,
+                "selector": css_selector,
+                "method": "ref"
+            })))
         } else {
             unreachable!("Validation above ensures one field is Some")
         }
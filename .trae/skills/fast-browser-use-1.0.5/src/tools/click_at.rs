@@ -0,0 +1,111 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::protocol::cdp::Input::{DispatchMouseEvent, DispatchMouseEventTypeOption, MouseButton};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the click_at tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClickAtParams {
+    /// Horizontal position in CSS pixels from the left edge of the viewport
+    pub x: f64,
+
+    /// Vertical position in CSS pixels from the top edge of the viewport
+    pub y: f64,
+
+    /// Mouse button: "left" (default), "right", "middle", "back" or "forward"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub button: Option<String>,
+}
+
+/// Resolve a button name to the CDP mouse button
+fn mouse_button(name: Option<&str>) -> Result<MouseButton> {
+    match name.map(|n| n.trim().to_lowercase()).as_deref() {
+        None | Some("left") => Ok(MouseButton::Left),
+        Some("right") => Ok(MouseButton::Right),
+        Some("middle") => Ok(MouseButton::Middle),
+        Some("back") => Ok(MouseButton::Back),
+        Some("forward") => Ok(MouseButton::Forward),
+        Some(other) => Err(BrowserError::InvalidArgument(format!(
+            "Unknown mouse button '{}'. Use left, right, middle, back or forward",
+            other
+        ))),
+    }
+}
+
+/// Mouse event of `event_type` at (`x`, `y`)
+pub(crate) fn mouse_event(
+    event_type: DispatchMouseEventTypeOption,
+    x: f64,
+    y: f64,
+    button: Option<MouseButton>,
+) -> DispatchMouseEvent {
+    let click_count = button.as_ref().map(|_| 1);
+    DispatchMouseEvent {
+        Type: event_type,
+        x,
+        y,
+        modifiers: None,
+        timestamp: None,
+        button,
+        buttons: None,
+        click_count,
+        force: None,
+        tangential_pressure: None,
+        tilt_x: None,
+        tilt_y: None,
+        twist: None,
+        delta_x: None,
+        delta_y: None,
+        pointer_Type: None,
+    }
+}
+
+/// Tool for clicking at viewport coordinates, e.g. on canvas, map or video controls that have
+/// no element to select
+#[derive(Default)]
+pub struct ClickAtTool;
+
+impl Tool for ClickAtTool {
+    type Params = ClickAtParams;
+
+    fn name(&self) -> &str {
+        "click_at"
+    }
+
+    fn description(&self) -> &str {
+        "Click at the given viewport coordinates"
+    }
+
+    fn execute_typed(&self, params: ClickAtParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let button = mouse_button(params.button.as_deref())?;
+        let tab = context.tab()?;
+
+        let dispatch = |event: DispatchMouseEvent| {
+            tab.call_method(event)
+                .map_err(|e| BrowserError::ToolExecutionFailed { tool: "click_at".to_string(), reason: e.to_string() })
+        };
+        dispatch(mouse_event(DispatchMouseEventTypeOption::MouseMoved, params.x, params.y, None))?;
+        dispatch(mouse_event(DispatchMouseEventTypeOption::MousePressed, params.x, params.y, Some(button.clone())))?;
+        dispatch(mouse_event(DispatchMouseEventTypeOption::MouseReleased, params.x, params.y, Some(button.clone())))?;
+        context.session.set_mouse_position_in(&tab, (params.x, params.y));
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "x": params.x,
+            "y": params.y,
+            "button": button,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mouse_button() {
+        assert_eq!(mouse_button(None).unwrap(), MouseButton::Left);
+        assert_eq!(mouse_button(Some("Right")).unwrap(), MouseButton::Right);
+        assert!(matches!(mouse_button(Some("none")), Err(BrowserError::InvalidArgument(_))));
+    }
+}
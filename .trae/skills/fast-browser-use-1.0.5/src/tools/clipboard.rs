@@ -0,0 +1,104 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult,
+                    evaluate::{EvaluateParams, EvaluateTool}}};
+use headless_chrome::{Tab,
+                      protocol::cdp::{Browser::{GrantPermissions, PermissionType},
+                                      Emulation::SetFocusEmulationEnabled}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReadClipboardParams {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WriteClipboardParams {
+    /// Text to place on the clipboard
+    pub text: String,
+}
+
+/// Grant the page clipboard access and make it count as focused, which the
+/// async clipboard API requires (a headless page is never focused otherwise)
+fn grant_clipboard_access(tab: &Tab) -> Result<()> {
+    tab.call_method(GrantPermissions {
+        permissions: vec![PermissionType::ClipboardReadWrite, PermissionType::ClipboardSanitizedWrite],
+        origin: None,
+        browser_context_id: None,
+    })
+    .map_err(|e| BrowserError::ChromeError(format!("Failed to grant clipboard permission: {}", e)))?;
+
+    tab.call_method(SetFocusEmulationEnabled { enabled: true })
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to enable focus emulation: {}", e)))?;
+
+    Ok(())
+}
+
+/// Run a clipboard call through an awaited evaluate, turning permission errors into a clear message
+fn run_clipboard_js(tool: &str, params: EvaluateParams, context: &mut ToolContext) -> Result<ToolResult> {
+    let tab = context.tab()?;
+    grant_clipboard_access(&tab)?;
+
+    EvaluateTool.execute_typed(params, context).map_err(|e| match e {
+        BrowserError::EvaluationFailed(reason) if reason.contains("NotAllowedError") => {
+            BrowserError::ToolExecutionFailed {
+                tool: tool.to_string(),
+                reason: format!("Clipboard access was denied by the browser: {}", reason),
+            }
+        }
+        other => other,
+    })
+}
+
+#[derive(Default)]
+pub struct ReadClipboardTool;
+
+impl Tool for ReadClipboardTool {
+    type Params = ReadClipboardParams;
+
+    fn name(&self) -> &str {
+        "read_clipboard"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Read the text currently on the clipboard"
+    }
+
+    fn execute_typed(&self, _params: ReadClipboardParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let params =
+            EvaluateParams { code: "navigator.clipboard.readText()".to_string(), await_promise: true, args: vec![] };
+        let result = run_clipboard_js(self.name(), params, context)?;
+        let text = result.data.as_ref().map(|d| d["result"].clone()).unwrap_or_default();
+
+        Ok(ToolResult::success_with(serde_json::json!({ "text": text })))
+    }
+}
+
+#[derive(Default)]
+pub struct WriteClipboardTool;
+
+impl Tool for WriteClipboardTool {
+    type Params = WriteClipboardParams;
+
+    fn name(&self) -> &str {
+        "write_clipboard"
+    }
+
+    fn description(&self) -> &str {
+        "Write text to the clipboard"
+    }
+
+    fn execute_typed(&self, params: WriteClipboardParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let length = params.text.chars().count();
+        let params = EvaluateParams {
+            code: "return navigator.clipboard.writeText(args[0]);".to_string(),
+            await_promise: true,
+            args: vec![serde_json::Value::String(params.text)],
+        };
+        run_clipboard_js(self.name(), params, context)?;
+
+        Ok(ToolResult::success_with(serde_json::json!({ "length": length })))
+    }
+}
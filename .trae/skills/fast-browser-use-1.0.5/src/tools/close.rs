@@ -18,11 +18,14 @@ impl Tool for CloseTool {
         "close"
     }
 
-    fn execute_typed(&self, _params: CloseParams, context: &mut ToolContext) -> Result<ToolResult> {
-        // Note: Closing the browser via BrowserSession is tricky because we hold a reference
-        // In a real implementation, this would need to signal the session owner to close
-        // For now, we'll close all tabs as a proxy for closing the browser
+    fn description(&self) -> &str {
+        "Close the browser when the task is complete"
+    }
 
+    fn execute_typed(&self, _params: CloseParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Note: BrowserSession::quit consumes the session, but tools only borrow it.
+        // We close all tabs here; BrowserServer quits the browser once browser_close
+        // succeeds, other owners of the session have to call quit() themselves.
         context
             .session
             .close()
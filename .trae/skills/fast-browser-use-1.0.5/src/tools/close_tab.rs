@@ -3,11 +3,15 @@ use crate::{error::Result,
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-/// Parameters for the close_tab tool (no parameters needed)
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct CloseTabParams {}
+/// Parameters for the close_tab tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CloseTabParams {
+    /// Index of the tab to close, as listed by tab_list (default: the active tab)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+}
 
-/// Tool for closing the current active tab
+/// Tool for closing the active tab or a tab by index
 #[derive(Default)]
 pub struct CloseTabTool;
 
@@ -18,19 +22,35 @@ impl Tool for CloseTabTool {
         "close_tab"
     }
 
-    fn execute_typed(&self, _params: CloseTabParams, context: &mut ToolContext) -> Result<ToolResult> {
-        // Get the current tab info before closing
-        let active_tab = context.session.tab()?;
-        let tab_title = active_tab.get_title().unwrap_or_default();
-        let tab_url = active_tab.get_url();
+    fn description(&self) -> &str {
+        "Close the current active tab, or the tab at the given index"
+    }
 
-        // Get the current tab index
+    fn execute_typed(&self, params: CloseTabParams, context: &mut ToolContext) -> Result<ToolResult> {
         let tabs = context.session.get_tabs()?;
-        let current_index = tabs.iter().position(|tab| std::sync::Arc::ptr_eq(tab, &active_tab)).unwrap_or(0);
 
-        // Close the active tab
-        active_tab
-            .close(true)
+        // Get the tab to close and its index
+        let (current_index, tab) = match params.index {
+            Some(index) if index >= tabs.len() => {
+                return Ok(ToolResult::failure(format!(
+                    "Invalid tab index: {}. Valid range: 0-{}",
+                    index,
+                    tabs.len().saturating_sub(1)
+                )));
+            }
+            Some(index) => (index, tabs[index].clone()),
+            None => {
+                let active_tab = context.session.tab()?;
+                let current_index = tabs.iter().position(|tab| std::sync::Arc::ptr_eq(tab, &active_tab)).unwrap_or(0);
+                (current_index, active_tab)
+            }
+        };
+
+        // Get the tab info before closing
+        let tab_title = tab.get_title().unwrap_or_default();
+        let tab_url = tab.get_url();
+
+        tab.close(true)
             .map_err(|e| crate::error::BrowserError::TabOperationFailed(format!("Failed to close tab: {}", e)))?;
 
         let message = format!("Closed tab [{}]: {} ({})", current_index, tab_title, tab_url);
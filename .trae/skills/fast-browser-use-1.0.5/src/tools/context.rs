@@ -0,0 +1,139 @@
+use crate::{error::{BrowserError, Result},
+            tools::{GetConsoleLogsParams, GetNetworkErrorsParams, SnapshotParams, Tool, ToolContext, ToolResult,
+                    debug::{GetConsoleLogsTool, GetNetworkErrorsTool},
+                    snapshot::SnapshotTool}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+fn default_include() -> bool {
+    true
+}
+
+fn default_max_errors() -> usize {
+    10
+}
+
+/// Parameters for the page_context tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PageContextParams {
+    /// Include the snapshot of the page with indexed interactive elements (default: true)
+    #[serde(default = "default_include")]
+    pub snapshot: bool,
+
+    /// Include the most recent console errors (default: true)
+    #[serde(default = "default_include")]
+    pub console_errors: bool,
+
+    /// Include the most recent failed network requests (default: true)
+    #[serde(default = "default_include")]
+    pub network_errors: bool,
+
+    /// Maximum number of console and network errors returned each, most recent last (default: 10)
+    #[serde(default = "default_max_errors")]
+    pub max_errors: usize,
+}
+
+impl Default for PageContextParams {
+    fn default() -> Self {
+        Self {
+            snapshot: default_include(),
+            console_errors: default_include(),
+            network_errors: default_include(),
+            max_errors: default_max_errors(),
+        }
+    }
+}
+
+/// The last `max` entries of a JSON array returned by a debug tool, keeping those that match `keep`
+fn recent(entries: Option<Value>, max: usize, keep: impl Fn(&Value) -> bool) -> Vec<Value> {
+    let mut entries: Vec<Value> = match entries {
+        Some(Value::Array(entries)) => entries.into_iter().filter(|entry| keep(entry)).collect(),
+        _ => Vec::new(),
+    };
+    let skip = entries.len().saturating_sub(max);
+    entries.drain(..skip);
+    entries
+}
+
+/// Tool for getting what an agent needs to know about the page in one call: URL, title, snapshot
+/// and recent errors
+#[derive(Default)]
+pub struct PageContextTool;
+
+impl Tool for PageContextTool {
+    type Params = PageContextParams;
+
+    fn name(&self) -> &str {
+        "page_context"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the URL, title, snapshot and recent console and network errors of the current page in one call"
+    }
+
+    fn execute_typed(&self, params: PageContextParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let title = tab.get_title().map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "page_context".to_string(),
+            reason: format!("Failed to get title: {}", e),
+        })?;
+
+        let mut result = serde_json::json!({
+            "url": tab.get_url(),
+            "title": title,
+        });
+
+        if params.snapshot {
+            let snapshot = SnapshotTool.execute_typed(SnapshotParams::default(), context)?.data.unwrap_or_default();
+            result["snapshot"] = snapshot["snapshot"].clone();
+            result["interactive_count"] = snapshot["interactive_count"].clone();
+        }
+
+        if params.console_errors {
+            let logs = GetConsoleLogsTool.execute_typed(GetConsoleLogsParams::default(), context)?.data;
+            // Both console.error calls and browser log entries of level error are reported as "Error"
+            let errors = recent(logs, params.max_errors, |log| log["type_"] == "Error");
+            result["recent_console_errors"] = Value::Array(errors);
+        }
+
+        if params.network_errors {
+            let errors = GetNetworkErrorsTool.execute_typed(GetNetworkErrorsParams {}, context)?.data;
+            result["recent_network_errors"] = Value::Array(recent(errors, params.max_errors, |_| true));
+        }
+
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_context_params_default() {
+        let params: PageContextParams = serde_json::from_value(serde_json::json!({ "snapshot": false })).unwrap();
+        assert!(!params.snapshot);
+        assert!(params.console_errors);
+        assert!(params.network_errors);
+        assert_eq!(params.max_errors, 10);
+    }
+
+    #[test]
+    fn test_recent_keeps_last_matching_entries() {
+        let logs = serde_json::json!([
+            { "type_": "Error", "text": "first" },
+            { "type_": "Log", "text": "ignored" },
+            { "type_": "Error", "text": "second" },
+            { "type_": "Error", "text": "third" },
+        ]);
+        let errors = recent(Some(logs), 2, |log| log["type_"] == "Error");
+        assert_eq!(errors.iter().map(|log| log["text"].as_str().unwrap()).collect::<Vec<_>>(), ["second", "third"]);
+
+        assert!(recent(None, 10, |_| true).is_empty());
+    }
+}
@@ -25,6 +25,13 @@ pub struct SetCookiesParams {
     pub cookies: Vec<CookieParam>,
 }
 
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ClearCookiesParams {
+    /// Only delete cookies for this domain and its subdomains (default: clear all cookies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+}
+
 #[derive(Default)]
 pub struct GetCookiesTool;
 
@@ -35,6 +42,14 @@ impl Tool for GetCookiesTool {
         "get_cookies"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the cookies of the current page"
+    }
+
     fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
         let cookies = context.session.get_cookies()?;
         Ok(ToolResult::success_with(cookies))
@@ -51,8 +66,32 @@ impl Tool for SetCookiesTool {
         "set_cookies"
     }
 
+    fn description(&self) -> &str {
+        "Set one or more cookies in the browser"
+    }
+
     fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
         context.session.set_cookies(params.cookies)?;
         Ok(ToolResult::success(None))
     }
 }
+
+#[derive(Default)]
+pub struct ClearCookiesTool;
+
+impl Tool for ClearCookiesTool {
+    type Params = ClearCookiesParams;
+
+    fn name(&self) -> &str {
+        "clear_cookies"
+    }
+
+    fn description(&self) -> &str {
+        "Clear browser cookies, optionally only those of a given domain"
+    }
+
+    fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        let cleared = context.session.clear_cookies(params.domain.as_deref())?;
+        Ok(ToolResult::success_with(serde_json::json!({ "cleared": cleared })))
+    }
+}
@@ -1,9 +1,12 @@
-use crate::{browser::debug::{ConsoleLog, NetworkError}, error::Result, tools::{Tool, ToolContext, ToolResult}};
+use crate::{error::Result, tools::{Tool, ToolContext, ToolResult}};
 use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct GetConsoleLogsParams {
-    // No params needed, gets all logs since session start
+    /// Only return entries logged within the last N milliseconds (default: all logs since session start or last clear)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since_ms: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -11,6 +14,21 @@ pub struct GetNetworkErrorsParams {
     // No params needed
 }
 
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GetJsExceptionsParams {
+    // No params needed
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ClearConsoleLogsParams {
+    // No params needed
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ClearNetworkErrorsParams {
+    // No params needed
+}
+
 #[derive(Default)]
 pub struct GetConsoleLogsTool;
 
@@ -21,8 +39,22 @@ impl Tool for GetConsoleLogsTool {
         "get_console_logs"
     }
 
-    fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
-        let logs = context.session.get_console_logs()?;
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get console messages logged by the page"
+    }
+
+    fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        let mut logs = context.session.get_console_logs()?;
+        if let Some(since_ms) = params.since_ms {
+            // Console timestamps are milliseconds since the Unix epoch
+            let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as f64).unwrap_or(0.0);
+            let cutoff = now_ms - since_ms as f64;
+            logs.retain(|log| log.timestamp >= cutoff);
+        }
         Ok(ToolResult::success_with(logs))
     }
 }
@@ -37,8 +69,84 @@ impl Tool for GetNetworkErrorsTool {
         "get_network_errors"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get failed network requests made by the page"
+    }
+
     fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
         let errors = context.session.get_network_errors()?;
         Ok(ToolResult::success_with(errors))
     }
 }
+
+#[derive(Default)]
+pub struct GetJsExceptionsTool;
+
+impl Tool for GetJsExceptionsTool {
+    type Params = GetJsExceptionsParams;
+
+    fn name(&self) -> &str {
+        "get_js_exceptions"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get uncaught JavaScript exceptions thrown by the page, with message, stack and script URL"
+    }
+
+    fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        let exceptions = context.session.get_js_exceptions()?;
+        Ok(ToolResult::success_with(exceptions))
+    }
+}
+
+#[derive(Default)]
+pub struct ClearConsoleLogsTool;
+
+impl Tool for ClearConsoleLogsTool {
+    type Params = ClearConsoleLogsParams;
+
+    fn name(&self) -> &str {
+        "clear_console_logs"
+    }
+
+    fn description(&self) -> &str {
+        "Clear the captured console messages"
+    }
+
+    fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        context.session.clear_console_logs()?;
+        Ok(ToolResult::success_with(serde_json::json!({
+            "message": "Console logs cleared"
+        })))
+    }
+}
+
+#[derive(Default)]
+pub struct ClearNetworkErrorsTool;
+
+impl Tool for ClearNetworkErrorsTool {
+    type Params = ClearNetworkErrorsParams;
+
+    fn name(&self) -> &str {
+        "clear_network_errors"
+    }
+
+    fn description(&self) -> &str {
+        "Clear the captured network errors"
+    }
+
+    fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        context.session.clear_network_errors()?;
+        Ok(ToolResult::success_with(serde_json::json!({
+            "message": "Network errors cleared"
+        })))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, utils::with_retry}};
+use headless_chrome::browser::tab::element::ElementQuad;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+fn default_styles() -> Vec<String> {
+    ["display", "visibility", "position", "color", "font-size"].iter().map(|s| s.to_string()).collect()
+}
+
+/// Parameters for the describe_element tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DescribeElementParams {
+    /// CSS selector (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Computed CSS properties to return (default: display, visibility, position, color, font-size)
+    #[serde(default = "default_styles")]
+    pub styles: Vec<String>,
+}
+
+/// Tool for inspecting an element's tag, attributes, computed styles and box model, e.g. to find
+/// out why it is hidden or laid out unexpectedly
+#[derive(Default)]
+pub struct DescribeElementTool;
+
+/// Computed values of the requested properties, as a JSON string
+const COMPUTED_STYLES_JS: &str = "function(properties) {
+    const style = window.getComputedStyle(this);
+    const values = {};
+    for (const property of properties) values[property] = style.getPropertyValue(property);
+    return JSON.stringify(values);
+}";
+
+/// Flat `[name, value, name, value, ...]` attribute list from CDP as a JSON object
+fn attribute_map(attributes: &[String]) -> serde_json::Map<String, serde_json::Value> {
+    attributes.chunks_exact(2).map(|pair| (pair[0].clone(), serde_json::json!(pair[1]))).collect()
+}
+
+/// Rectangle of a box model quad in CSS pixels from the top left of the document
+fn quad_rect(quad: &ElementQuad) -> serde_json::Value {
+    serde_json::json!({
+        "x": quad.most_left(),
+        "y": quad.most_top(),
+        "width": quad.width(),
+        "height": quad.height(),
+    })
+}
+
+impl Tool for DescribeElementTool {
+    type Params = DescribeElementParams;
+
+    fn name(&self) -> &str {
+        "describe_element"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the tag, attributes, computed styles and box model of an element specified by CSS selector or index"
+    }
+
+    fn execute_typed(&self, params: DescribeElementParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Validate that exactly one selector method is provided
+        match (&params.selector, &params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "describe_element".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "describe_element".to_string(),
+                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let tab = context.tab()?;
+        let (element, css_selector) = if let Some(selector) = params.selector {
+            (context.session.find_element(&tab, &selector)?, selector)
+        } else if let Some(index) = params.index {
+            with_retry(context, index, |context, selector| {
+                Ok((context.session.find_element(&tab, selector)?, selector.to_string()))
+            })?
+        } else {
+            unreachable!("Validation above ensures one field is Some")
+        };
+        let failed = |e: anyhow::Error| BrowserError::ToolExecutionFailed {
+            tool: "describe_element".to_string(),
+            reason: e.to_string(),
+        };
+
+        let node = element.get_description().map_err(failed)?;
+        let attributes = attribute_map(node.attributes.as_deref().unwrap_or_default());
+
+        let result =
+            element.call_js_fn(COMPUTED_STYLES_JS, vec![serde_json::json!(params.styles)], false).map_err(failed)?;
+        let styles = match result.value {
+            Some(serde_json::Value::String(json_str)) => serde_json::from_str(&json_str).unwrap_or_default(),
+            _ => serde_json::json!({}),
+        };
+
+        // Elements that aren't rendered (e.g. display: none) have no box model
+        let box_model = element.get_box_model().ok().map(|model| {
+            serde_json::json!({
+                "content": quad_rect(&model.content),
+                "padding": quad_rect(&model.padding),
+                "border": quad_rect(&model.border),
+                "margin": quad_rect(&model.margin),
+                "width": model.width,
+                "height": model.height,
+            })
+        });
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "selector": css_selector,
+            "tag": node.local_name,
+            "attributes": attributes,
+            "styles": styles,
+            "box_model": box_model,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_element_params_default_styles() {
+        let params: DescribeElementParams = serde_json::from_value(serde_json::json!({ "selector": "#main" })).unwrap();
+        assert_eq!(params.styles, vec!["display", "visibility", "position", "color", "font-size"]);
+
+        let params: DescribeElementParams =
+            serde_json::from_value(serde_json::json!({ "index": 3, "styles": ["z-index"] })).unwrap();
+        assert_eq!(params.styles, vec!["z-index"]);
+    }
+
+    #[test]
+    fn test_attribute_map() {
+        let attributes = ["id", "main", "class", "a b", "hidden", ""].map(String::from);
+        assert_eq!(
+            serde_json::Value::Object(attribute_map(&attributes)),
+            serde_json::json!({ "id": "main", "class": "a b", "hidden": "" })
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+fn default_accept() -> bool {
+    true
+}
+
+/// Parameters for the handle_dialog tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HandleDialogParams {
+    /// Press OK (true) or Cancel (false) (default: true)
+    #[serde(default = "default_accept")]
+    pub accept: bool,
+
+    /// Text to enter into prompt() dialogs when accepting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_text: Option<String>,
+}
+
+/// Tool for answering JavaScript alert/confirm/prompt dialogs
+#[derive(Default)]
+pub struct HandleDialogTool;
+
+impl Tool for HandleDialogTool {
+    type Params = HandleDialogParams;
+
+    fn name(&self) -> &str {
+        "handle_dialog"
+    }
+
+    fn description(&self) -> &str {
+        "Accept or dismiss JavaScript alert/confirm/prompt dialogs, including ones that open later"
+    }
+
+    fn execute_typed(&self, params: HandleDialogParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let handled = context.session.set_dialog_handler(params.accept, params.prompt_text.clone())?;
+        let last_message = context.session.get_dialogs()?.last().map(|dialog| dialog.message.clone());
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "accept": params.accept,
+            "prompt_text": params.prompt_text,
+            "handled": handled,
+            "last_message": last_message,
+        })))
+    }
+}
@@ -0,0 +1,76 @@
+JSON.stringify(
+  (function () {
+    const config = __DISMISS_CONFIG__;
+    const CONSENT_PATTERN = /cookie|consent|gdpr|privacy|cmp|banner/i;
+
+    const isVisible = (element) => {
+      const rect = element.getBoundingClientRect();
+      const style = window.getComputedStyle(element);
+      return rect.width > 0 && rect.height > 0 && style.visibility !== "hidden" && style.display !== "none";
+    };
+
+    const normalize = (text) =>
+      (text || "").replace(/\s+/g, " ").trim().replace(/[.!]+$/, "").toLowerCase();
+
+    const describe = (element) => {
+      if (!element) return null;
+      let label = element.tagName.toLowerCase();
+      if (element.id) label += "#" + element.id;
+      if (typeof element.className === "string" && element.className.trim()) {
+        label += "." + element.className.trim().split(/\s+/).join(".");
+      }
+      return label;
+    };
+
+    // The banner is the nearest ancestor that looks like a consent container, or else the
+    // nearest fixed/sticky overlay
+    const findBanner = (element) => {
+      let overlay = null;
+      for (let node = element.parentElement; node && node !== document.body; node = node.parentElement) {
+        const name = node.id + " " + (typeof node.className === "string" ? node.className : "");
+        if (CONSENT_PATTERN.test(name) || CONSENT_PATTERN.test(node.getAttribute("aria-label") || "")) {
+          return node;
+        }
+        const position = window.getComputedStyle(node).position;
+        if (!overlay && (position === "fixed" || position === "sticky")) overlay = node;
+      }
+      return overlay;
+    };
+
+    const dismiss = (element, method, match) => {
+      const banner = findBanner(element);
+      element.click();
+      return {
+        dismissed: true,
+        method,
+        match,
+        text: (element.innerText || element.value || "").trim(),
+        banner: describe(banner),
+      };
+    };
+
+    // Known consent managers first: their accept buttons have stable selectors
+    for (const selector of config.selectors) {
+      let element = null;
+      try {
+        element = document.querySelector(selector);
+      } catch (e) {
+        continue;
+      }
+      if (element && isVisible(element)) return dismiss(element, "selector", selector);
+    }
+
+    // Otherwise a visible button with a known text inside something that looks like a banner;
+    // texts earlier in the list (e.g. "accept all") win over later ones (e.g. "ok")
+    const candidates = Array.from(
+      document.querySelectorAll("button, a, [role='button'], input[type='button'], input[type='submit']"),
+    ).filter((element) => isVisible(element) && findBanner(element));
+    for (const text of config.texts) {
+      const wanted = normalize(text);
+      const element = candidates.find((candidate) => normalize(candidate.innerText || candidate.value) === wanted);
+      if (element) return dismiss(element, "text", text);
+    }
+
+    return { dismissed: false };
+  })(),
+);
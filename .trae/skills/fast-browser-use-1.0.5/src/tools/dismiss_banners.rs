@@ -0,0 +1,126 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the dismiss_banners tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DismissBannersParams {
+    /// Additional button texts to click (case-insensitive, whole text), tried before the built-in ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub texts: Vec<String>,
+
+    /// Additional CSS selectors of consent buttons, tried before the built-in ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selectors: Vec<String>,
+}
+
+/// Accept buttons of widespread consent management platforms
+const CONSENT_SELECTORS: &[&str] = &[
+    "#onetrust-accept-btn-handler",
+    "#CybotCookiebotDialogBodyLevelButtonLevelOptinAllowAll",
+    "#CybotCookiebotDialogBodyButtonAccept",
+    "#didomi-notice-agree-button",
+    "#truste-consent-button",
+    "[data-testid='uc-accept-all-button']",
+    ".qc-cmp2-summary-buttons button[mode='primary']",
+    ".fc-cta-consent",
+    "#sp-cc-accept",
+    ".cc-allow",
+    ".cc-dismiss",
+];
+
+/// Texts of consent buttons, most specific first so "Accept all" wins over a plain "OK"
+const CONSENT_TEXTS: &[&str] = &[
+    "accept all cookies",
+    "accept all",
+    "allow all cookies",
+    "allow all",
+    "accept cookies",
+    "allow cookies",
+    "i accept",
+    "accept",
+    "i agree",
+    "agree",
+    "got it",
+    "ok",
+    "okay",
+    "alle akzeptieren",
+    "akzeptieren",
+    "tout accepter",
+    "accepter",
+    "aceptar todo",
+    "aceptar",
+    "accetta tutto",
+    "accetta",
+];
+
+const DISMISS_BANNERS_JS: &str = include_str!("dismiss_banners.js");
+
+/// Configuration passed to the script: the caller's selectors and texts ahead of the built-in ones
+fn dismiss_config(params: &DismissBannersParams) -> serde_json::Value {
+    let selectors: Vec<&str> =
+        params.selectors.iter().map(String::as_str).chain(CONSENT_SELECTORS.iter().copied()).collect();
+    let texts: Vec<&str> = params.texts.iter().map(String::as_str).chain(CONSENT_TEXTS.iter().copied()).collect();
+    serde_json::json!({ "selectors": selectors, "texts": texts })
+}
+
+/// Tool for clicking away cookie and consent banners that cover the page
+#[derive(Default)]
+pub struct DismissBannersTool;
+
+impl Tool for DismissBannersTool {
+    type Params = DismissBannersParams;
+
+    fn name(&self) -> &str {
+        "dismiss_banners"
+    }
+
+    fn description(&self) -> &str {
+        "Click the accept button of a cookie or consent banner, matching known consent managers and common texts"
+    }
+
+    fn execute_typed(&self, params: DismissBannersParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let dismiss_js = DISMISS_BANNERS_JS.replace("__DISMISS_CONFIG__", &dismiss_config(&params).to_string());
+
+        let result = context.tab()?.evaluate(&dismiss_js, false).map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "dismiss_banners".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Parse the JSON string returned by JavaScript
+        let result_json: serde_json::Value = match result.value {
+            Some(serde_json::Value::String(json_str)) => serde_json::from_str(&json_str).map_err(|e| {
+                BrowserError::ToolExecutionFailed { tool: "dismiss_banners".to_string(), reason: e.to_string() }
+            })?,
+            _ => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "dismiss_banners".to_string(),
+                    reason: "No result returned".to_string(),
+                });
+            }
+        };
+
+        // Finding no banner is a normal outcome, not an error
+        Ok(ToolResult::success_with(result_json))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dismiss_config_puts_custom_matches_first() {
+        let params =
+            DismissBannersParams { texts: vec!["Alles klar".to_string()], selectors: vec!["#my-consent".to_string()] };
+        let config = dismiss_config(&params);
+        assert_eq!(config["texts"][0], "Alles klar");
+        assert_eq!(config["texts"][1], CONSENT_TEXTS[0]);
+        assert_eq!(config["selectors"][0], "#my-consent");
+        assert_eq!(config["selectors"].as_array().unwrap().len(), CONSENT_SELECTORS.len() + 1);
+
+        let config = dismiss_config(&DismissBannersParams::default());
+        assert_eq!(config["texts"].as_array().unwrap().len(), CONSENT_TEXTS.len());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{error::{BrowserError, Result},
             tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::{Tab, protocol::cdp::Runtime};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -12,6 +13,12 @@ pub struct EvaluateParams {
     /// Wait for promise resolution (default: false)
     #[serde(default)]
     pub await_promise: bool,
+
+    /// Arguments to pass to the code. When non-empty, `code` is run as the body of
+    /// `function(...args) { <code> }`, so it must `return` its result and reads the
+    /// values via `args[0]`, `args[1]`, ... (or `arguments`) instead of string interpolation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<Value>,
 }
 
 #[derive(Default)]
@@ -24,17 +31,120 @@ impl Tool for EvaluateTool {
         "evaluate"
     }
 
+    fn description(&self) -> &str {
+        "Execute JavaScript code in the browser context"
+    }
+
     fn execute_typed(&self, params: EvaluateParams, context: &mut ToolContext) -> Result<ToolResult> {
-        let result = context
-            .session
-            .tab()?
-            .evaluate(&params.code, params.await_promise)
+        let tab = context.tab()?;
+
+        if !params.args.is_empty() {
+            return call_with_args(&tab, params);
+        }
+
+        // Call Runtime.evaluate directly: tab.evaluate() returns objects by reference, so
+        // resolved Promises and plain objects would come back without a value
+        let response = tab
+            .call_method(Runtime::Evaluate {
+                expression: params.code,
+                object_group: None,
+                include_command_line_api: Some(false),
+                silent: Some(false),
+                context_id: None,
+                return_by_value: Some(true),
+                generate_preview: Some(false),
+                user_gesture: Some(false),
+                await_promise: Some(params.await_promise),
+                throw_on_side_effect: None,
+                timeout: None,
+                disable_breaks: None,
+                repl_mode: None,
+                allow_unsafe_eval_blocked_by_csp: None,
+                unique_context_id: None,
+                serialization_options: None,
+            })
             .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
 
-        let result_value = result.value.unwrap_or(Value::Null);
+        if let Some(details) = response.exception_details {
+            return Err(BrowserError::EvaluationFailed(exception_message(&details)));
+        }
+
+        let result_value = response.result.value.unwrap_or(Value::Null);
 
         Ok(ToolResult::success_with(serde_json::json!({
             "result": result_value
         })))
     }
 }
+
+/// Run `code` as a function body with `args` bound via Runtime.callFunctionOn
+fn call_with_args(tab: &Tab, params: EvaluateParams) -> Result<ToolResult> {
+    // callFunctionOn needs a target object; use the page's global object as `this`
+    let global = tab.evaluate("globalThis", false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+
+    let arguments = params
+        .args
+        .into_iter()
+        .map(|value| Runtime::CallArgument { value: Some(value), unserializable_value: None, object_id: None })
+        .collect();
+
+    let response = tab
+        .call_method(Runtime::CallFunctionOn {
+            function_declaration: format!("function(...args) {{\n{}\n}}", params.code),
+            object_id: global.object_id,
+            arguments: Some(arguments),
+            silent: Some(false),
+            return_by_value: Some(true),
+            generate_preview: Some(false),
+            user_gesture: Some(false),
+            await_promise: Some(params.await_promise),
+            execution_context_id: None,
+            object_group: None,
+            throw_on_side_effect: None,
+            unique_context_id: None,
+            serialization_options: None,
+        })
+        .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+
+    if let Some(details) = response.exception_details {
+        return Err(BrowserError::EvaluationFailed(exception_message(&details)));
+    }
+
+    let result_value = response.result.value.unwrap_or(Value::Null);
+
+    Ok(ToolResult::success_with(serde_json::json!({
+        "result": result_value
+    })))
+}
+
+/// Human-readable message for a thrown exception or rejected Promise
+fn exception_message(details: &Runtime::ExceptionDetails) -> String {
+    details
+        .exception
+        .as_ref()
+        .and_then(|e| e.description.clone().or_else(|| e.value.as_ref().map(|v| v.to_string())))
+        .map(|description| format!("{} {}", details.text, description))
+        .unwrap_or_else(|| details.text.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_params_args_default() {
+        let params: EvaluateParams = serde_json::from_value(serde_json::json!({ "code": "1 + 1" })).unwrap();
+        assert!(params.args.is_empty());
+        assert!(!params.await_promise);
+    }
+
+    #[test]
+    fn test_evaluate_params_with_args() {
+        let params: EvaluateParams = serde_json::from_value(serde_json::json!({
+            "code": "return args[0] + args[1].length;",
+            "args": [1, "two"]
+        }))
+        .unwrap();
+        assert_eq!(params.args, vec![serde_json::json!(1), serde_json::json!("two")]);
+    }
+}
@@ -28,9 +28,17 @@ impl Tool for ExtractContentTool {
         "extract"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Extract text or HTML content from the page or an element"
+    }
+
     fn execute_typed(&self, params: ExtractParams, context: &mut ToolContext) -> Result<ToolResult> {
         let content = if let Some(selector) = &params.selector {
-            let tab = context.session.tab()?;
+            let tab = context.tab()?;
             let element = context.session.find_element(&tab, selector)?;
 
             if params.format == "html" {
@@ -48,11 +56,8 @@ impl Tool for ExtractContentTool {
             // Extract from body
             let js_code = if params.format == "html" { "document.body.innerHTML" } else { "document.body.innerText" };
 
-            let result = context
-                .session
-                .tab()?
-                .evaluate(js_code, false)
-                .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+            let result =
+                context.tab()?.evaluate(js_code, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
 
             result.value.and_then(|v| v.as_str().map(String::from)).unwrap_or_default()
         };
@@ -18,18 +18,24 @@ impl Tool for GoBackTool {
         "go_back"
     }
 
+    fn description(&self) -> &str {
+        "Navigate back in browser history"
+    }
+
     fn execute_typed(&self, _params: GoBackParams, context: &mut ToolContext) -> Result<ToolResult> {
-        context
+        let tab = context.tab()?;
+        let navigated = context
             .session
-            .go_back()
+            .go_back_in(&tab)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "go_back".to_string(), reason: e.to_string() })?;
 
         // Get current URL after going back
-        let current_url = context.session.tab()?.get_url();
+        let current_url = tab.get_url();
 
         Ok(ToolResult::success_with(serde_json::json!({
             "message": "Navigated back in history",
-            "url": current_url
+            "url": current_url,
+            "navigated": navigated
         })))
     }
 }
@@ -18,18 +18,24 @@ impl Tool for GoForwardTool {
         "go_forward"
     }
 
+    fn description(&self) -> &str {
+        "Navigate forward in browser history"
+    }
+
     fn execute_typed(&self, _params: GoForwardParams, context: &mut ToolContext) -> Result<ToolResult> {
-        context
+        let tab = context.tab()?;
+        let navigated = context
             .session
-            .go_forward()
+            .go_forward_in(&tab)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "go_forward".to_string(), reason: e.to_string() })?;
 
         // Get current URL after going forward
-        let current_url = context.session.tab()?.get_url();
+        let current_url = tab.get_url();
 
         Ok(ToolResult::success_with(serde_json::json!({
             "message": "Navigated forward in history",
-            "url": current_url
+            "url": current_url,
+            "navigated": navigated
         })))
     }
 }
@@ -0,0 +1,49 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the health tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HealthParams {}
+
+/// Tool for checking that the browser is still running and responsive
+#[derive(Default)]
+pub struct HealthTool;
+
+impl Tool for HealthTool {
+    type Params = HealthParams;
+
+    fn name(&self) -> &str {
+        "health"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Check whether the browser is alive and report its version and tab count"
+    }
+
+    fn execute_typed(&self, _params: HealthParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let version = match context.session.version() {
+            Ok(version) => version,
+            Err(e) => {
+                return Ok(ToolResult::success_with(serde_json::json!({
+                    "alive": false,
+                    "error": e.to_string(),
+                })));
+            }
+        };
+        let tab_count = context.session.get_tabs().map(|tabs| tabs.len()).unwrap_or_default();
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "alive": true,
+            "product": version.product,
+            "protocol_version": version.protocol_version,
+            "user_agent": version.user_agent,
+            "tab_count": tab_count,
+        })))
+    }
+}
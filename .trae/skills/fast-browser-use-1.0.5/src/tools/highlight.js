@@ -0,0 +1,53 @@
+JSON.stringify(
+  (function () {
+    const selector = __SELECTOR__;
+    const durationMs = __DURATION_MS__;
+    const element = document.querySelector(selector);
+    if (!element) {
+      return { success: false, error: "Element not found" };
+    }
+
+    element.scrollIntoView({
+      behavior: "auto",
+      block: "center",
+      inline: "center",
+    });
+
+    const fadeMs = Math.min(300, durationMs);
+    const rect = element.getBoundingClientRect();
+    const overlay = document.createElement("div");
+    overlay.setAttribute("data-browser-use-highlight", "");
+    Object.assign(overlay.style, {
+      position: "absolute",
+      left: `${rect.left + window.scrollX}px`,
+      top: `${rect.top + window.scrollY}px`,
+      width: `${rect.width}px`,
+      height: `${rect.height}px`,
+      boxSizing: "border-box",
+      border: "2px solid #ff3b30",
+      background: "rgba(255, 59, 48, 0.25)",
+      borderRadius: "2px",
+      pointerEvents: "none",
+      zIndex: "2147483647",
+      opacity: "1",
+      transition: `opacity ${fadeMs}ms ease-out`,
+    });
+    document.documentElement.appendChild(overlay);
+
+    // Fade out over the last part of the duration, then remove the overlay
+    setTimeout(() => {
+      overlay.style.opacity = "0";
+    }, durationMs - fadeMs);
+    setTimeout(() => overlay.remove(), durationMs);
+
+    return {
+      success: true,
+      rect: {
+        x: rect.left,
+        y: rect.top,
+        width: rect.width,
+        height: rect.height,
+      },
+    };
+  })(),
+);
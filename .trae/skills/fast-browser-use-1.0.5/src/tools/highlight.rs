@@ -0,0 +1,106 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+fn default_duration_ms() -> u64 {
+    1500
+}
+
+/// Parameters for the highlight tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HighlightParams {
+    /// CSS selector (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// How long the overlay stays on the page before it has faded out (default: 1500)
+    #[serde(default = "default_duration_ms")]
+    pub duration_ms: u64,
+}
+
+/// Tool for flashing an overlay over an element in the live page
+#[derive(Default)]
+pub struct HighlightTool;
+
+const HIGHLIGHT_JS: &str = include_str!("highlight.js");
+
+impl Tool for HighlightTool {
+    type Params = HighlightParams;
+
+    fn name(&self) -> &str {
+        "highlight"
+    }
+
+    fn description(&self) -> &str {
+        "Briefly highlight an element specified by CSS selector or index with an overlay"
+    }
+
+    fn execute_typed(&self, params: HighlightParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Validate that exactly one selector method is provided
+        match (&params.selector, &params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "highlight".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "highlight".to_string(),
+                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let css_selector = if let Some(selector) = params.selector {
+            selector
+        } else if let Some(index) = params.index {
+            let dom = context.get_dom()?;
+            let selector = dom
+                .get_selector(index)
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+            selector.clone()
+        } else {
+            unreachable!("Validation above ensures one field is Some")
+        };
+
+        let selector_json = serde_json::to_string(&css_selector).expect("serializing CSS selector never fails");
+        let highlight_js = HIGHLIGHT_JS
+            .replace("__SELECTOR__", &selector_json)
+            .replace("__DURATION_MS__", &params.duration_ms.to_string());
+
+        let result = context
+            .tab()?
+            .evaluate(&highlight_js, false)
+            .map_err(|e| BrowserError::ToolExecutionFailed { tool: "highlight".to_string(), reason: e.to_string() })?;
+
+        // Parse the JSON string returned by JavaScript
+        let result_json: serde_json::Value = if let Some(serde_json::Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+        };
+
+        if result_json["success"].as_bool() == Some(true) {
+            Ok(ToolResult::success_with(serde_json::json!({
+                "selector": css_selector,
+                "rect": result_json["rect"],
+                "duration_ms": params.duration_ms
+            })))
+        } else if result_json["error"].as_str() == Some("Element not found") {
+            Err(BrowserError::ElementNotFound(css_selector))
+        } else {
+            Err(BrowserError::ToolExecutionFailed {
+                tool: "highlight".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            })
+        }
+    }
+}
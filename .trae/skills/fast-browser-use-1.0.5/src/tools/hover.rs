@@ -1,5 +1,5 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult}};
+            tools::{Tool, ToolContext, ToolResult, human_mouse::move_mouse_to_element}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +13,11 @@ pub struct HoverParams {
     /// Element index from DOM tree (use either this or selector, not both)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<usize>,
+
+    /// Move the mouse to the element along a curved, slightly jittery path, like a person would
+    /// (default: false)
+    #[serde(default)]
+    pub emulate_human: bool,
 }
 
 /// Tool for hovering over elements
@@ -28,6 +33,10 @@ impl Tool for HoverTool {
         "hover"
     }
 
+    fn description(&self) -> &str {
+        "Hover over an element specified by CSS selector or index"
+    }
+
     fn execute_typed(&self, params: HoverParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Validate that exactly one selector method is provided
         match (&params.selector, &params.index) {
@@ -58,14 +67,18 @@ impl Tool for HoverTool {
             unreachable!("Validation above ensures one field is Some")
         };
 
-        // Find the element (to verify it exists)
+        // Real mouse moves trigger :hover and mouse events the way a person's would
+        if params.emulate_human {
+            let tab = context.tab()?;
+            let element = context.session.find_element(&tab, &css_selector)?;
+            move_mouse_to_element(context.session, &tab, &element)?;
+        }
 
         // Scroll into view if needed, then hover
         let selector_json = serde_json::to_string(&css_selector).expect("serializing CSS selector never fails");
         let hover_js = HOVER_JS.replace("__SELECTOR__", &selector_json);
 
         let result = context
-            .session
             .tab()?
             .evaluate(&hover_js, false)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "hover".to_string(), reason: e.to_string() })?;
@@ -0,0 +1,71 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult,
+                    hover::{HoverParams, HoverTool}}};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{thread, time::Duration};
+
+/// Parameters for the hover_capture tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HoverCaptureParams {
+    /// CSS selector (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Time in milliseconds to let hover effects (tooltips, menus) appear before capturing (default: 300)
+    #[serde(default = "default_settle_ms")]
+    pub settle_ms: u64,
+}
+
+fn default_settle_ms() -> u64 {
+    300
+}
+
+/// Tool for hovering an element and screenshotting the result in one step,
+/// so transient hover UI is still visible when the capture happens
+#[derive(Default)]
+pub struct HoverCaptureTool;
+
+impl Tool for HoverCaptureTool {
+    type Params = HoverCaptureParams;
+
+    fn name(&self) -> &str {
+        "hover_capture"
+    }
+
+    fn description(&self) -> &str {
+        "Hover over an element and capture a screenshot while hover effects such as tooltips are visible"
+    }
+
+    fn execute_typed(&self, params: HoverCaptureParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let hover_result = HoverTool.execute_typed(
+            HoverParams { selector: params.selector, index: params.index, emulate_human: false },
+            context,
+        )?;
+
+        thread::sleep(Duration::from_millis(params.settle_ms));
+
+        let screenshot_data = context
+            .tab()?
+            .capture_screenshot(
+                headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
+                None,
+                None,
+                true,
+            )
+            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?;
+
+        let hover_data = hover_result.data.unwrap_or_default();
+        Ok(ToolResult::success_with(serde_json::json!({
+            "selector": hover_data["selector"],
+            "element": hover_data["element"],
+            "image_base64": BASE64.encode(&screenshot_data),
+            "size_bytes": screenshot_data.len()
+        })))
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::{Tab, protocol::cdp::Page};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+fn default_rendered() -> bool {
+    true
+}
+
+/// Parameters for the html_source tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HtmlSourceParams {
+    /// Return the current DOM serialized as HTML (default: true); false returns the HTML
+    /// the server originally sent, before any script ran
+    #[serde(default = "default_rendered")]
+    pub rendered: bool,
+}
+
+/// Main frame of `tab`, which carries the document's URL and MIME type
+pub(crate) fn main_frame(tab: &Tab) -> Result<Page::Frame> {
+    Ok(tab
+        .call_method(Page::GetFrameTree(None))
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to get frame tree: {}", e)))?
+        .frame_tree
+        .frame)
+}
+
+/// Body of the document in `frame` as the server sent it, possibly base64-encoded
+pub(crate) fn original_response(tab: &Tab, frame: &Page::Frame) -> Result<Page::GetResourceContentReturnObject> {
+    // Chrome keeps the main document's response body around; fetch it by frame and URL
+    // rather than tracking the document's request ID for Network.getResponseBody
+    tab.call_method(Page::GetResourceContent { frame_id: frame.id.clone(), url: frame.url.clone() })
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to get original response for {}: {}", frame.url, e)))
+}
+
+/// Decode a base64-encoded response body
+pub(crate) fn decode_body(content: &str) -> Result<Vec<u8>> {
+    BASE64.decode(content).map_err(|e| BrowserError::ChromeError(format!("Failed to decode response body: {}", e)))
+}
+
+/// Tool for getting the full HTML of the current page
+#[derive(Default)]
+pub struct HtmlSourceTool;
+
+const RENDERED_HTML_JS: &str = r#"
+(document.doctype ? new XMLSerializer().serializeToString(document.doctype) + "\n" : "")
+    + document.documentElement.outerHTML
+"#;
+
+impl Tool for HtmlSourceTool {
+    type Params = HtmlSourceParams;
+
+    fn name(&self) -> &str {
+        "html_source"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the full HTML of the current page, either the rendered DOM or the original response"
+    }
+
+    fn execute_typed(&self, params: HtmlSourceParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+
+        let html = if params.rendered {
+            let result =
+                tab.evaluate(RENDERED_HTML_JS, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+            match result.value {
+                Some(serde_json::Value::String(html)) => html,
+                _ => return Err(BrowserError::EvaluationFailed("Page has no document element".to_string())),
+            }
+        } else {
+            let resource = original_response(&tab, &main_frame(&tab)?)?;
+            if resource.base_64_encoded {
+                String::from_utf8_lossy(&decode_body(&resource.content)?).into_owned()
+            } else {
+                resource.content
+            }
+        };
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "url": tab.get_url(),
+            "rendered": params.rendered,
+            "length": html.len(),
+            "html": html,
+        })))
+    }
+}
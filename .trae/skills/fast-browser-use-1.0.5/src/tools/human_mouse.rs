@@ -0,0 +1,132 @@
+//! Human-like mouse movement, so interactions look less automated to anti-bot scripts
+
+use crate::{browser::BrowserSession,
+            error::{BrowserError, Result},
+            tools::click_at::mouse_event};
+use headless_chrome::{Element, Tab, protocol::cdp::Input::DispatchMouseEventTypeOption};
+use std::{thread,
+          time::{Duration, SystemTime, UNIX_EPOCH}};
+
+/// Number of mouse moves on the way to a target
+const PATH_STEPS: usize = 25;
+
+/// Largest random offset in CSS pixels of each intermediate point
+const JITTER_PX: f64 = 1.5;
+
+/// Largest sideways push of the curve's control points, as a fraction of the distance
+const MAX_BEND: f64 = 0.3;
+
+/// Pause between two mouse moves
+const STEP_DELAY: Duration = Duration::from_millis(8);
+
+/// Points along a curved path from `from` to `to`, ending exactly at `to`.
+///
+/// The path is a cubic bezier whose control points are pushed sideways by a random amount, and
+/// every point but the last gets a little jitter. `random` yields numbers in `[0, 1)`.
+pub(crate) fn mouse_path(
+    from: (f64, f64),
+    to: (f64, f64),
+    steps: usize,
+    mut random: impl FnMut() -> f64,
+) -> Vec<(f64, f64)> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    // Perpendicular of the straight line, scaled to its length
+    let normal = (-dy, dx);
+    let mut bend = || (random() * 2.0 - 1.0) * MAX_BEND;
+    let (bend1, bend2) = (bend(), bend());
+    let control1 = (from.0 + dx * 0.3 + normal.0 * bend1, from.1 + dy * 0.3 + normal.1 * bend1);
+    let control2 = (from.0 + dx * 0.7 + normal.0 * bend2, from.1 + dy * 0.7 + normal.1 * bend2);
+
+    (1..=steps)
+        .map(|step| {
+            if step == steps {
+                return to;
+            }
+            let t = step as f64 / steps as f64;
+            let u = 1.0 - t;
+            let point = |a: f64, b: f64, c: f64, d: f64| {
+                u * u * u * a + 3.0 * u * u * t * b + 3.0 * u * t * t * c + t * t * t * d
+            };
+            let jitter_x = (random() * 2.0 - 1.0) * JITTER_PX;
+            let jitter_y = (random() * 2.0 - 1.0) * JITTER_PX;
+            (
+                point(from.0, control1.0, control2.0, to.0) + jitter_x,
+                point(from.1, control1.1, control2.1, to.1) + jitter_y,
+            )
+        })
+        .collect()
+}
+
+/// Pseudo-random numbers in `[0, 1)` (xorshift seeded from the clock); good enough for jitter
+fn random_source() -> impl FnMut() -> f64 {
+    let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0);
+    let mut state = seed | 1;
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Move the mouse to `to` along a curved path from its last known position, or from a random
+/// point a few hundred pixels away if it isn't known
+pub(crate) fn move_mouse_humanlike(session: &BrowserSession, tab: &Tab, to: (f64, f64)) -> Result<()> {
+    let mut random = random_source();
+    let from = session.mouse_position_in(tab).unwrap_or_else(|| {
+        let angle = random() * std::f64::consts::TAU;
+        let distance = 150.0 + random() * 250.0;
+        ((to.0 + angle.cos() * distance).max(0.0), (to.1 + angle.sin() * distance).max(0.0))
+    });
+
+    for (x, y) in mouse_path(from, to, PATH_STEPS, random) {
+        dispatch_mouse_move(session, tab, (x, y))?;
+        thread::sleep(STEP_DELAY);
+    }
+    Ok(())
+}
+
+/// Move the mouse straight to `position` and remember it as the tab's mouse position
+pub(crate) fn dispatch_mouse_move(session: &BrowserSession, tab: &Tab, position: (f64, f64)) -> Result<()> {
+    tab.call_method(mouse_event(DispatchMouseEventTypeOption::MouseMoved, position.0, position.1, None))
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to move mouse: {}", e)))?;
+    session.set_mouse_position_in(tab, position);
+    Ok(())
+}
+
+/// Scroll `element` into view and move the mouse to its center along a human-like path
+pub(crate) fn move_mouse_to_element(session: &BrowserSession, tab: &Tab, element: &Element) -> Result<()> {
+    let midpoint = element
+        .scroll_into_view()
+        .and_then(|element| element.get_midpoint())
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to locate element: {}", e)))?;
+    move_mouse_humanlike(session, tab, (midpoint.x, midpoint.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mouse_path_ends_at_target() {
+        let path = mouse_path((0.0, 0.0), (300.0, 200.0), 20, random_source());
+        assert_eq!(path.len(), 20);
+        assert_eq!(path.last(), Some(&(300.0, 200.0)));
+
+        // Points move steadily towards the target rather than jumping around
+        let distance = |(x, y): (f64, f64)| ((300.0 - x).powi(2) + (200.0 - y).powi(2)).sqrt();
+        assert!(distance(path[0]) > distance(path[10]));
+        assert!(distance(path[10]) > distance(path[18]));
+    }
+
+    #[test]
+    fn test_mouse_path_is_curved() {
+        // Without bend or jitter (random always 0.5) the path is the straight line
+        let straight = mouse_path((0.0, 0.0), (100.0, 0.0), 10, || 0.5);
+        assert!(straight.iter().all(|&(_, y)| y.abs() < 1e-9));
+
+        // Maximum bend pushes the middle of the path off the line
+        let curved = mouse_path((0.0, 0.0), (100.0, 0.0), 10, || 0.999);
+        assert!(curved[4].1.abs() > 10.0);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::protocol::cdp::Page::{AddScriptToEvaluateOnNewDocument, RemoveScriptToEvaluateOnNewDocument};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Parameters for the inject_css tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InjectCssParams {
+    /// CSS to add to the page, e.g. `#cookie-banner { display: none !important; }`
+    pub css: String,
+
+    /// Also inject the style into every page the tab navigates to later (default: false)
+    #[serde(default)]
+    pub persist: bool,
+}
+
+/// Parameters for the remove_css tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RemoveCssParams {
+    /// ID returned by inject_css
+    pub id: String,
+}
+
+/// Number of styles injected so far, for unique style IDs
+static NEXT_STYLE: AtomicUsize = AtomicUsize::new(1);
+
+/// Add or replace the `<style>` with ID __ID__. New documents have no `documentElement` yet when
+/// persistent scripts run, so the style is added as soon as one appears.
+const INJECT_CSS_JS: &str = r#"(() => {
+    const id = __ID__;
+    const css = __CSS__;
+    const apply = () => {
+        let style = document.getElementById(id);
+        if (!style) {
+            style = document.createElement('style');
+            style.id = id;
+            (document.head || document.documentElement).appendChild(style);
+        }
+        style.textContent = css;
+    };
+    if (document.documentElement) {
+        apply();
+    } else {
+        new MutationObserver((_, observer) => {
+            if (document.documentElement) {
+                observer.disconnect();
+                apply();
+            }
+        }).observe(document, { childList: true });
+    }
+})()"#;
+
+/// Remove the `<style>` with ID __ID__; true if there was one
+const REMOVE_CSS_JS: &str = r#"(() => {
+    const style = document.getElementById(__ID__);
+    if (style) style.remove();
+    return !!style;
+})()"#;
+
+/// Script that injects `css` as the `<style>` with ID `id`
+fn inject_script(id: &str, css: &str) -> String {
+    INJECT_CSS_JS
+        .replace("__ID__", &serde_json::json!(id).to_string())
+        .replace("__CSS__", &serde_json::json!(css).to_string())
+}
+
+/// Tool for adding CSS to the page, e.g. to hide overlays before a screenshot
+#[derive(Default)]
+pub struct InjectCssTool;
+
+impl Tool for InjectCssTool {
+    type Params = InjectCssParams;
+
+    fn name(&self) -> &str {
+        "inject_css"
+    }
+
+    fn description(&self) -> &str {
+        "Add a style sheet to the page, optionally re-adding it after every navigation; returns an ID for remove_css"
+    }
+
+    fn execute_typed(&self, params: InjectCssParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let id = format!("browser-use-css-{}", NEXT_STYLE.fetch_add(1, Ordering::Relaxed));
+        let script = inject_script(&id, &params.css);
+
+        if params.persist {
+            // Runs in the current document right away and in every new one before page scripts
+            let registered = tab
+                .call_method(AddScriptToEvaluateOnNewDocument {
+                    source: script,
+                    world_name: None,
+                    include_command_line_api: None,
+                    run_immediately: Some(true),
+                })
+                .map_err(|e| BrowserError::ChromeError(format!("Failed to register style script: {}", e)))?;
+            context.session.add_style_script(&id, registered.identifier);
+        } else {
+            tab.evaluate(&script, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "id": id,
+            "persist": params.persist,
+        })))
+    }
+}
+
+/// Tool for removing CSS added by inject_css
+#[derive(Default)]
+pub struct RemoveCssTool;
+
+impl Tool for RemoveCssTool {
+    type Params = RemoveCssParams;
+
+    fn name(&self) -> &str {
+        "remove_css"
+    }
+
+    fn description(&self) -> &str {
+        "Remove a style sheet added by inject_css from the page and stop re-adding it after navigation"
+    }
+
+    fn execute_typed(&self, params: RemoveCssParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+
+        let script = context.session.take_style_script(&params.id);
+        if let Some(identifier) = &script {
+            tab.call_method(RemoveScriptToEvaluateOnNewDocument { identifier: identifier.clone() })
+                .map_err(|e| BrowserError::ChromeError(format!("Failed to unregister style script: {}", e)))?;
+        }
+
+        let remove_js = REMOVE_CSS_JS.replace("__ID__", &serde_json::json!(params.id).to_string());
+        let result = tab.evaluate(&remove_js, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+        let removed = result.value == Some(serde_json::Value::Bool(true));
+
+        if !removed && script.is_none() {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "remove_css".to_string(),
+                reason: format!("No style with ID '{}' was injected into this page", params.id),
+            });
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "id": params.id,
+            "removed": removed,
+            "persisted": script.is_some(),
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject_script_escapes_css() {
+        let script = inject_script("browser-use-css-1", "a::after { content: \"</style>\\n\"; }");
+        assert!(script.contains(r#"const id = "browser-use-css-1";"#));
+        assert!(script.contains(r#"const css = "a::after { content: \"</style>\\n\"; }";"#));
+    }
+}
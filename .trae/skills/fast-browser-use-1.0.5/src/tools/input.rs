@@ -1,19 +1,24 @@
 use crate::{error::{BrowserError, Result},
             tools::{Tool, ToolContext, ToolResult,
-                    snapshot::{RenderMode, render_aria_tree}}};
+                    snapshot::{RenderMode, render_aria_tree},
+                    utils::{with_ref_retry, with_retry}}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct InputParams {
-    /// CSS selector (use either this or index, not both)
+    /// CSS selector (use exactly one of selector, index or ref)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub selector: Option<String>,
 
-    /// Element index from DOM tree (use either this or selector, not both)
+    /// Element index from DOM tree (use exactly one of selector, index or ref)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<usize>,
 
+    /// Stable element ref from DOM tree; unlike index it stays valid after the page changes
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub element_ref: Option<String>,
+
     /// Text to type into the element
     pub text: String,
 
@@ -32,40 +37,40 @@ impl Tool for InputTool {
         "input"
     }
 
+    fn description(&self) -> &str {
+        "Type text into an input element specified by CSS selector, index or ref"
+    }
+
     fn execute_typed(&self, params: InputParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Validate that exactly one selector method is provided
-        match (&params.selector, &params.index) {
-            (Some(_), Some(_)) => {
+        match (&params.selector, &params.index, &params.element_ref) {
+            (None, None, None) => {
                 return Err(BrowserError::ToolExecutionFailed {
                     tool: "input".to_string(),
-                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                    reason: "Must specify one of 'selector', 'index' or 'ref'.".to_string(),
                 });
             }
-            (None, None) => {
+            (Some(_), None, None) | (None, Some(_), None) | (None, None, Some(_)) => {}
+            _ => {
                 return Err(BrowserError::ToolExecutionFailed {
                     tool: "input".to_string(),
-                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                    reason: "Cannot specify more than one of 'selector', 'index' and 'ref'. Use only one.".to_string(),
                 });
             }
-            _ => {}
         }
 
-        // Get the CSS selector (either directly or from index)
-        let css_selector = if let Some(selector) = params.selector.clone() {
-            selector
+        // Find the element (either directly or from index)
+        let tab = context.tab()?;
+        let element = if let Some(selector) = params.selector.as_deref() {
+            context.session.find_element(&tab, selector)?
         } else if let Some(index) = params.index {
-            let dom = context.get_dom()?;
-            let selector = dom
-                .get_selector(index)
-                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
-            selector.clone()
+            with_retry(context, index, |context, selector| context.session.find_element(&tab, selector))?
+        } else if let Some(element_ref) = params.element_ref.as_deref() {
+            with_ref_retry(context, element_ref, |context, selector| context.session.find_element(&tab, selector))?
         } else {
             unreachable!("Validation above ensures one field is Some")
         };
 
-        let tab = context.session.tab()?;
-        let element = context.session.find_element(&tab, &css_selector)?;
-
         if params.clear {
             element.click().ok(); // Focus
             // Clear with Ctrl+A and Delete
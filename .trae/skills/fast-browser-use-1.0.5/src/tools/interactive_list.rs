@@ -0,0 +1,106 @@
+use crate::{dom::{DomTree, element::Rect},
+            error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the interactive_list tool (no parameters needed)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InteractiveListParams {}
+
+/// One actionable element of the page
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InteractiveElement {
+    /// Element index, usable wherever tools accept `index`
+    pub index: usize,
+
+    /// ARIA role
+    pub role: String,
+
+    /// Accessible name
+    pub name: String,
+
+    /// CSS selector the index resolves to
+    pub selector: Option<String>,
+
+    /// Bounding box in viewport coordinates
+    pub rect: Option<Rect>,
+}
+
+/// Tool for listing the interactive elements of the page as a flat array
+#[derive(Default)]
+pub struct InteractiveListTool;
+
+impl Tool for InteractiveListTool {
+    type Params = InteractiveListParams;
+
+    fn name(&self) -> &str {
+        "interactive_list"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "List the visible interactive elements of the current page with index, role, name, selector and rect"
+    }
+
+    fn execute_typed(&self, _params: InteractiveListParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let dom = context.get_dom()?;
+        let elements = interactive_elements(dom);
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "count": elements.len(),
+            "elements": elements,
+        })))
+    }
+}
+
+/// Collect every indexed node with a visible box, in index order
+fn interactive_elements(dom: &DomTree) -> Vec<InteractiveElement> {
+    dom.interactive_indices()
+        .into_iter()
+        .filter_map(|index| dom.find_node_by_index(index).map(|node| (index, node)))
+        .filter(|(_, node)| node.box_info.visible)
+        .map(|(index, node)| InteractiveElement {
+            index,
+            role: node.role.clone(),
+            name: node.name.clone(),
+            selector: dom.get_selector(index).cloned(),
+            rect: node.box_info.rect.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::{AriaChild, AriaNode};
+
+    #[test]
+    fn test_interactive_elements() {
+        let mut root = AriaNode::fragment();
+        let mut button = AriaNode::new("button", "Submit").with_index(1).with_box(true, Some("pointer".to_string()));
+        button.box_info.rect = Some(Rect { x: 10.0, y: 20.0, width: 80.0, height: 30.0 });
+        root.children.push(AriaChild::Node(Box::new(
+            AriaNode::new("generic", "").with_child(AriaChild::Node(Box::new(button))),
+        )));
+        root.children.push(AriaChild::Node(Box::new(AriaNode::new("link", "Home").with_index(0).with_box(true, None))));
+        root.children
+            .push(AriaChild::Node(Box::new(AriaNode::new("button", "Hidden").with_index(2).with_box(false, None))));
+
+        let mut dom = DomTree::new(root);
+        dom.selectors = vec!["a.home".to_string(), "#submit".to_string(), "#hidden".to_string()];
+
+        let elements = interactive_elements(&dom);
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[0].index, 0);
+        assert_eq!(elements[0].role, "link");
+        assert_eq!(elements[0].selector.as_deref(), Some("a.home"));
+        assert_eq!(elements[0].rect, None);
+        assert_eq!(elements[1].name, "Submit");
+        assert_eq!(elements[1].selector.as_deref(), Some("#submit"));
+        assert_eq!(elements[1].rect, Some(Rect { x: 10.0, y: 20.0, width: 80.0, height: 30.0 }));
+    }
+}
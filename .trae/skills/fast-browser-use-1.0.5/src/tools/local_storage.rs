@@ -1,4 +1,4 @@
-use crate::{error::{BrowserError, Result}, tools::{Tool, ToolContext, ToolResult}};
+use crate::{error::{BrowserError, Result}, tools::{Tool, ToolContext, ToolResult, utils::js_string}};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -33,11 +33,19 @@ impl Tool for GetLocalStorageTool {
         "get_local_storage"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get localStorage items of the current page"
+    }
+
         fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
 
             let (script, is_json) = if let Some(key) = &params.key {
 
-                (format!(r#"window.localStorage.getItem("{}")"#, key.replace("\"", "\\\"")), false)
+                (format!("window.localStorage.getItem({})", js_string(key)), false)
 
             } else {
 
@@ -65,7 +73,7 @@ impl Tool for GetLocalStorageTool {
 
     
 
-            let remote_object = context.session.tab()?.evaluate(&script, false)
+            let remote_object = context.tab()?.evaluate(&script, false)
 
                 .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to get local storage: {}", e)))?;
 
@@ -75,9 +83,9 @@ impl Tool for GetLocalStorageTool {
 
     
 
-            if is_json {
-
-                 if let Some(json_str) = value.as_str() {
+            if is_json
+                 && let Some(json_str) = value.as_str()
+            {
 
                      let parsed: Value = serde_json::from_str(json_str)
 
@@ -87,8 +95,6 @@ impl Tool for GetLocalStorageTool {
 
                  }
 
-            }
-
             
 
             Ok(ToolResult::success_with(value))
@@ -96,6 +102,11 @@ impl Tool for GetLocalStorageTool {
         }
 }
 
+/// Build the `setItem` call for a key/value pair, encoding both as JS string literals
+fn set_item_script(key: &str, value: &str) -> String {
+    format!("window.localStorage.setItem({}, {})", js_string(key), js_string(value))
+}
+
 #[derive(Default)]
 pub struct SetLocalStorageTool;
 
@@ -106,14 +117,14 @@ impl Tool for SetLocalStorageTool {
         "set_local_storage"
     }
 
+    fn description(&self) -> &str {
+        "Set a localStorage item on the current page"
+    }
+
     fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
-        let script = format!(
-            r#"window.localStorage.setItem("{}", "{}")"#,
-            params.key.replace("\"", "\\\""),
-            params.value.replace("\"", "\\\"")
-        );
+        let script = set_item_script(&params.key, &params.value);
 
-        context.session.tab()?.evaluate(&script, false)
+        context.tab()?.evaluate(&script, false)
             .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to set local storage: {}", e)))?;
 
         Ok(ToolResult::success(None))
@@ -130,10 +141,14 @@ impl Tool for RemoveLocalStorageTool {
         "remove_local_storage"
     }
 
+    fn description(&self) -> &str {
+        "Remove a localStorage item from the current page"
+    }
+
     fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
-        let script = format!(r#"window.localStorage.removeItem("{}")"#, params.key.replace("\"", "\\\""));
+        let script = format!("window.localStorage.removeItem({})", js_string(&params.key));
 
-        context.session.tab()?.evaluate(&script, false)
+        context.tab()?.evaluate(&script, false)
             .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to remove local storage item: {}", e)))?;
 
         Ok(ToolResult::success(None))
@@ -150,10 +165,47 @@ impl Tool for ClearLocalStorageTool {
         "clear_local_storage"
     }
 
+    fn description(&self) -> &str {
+        "Clear all localStorage items of the current page"
+    }
+
     fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
-        context.session.tab()?.evaluate("window.localStorage.clear()", false)
+        context.tab()?.evaluate("window.localStorage.clear()", false)
             .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to clear local storage: {}", e)))?;
 
         Ok(ToolResult::success(None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse the two string literals back out of a generated setItem call
+    fn parse_set_item_args(script: &str) -> (String, String) {
+        let args = script.strip_prefix("window.localStorage.setItem(").unwrap().strip_suffix(')').unwrap();
+        let parsed: Vec<String> = serde_json::from_str(&format!("[{}]", args)).unwrap();
+        (parsed[0].clone(), parsed[1].clone())
+    }
+
+    #[test]
+    fn test_set_item_script_escapes_quotes() {
+        let script = set_item_script(r#"say "hi""#, r#"{"nested": "json"}"#);
+        assert_eq!(parse_set_item_args(&script), (r#"say "hi""#.to_string(), r#"{"nested": "json"}"#.to_string()));
+    }
+
+    #[test]
+    fn test_set_item_script_escapes_backslashes_and_newlines() {
+        let value = "C:\\path\\to\nline two\\\"";
+        let script = set_item_script("key", value);
+        assert!(!script.contains('\n'), "Raw newlines must not reach the evaluated code");
+        assert_eq!(parse_set_item_args(&script).1, value);
+    }
+
+    #[test]
+    fn test_set_item_script_html_payload() {
+        let value = "</script><script>alert('x')</script>";
+        let script = set_item_script("k'ey", value);
+        assert_eq!(parse_set_item_args(&script), ("k'ey".to_string(), value.to_string()));
+    }
+}
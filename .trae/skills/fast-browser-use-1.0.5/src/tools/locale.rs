@@ -0,0 +1,33 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the set_locale tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SetLocaleParams {
+    /// BCP 47 locale such as "de-DE" or "ja-JP"
+    pub locale: String,
+}
+
+/// Tool for emulating a browser locale
+#[derive(Default)]
+pub struct SetLocaleTool;
+
+impl Tool for SetLocaleTool {
+    type Params = SetLocaleParams;
+
+    fn name(&self) -> &str {
+        "set_locale"
+    }
+
+    fn description(&self) -> &str {
+        "Emulate a locale (Accept-Language header and Intl formatting); reload the page if it was already loaded"
+    }
+
+    fn execute_typed(&self, params: SetLocaleParams, context: &mut ToolContext) -> Result<ToolResult> {
+        context.session.set_locale(&params.locale)?;
+
+        Ok(ToolResult::success_with(serde_json::json!({ "locale": params.locale })))
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult, html_to_markdown::convert_html_to_markdown,
-                    readability_script::READABILITY_SCRIPT}};
+            tools::{Tool, ToolContext, ToolResult,
+                    html_to_markdown::convert_html_to_markdown,
+                    readability_script::READABILITY_SCRIPT,
+                    scroll::{ScrollParams, ScrollTool}}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +16,11 @@ pub struct GetMarkdownParams {
     /// Maximum characters per page (default: 100000)
     #[serde(default = "default_page_size")]
     pub page_size: usize,
+
+    /// Scroll through the whole page first so lazy-loaded images and content load (default: false).
+    /// Adds roughly 100 ms per viewport height of page, plus the wait for the content to arrive.
+    #[serde(default)]
+    pub trigger_lazy: bool,
 }
 
 fn default_page() -> usize {
@@ -26,8 +33,35 @@ fn default_page_size() -> usize {
 
 impl Default for GetMarkdownParams {
     fn default() -> Self {
-        Self { page: default_page(), page_size: default_page_size() }
+        Self { page: default_page(), page_size: default_page_size(), trigger_lazy: false }
+    }
+}
+
+/// Maximum time to wait for the page to settle before extracting content
+const NETWORK_IDLE_TIMEOUT_MS: u64 = 3000;
+
+/// Quiet period without new resource requests that counts as idle
+const NETWORK_IDLE_MS: u64 = 500;
+
+/// Upper bound on viewport-sized scroll steps when triggering lazy content, so infinite feeds end
+const LAZY_SCROLL_MAX_STEPS: usize = 50;
+
+/// Scroll down the page a viewport at a time so `loading="lazy"` images and scroll-triggered
+/// content enter the viewport and load, then return to the top
+fn trigger_lazy_content(context: &mut ToolContext) -> Result<()> {
+    for _ in 0..LAZY_SCROLL_MAX_STEPS {
+        let result = ScrollTool.execute_typed(ScrollParams { amount: None, target: None }, context)?;
+        let data = result.data.unwrap_or_default();
+        if data["isAtBottom"].as_bool().unwrap_or(true) || data["scrolled"].as_i64() == Some(0) {
+            break;
+        }
     }
+
+    context
+        .tab()?
+        .evaluate("window.scrollTo(0, 0)", false)
+        .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+    Ok(())
 }
 
 #[derive(Default)]
@@ -40,27 +74,37 @@ impl Tool for GetMarkdownTool {
         "get_markdown"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the markdown content of the current page"
+    }
+
     fn execute_typed(&self, params: GetMarkdownParams, context: &mut ToolContext) -> Result<ToolResult> {
-        // Wait for network idle with a timeout
-        // Since headless_chrome doesn't have a direct network idle wait,
-        // we add a small delay to let dynamic content load
-        std::thread::sleep(std::time::Duration::from_millis(1000));
+        if params.trigger_lazy {
+            trigger_lazy_content(context)?;
+        }
+
+        let tab = context.tab()?;
+
+        // Wait for network idle with a timeout to let dynamic content load.
+        // Extraction still proceeds on a busy page; it just gets what has rendered so far.
+        if let Err(e) = context.session.wait_for_network_idle_in(&tab, NETWORK_IDLE_TIMEOUT_MS, NETWORK_IDLE_MS) {
+            log::debug!("Page did not become network idle before extracting markdown: {}", e);
+        }
 
         // Inject Readability.js script and the conversion script
-        // Use 'var' instead of 'const' to allow redeclaration on subsequent calls
-        // This prevents "identifier already declared" errors when calling get_markdown multiple times
         let js_code = format!(
-            "var READABILITY_SCRIPT = {};\n{}",
+            "const READABILITY_SCRIPT = {};\n{}",
             serde_json::to_string(READABILITY_SCRIPT).unwrap(),
             include_str!("convert_to_markdown.js")
         );
 
-        // Execute the JavaScript to extract and convert content
-        let result = context
-            .session
-            .tab()?
-            .evaluate(&js_code, false)
-            .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+        // Execute the JavaScript to extract and convert content. The isolated world keeps the
+        // declarations above away from the page's globals and from earlier calls.
+        let result = context.session.evaluate_isolated_in(&tab, &js_code)?;
 
         // Parse the result
         let result_value = result.value.ok_or_else(|| {
@@ -102,8 +146,7 @@ impl Tool for GetMarkdownTool {
         let full_markdown = convert_html_to_markdown(&extraction_result.content);
 
         // Calculate pagination information
-        let total_pages =
-            if full_markdown.is_empty() { 1 } else { (full_markdown.len() + params.page_size - 1) / params.page_size };
+        let total_pages = if full_markdown.is_empty() { 1 } else { full_markdown.len().div_ceil(params.page_size) };
 
         // Clamp page number to valid range
         let current_page = params.page.clamp(1, total_pages.max(1));
@@ -0,0 +1,53 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the mhtml tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MhtmlParams {
+    /// Path to save the .mhtml archive (default: return the archive in the result)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Tool for archiving the current page as a single self-contained MHTML file
+#[derive(Default)]
+pub struct MhtmlTool;
+
+impl Tool for MhtmlTool {
+    type Params = MhtmlParams;
+
+    fn name(&self) -> &str {
+        "mhtml"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Archive the current page as MHTML, a single file with CSS and images inlined"
+    }
+
+    fn execute_typed(&self, params: MhtmlParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let mhtml = context.session.capture_mhtml_in(&context.tab()?)?;
+
+        let Some(path) = params.path else {
+            return Ok(ToolResult::success_with(serde_json::json!({
+                "size_bytes": mhtml.len(),
+                "mhtml": mhtml,
+            })));
+        };
+
+        std::fs::write(&path, &mhtml).map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "mhtml".to_string(),
+            reason: format!("Failed to save archive: {}", e),
+        })?;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "path": path,
+            "size_bytes": mhtml.len(),
+        })))
+    }
+}
@@ -3,75 +3,146 @@
 //! This module provides a framework for browser automation tools and
 //! includes implementations of common browser operations.
 
+pub mod accessibility;
+pub mod batch;
+pub mod check;
 pub mod click;
+pub mod click_at;
+pub mod clipboard;
 pub mod close;
 pub mod close_tab;
+pub mod context;
 pub mod cookies;
 pub mod debug;
+pub mod describe_element;
+pub mod dialog;
+pub mod dismiss_banners;
 pub mod evaluate;
 pub mod extract;
 pub mod go_back;
 pub mod go_forward;
+pub mod health;
+pub mod highlight;
 pub mod hover;
+pub mod hover_capture;
+pub mod html_source;
 pub mod html_to_markdown;
+mod human_mouse;
+pub mod inject_css;
 pub mod input;
+pub mod interactive_list;
 pub mod local_storage;
+pub mod locale;
 pub mod markdown;
+pub mod mhtml;
+pub mod mouse_move;
 pub mod navigate;
 pub mod new_tab;
+pub mod open_in_new_tab;
+pub mod permissions;
+pub mod popup;
 pub mod press_key;
 pub mod read_links;
 pub mod readability_script;
+pub mod responses;
 pub mod screenshot;
 pub mod scroll;
 pub mod select;
+pub mod session_storage;
 pub mod sitemap;
 pub mod snapshot;
+pub mod submit;
 pub mod switch_tab;
 pub mod tab_list;
+pub mod timezone;
+pub mod type_text;
 pub mod annotate;
 mod utils;
+pub mod visual_diff;
 pub mod wait;
+pub mod wait_and_read;
 
 // Re-export Params types for use by MCP layer
+pub use accessibility::CdpAccessibilityTreeParams;
+pub use batch::BatchParams;
+pub use check::CheckParams;
 pub use click::ClickParams;
+pub use click_at::ClickAtParams;
+pub use clipboard::{ReadClipboardParams, WriteClipboardParams};
 pub use close::CloseParams;
 pub use close_tab::CloseTabParams;
-pub use cookies::{GetCookiesParams, SetCookiesParams};
-pub use debug::{GetConsoleLogsParams, GetNetworkErrorsParams};
+pub use context::PageContextParams;
+pub use cookies::{ClearCookiesParams, GetCookiesParams, SetCookiesParams};
+pub use debug::{
+    ClearConsoleLogsParams, ClearNetworkErrorsParams, GetConsoleLogsParams, GetJsExceptionsParams, GetNetworkErrorsParams,
+};
+pub use describe_element::DescribeElementParams;
+pub use dialog::HandleDialogParams;
+pub use dismiss_banners::DismissBannersParams;
 pub use evaluate::EvaluateParams;
 pub use extract::ExtractParams;
 pub use go_back::GoBackParams;
 pub use go_forward::GoForwardParams;
+pub use health::HealthParams;
+pub use highlight::HighlightParams;
 pub use hover::HoverParams;
+pub use hover_capture::HoverCaptureParams;
+pub use html_source::HtmlSourceParams;
+pub use inject_css::{InjectCssParams, RemoveCssParams};
 pub use input::InputParams;
+pub use interactive_list::InteractiveListParams;
 pub use local_storage::{
     ClearLocalStorageParams, GetLocalStorageParams, RemoveLocalStorageParams, SetLocalStorageParams,
 };
+pub use locale::SetLocaleParams;
 pub use markdown::GetMarkdownParams;
+pub use mhtml::MhtmlParams;
+pub use mouse_move::MouseMoveParams;
 pub use navigate::NavigateParams;
 pub use new_tab::NewTabParams;
+pub use open_in_new_tab::OpenInNewTabParams;
+pub use permissions::{GrantPermissionsParams, ResetPermissionsParams};
+pub use popup::WaitForPopupParams;
 pub use press_key::PressKeyParams;
 pub use read_links::ReadLinksParams;
+pub use responses::{CaptureResponsesParams, GetCapturedResponsesParams};
 pub use screenshot::ScreenshotParams;
 pub use scroll::ScrollParams;
 pub use select::SelectParams;
+pub use session_storage::{
+    ClearSessionStorageParams, GetSessionStorageParams, RemoveSessionStorageParams, SetSessionStorageParams,
+};
 pub use sitemap::{SitemapParams, SitemapResult, PageStructure, Heading, NavLink, Section, MainContent, Meta};
 pub use snapshot::SnapshotParams;
+pub use submit::SubmitFormParams;
 pub use switch_tab::SwitchTabParams;
 pub use tab_list::TabListParams;
+pub use timezone::SetTimezoneParams;
+pub use type_text::TypeTextParams;
 pub use annotate::AnnotateParams;
+pub use visual_diff::VisualDiffParams;
 pub use wait::WaitParams;
+pub use wait_and_read::WaitAndReadParams;
+
+pub use utils::normalize_url;
 
-use crate::{browser::BrowserSession, dom::DomTree, error::Result};
+use crate::{browser::BrowserSession,
+            dom::DomTree,
+            error::{BrowserError, Result}};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::{Tab,
+                      protocol::cdp::{Page::CaptureScreenshotFormatOption, Runtime::TerminateExecution}};
 use serde_json::Value;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::{Arc, mpsc::{self, RecvTimeoutError}}, thread, time::{Duration, Instant}};
 
 /// Tool execution context
 pub struct ToolContext<'a> {
     /// Browser session
     pub session: &'a BrowserSession,
 
+    /// Tab the tools act on; `None` means the session's active tab
+    pub tab: Option<Arc<Tab>>,
+
     /// Optional DOM tree (extracted on demand)
     pub dom_tree: Option<DomTree>,
 }
@@ -79,18 +150,32 @@ pub struct ToolContext<'a> {
 impl<'a> ToolContext<'a> {
     /// Create a new tool context
     pub fn new(session: &'a BrowserSession) -> Self {
-        Self { session, dom_tree: None }
+        Self { session, tab: None, dom_tree: None }
     }
 
     /// Create a context with a pre-extracted DOM tree
     pub fn with_dom(session: &'a BrowserSession, dom_tree: DomTree) -> Self {
-        Self { session, dom_tree: Some(dom_tree) }
+        Self { session, tab: None, dom_tree: Some(dom_tree) }
+    }
+
+    /// Create a context whose tools act on `tab` rather than the active tab, so several
+    /// tabs can be driven in parallel (one context per thread)
+    pub fn for_tab(session: &'a BrowserSession, tab: Arc<Tab>) -> Self {
+        Self { session, tab: Some(tab), dom_tree: None }
+    }
+
+    /// Get the tab to act on: the explicit tab if set, otherwise the active tab
+    pub fn tab(&self) -> Result<Arc<Tab>> {
+        match &self.tab {
+            Some(tab) => Ok(tab.clone()),
+            None => self.session.tab(),
+        }
     }
 
     /// Get or extract the DOM tree
     pub fn get_dom(&mut self) -> Result<&DomTree> {
         if self.dom_tree.is_none() {
-            self.dom_tree = Some(self.session.extract_dom()?);
+            self.dom_tree = Some(DomTree::from_tab_with_strategy(&self.tab()?, "", self.session.selector_strategy())?);
         }
         Ok(self.dom_tree.as_ref().unwrap())
     }
@@ -110,6 +195,10 @@ pub struct ToolResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 
+    /// Machine-readable error code if execution failed (see [`BrowserError::code`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+
     /// Additional metadata
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, Value>,
@@ -118,17 +207,34 @@ pub struct ToolResult {
 impl ToolResult {
     /// Create a successful result
     pub fn success(data: Option<Value>) -> Self {
-        Self { success: true, data, error: None, metadata: HashMap::new() }
+        Self { success: true, data, error: None, error_code: None, metadata: HashMap::new() }
     }
 
     /// Create a successful result with data
     pub fn success_with<T: serde::Serialize>(data: T) -> Self {
-        Self { success: true, data: serde_json::to_value(data).ok(), error: None, metadata: HashMap::new() }
+        Self {
+            success: true,
+            data: serde_json::to_value(data).ok(),
+            error: None,
+            error_code: None,
+            metadata: HashMap::new(),
+        }
     }
 
     /// Create a failure result
     pub fn failure(error: impl Into<String>) -> Self {
-        Self { success: false, data: None, error: Some(error.into()), metadata: HashMap::new() }
+        Self { success: false, data: None, error: Some(error.into()), error_code: None, metadata: HashMap::new() }
+    }
+
+    /// Create a failure result from an error, carrying its error code
+    pub fn from_error(error: &BrowserError) -> Self {
+        Self::failure(error.to_string()).with_error_code(error.code())
+    }
+
+    /// Set the machine-readable error code
+    pub fn with_error_code(mut self, code: impl Into<String>) -> Self {
+        self.error_code = Some(code.into());
+        self
     }
 
     /// Add metadata to the result
@@ -146,27 +252,58 @@ pub trait Tool: Send + Sync + Default {
     /// Get tool name
     fn name(&self) -> &str;
 
+    /// Get a human-readable description of what the tool does
+    fn description(&self) -> &str {
+        ""
+    }
+
     /// Get tool parameter schema (JSON Schema)
     fn parameters_schema(&self) -> Value {
         serde_json::to_value(schemars::schema_for!(Self::Params)).unwrap_or_default()
     }
 
+    /// Whether the tool changes the page or browser state (clicks, navigation, scripts...).
+    /// Mutating tools are refused when the session is read-only.
+    fn is_mutating(&self) -> bool {
+        true
+    }
+
     /// Execute the tool with strongly-typed parameters
     fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult>;
 
+    /// Execute the tool with strongly-typed parameters, unless it is mutating and the session is
+    /// read-only
+    fn execute_checked(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        if self.is_mutating() && context.session.is_read_only() {
+            return Err(BrowserError::InvalidArgument(format!(
+                "Tool '{}' is not available in read-only mode",
+                self.name()
+            )));
+        }
+        self.execute_typed(params, context)
+    }
+
     /// Execute the tool with JSON parameters (default implementation)
     fn execute(&self, params: Value, context: &mut ToolContext) -> Result<ToolResult> {
-        let typed_params: Self::Params = serde_json::from_value(params)
-            .map_err(|e| crate::error::BrowserError::InvalidArgument(format!("Invalid parameters: {}", e)))?;
-        self.execute_typed(typed_params, context)
+        let typed_params: Self::Params = parse_params(params)?;
+        self.execute_checked(typed_params, context)
     }
 }
 
+/// Deserialize JSON parameters into a tool's typed parameters
+fn parse_params<P: for<'de> serde::Deserialize<'de>>(params: Value) -> Result<P> {
+    serde_json::from_value(params).map_err(|e| BrowserError::InvalidArgument(format!("Invalid parameters: {}", e)))
+}
+
 /// Type-erased tool trait for dynamic dispatch
 pub trait DynTool: Send + Sync {
     fn name(&self) -> &str;
+    fn description(&self) -> &str;
     fn parameters_schema(&self) -> Value;
+    /// Execute the tool with JSON parameters, recording its wall-clock time as `duration_ms` metadata
     fn execute(&self, params: Value, context: &mut ToolContext) -> Result<ToolResult>;
+    /// Check that `params` deserialize into the tool's parameter type without executing it
+    fn validate(&self, params: &Value) -> Result<()>;
 }
 
 /// Blanket implementation to convert any Tool into DynTool
@@ -175,24 +312,47 @@ impl<T: Tool> DynTool for T {
         Tool::name(self)
     }
 
+    fn description(&self) -> &str {
+        Tool::description(self)
+    }
+
     fn parameters_schema(&self) -> Value {
         Tool::parameters_schema(self)
     }
 
     fn execute(&self, params: Value, context: &mut ToolContext) -> Result<ToolResult> {
-        Tool::execute(self, params, context)
+        let started = Instant::now();
+        Tool::execute(self, params, context).map(|result| record_duration(result, started.elapsed()))
     }
+
+    fn validate(&self, params: &Value) -> Result<()> {
+        parse_params::<T::Params>(params.clone()).map(|_| ())
+    }
+}
+
+/// Name, description and parameter schema of a registered tool, e.g. for function-calling APIs
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ToolDescriptor {
+    pub name: String,
+    pub description: String,
+    pub parameters_schema: Value,
 }
 
+/// Callback run after each tool execution with the tool name, its parameters and the result
+pub type ToolObserver = Box<dyn Fn(&str, &Value, &ToolResult) + Send + Sync>;
+
 /// Tool registry for managing and accessing tools
 pub struct ToolRegistry {
     tools: HashMap<String, Arc<dyn DynTool>>,
+
+    /// Notified of every execution, e.g. for an audit log
+    observer: Option<ToolObserver>,
 }
 
 impl ToolRegistry {
     /// Create a new empty tool registry
     pub fn new() -> Self {
-        Self { tools: HashMap::new() }
+        Self { tools: HashMap::new(), observer: None }
     }
 
     /// Create a registry with default tools
@@ -204,40 +364,75 @@ impl ToolRegistry {
         registry.register(go_back::GoBackTool);
         registry.register(go_forward::GoForwardTool);
         registry.register(wait::WaitTool);
+        registry.register(wait_and_read::WaitAndReadTool);
 
         // Register interaction tools
         registry.register(click::ClickTool);
+        registry.register(click_at::ClickAtTool);
+        registry.register(mouse_move::MouseMoveTool);
         registry.register(input::InputTool);
         registry.register(select::SelectTool);
+        registry.register(check::CheckTool);
+        registry.register(submit::SubmitFormTool);
         registry.register(hover::HoverTool);
+        registry.register(hover_capture::HoverCaptureTool);
+        registry.register(highlight::HighlightTool);
         registry.register(press_key::PressKeyTool);
+        registry.register(type_text::TypeTextTool);
         registry.register(scroll::ScrollTool);
+        registry.register(dismiss_banners::DismissBannersTool);
 
         // Register tab management tools
         registry.register(new_tab::NewTabTool);
+        registry.register(open_in_new_tab::OpenInNewTabTool);
         registry.register(tab_list::TabListTool);
         registry.register(switch_tab::SwitchTabTool);
         registry.register(close_tab::CloseTabTool);
+        registry.register(popup::WaitForPopupTool);
 
         // Register reading and extraction tools
         registry.register(extract::ExtractContentTool);
         registry.register(markdown::GetMarkdownTool);
+        registry.register(html_source::HtmlSourceTool);
+        registry.register(mhtml::MhtmlTool);
         registry.register(read_links::ReadLinksTool);
         registry.register(snapshot::SnapshotTool);
+        registry.register(context::PageContextTool);
+        registry.register(interactive_list::InteractiveListTool);
+        registry.register(describe_element::DescribeElementTool);
+        registry.register(accessibility::CdpAccessibilityTreeTool);
 
         // Register utility tools
         registry.register(screenshot::ScreenshotTool);
         registry.register(annotate::AnnotateTool);
+        registry.register(inject_css::InjectCssTool);
+        registry.register(inject_css::RemoveCssTool);
+        registry.register(visual_diff::VisualDiffTool);
         registry.register(evaluate::EvaluateTool);
         registry.register(close::CloseTool);
+        registry.register(health::HealthTool);
+        registry.register(batch::BatchTool);
+        registry.register(clipboard::ReadClipboardTool);
+        registry.register(clipboard::WriteClipboardTool);
+        registry.register(permissions::GrantPermissionsTool);
+        registry.register(permissions::ResetPermissionsTool);
+        registry.register(locale::SetLocaleTool);
+        registry.register(timezone::SetTimezoneTool);
         
         // Register cookie tools
         registry.register(cookies::GetCookiesTool);
         registry.register(cookies::SetCookiesTool);
+        registry.register(cookies::ClearCookiesTool);
 
         // Register debug tools
         registry.register(debug::GetConsoleLogsTool);
         registry.register(debug::GetNetworkErrorsTool);
+        registry.register(debug::GetJsExceptionsTool);
+        registry.register(debug::ClearConsoleLogsTool);
+        registry.register(debug::ClearNetworkErrorsTool);
+        registry.register(responses::CaptureResponsesTool);
+        registry.register(responses::GetCapturedResponsesTool);
+        registry.register(dialog::HandleDialogTool);
         
         // Register local storage tools
         registry.register(local_storage::GetLocalStorageTool);
@@ -245,6 +440,12 @@ impl ToolRegistry {
         registry.register(local_storage::RemoveLocalStorageTool);
         registry.register(local_storage::ClearLocalStorageTool);
 
+        // Register session storage tools
+        registry.register(session_storage::GetSessionStorageTool);
+        registry.register(session_storage::SetSessionStorageTool);
+        registry.register(session_storage::RemoveSessionStorageTool);
+        registry.register(session_storage::ClearSessionStorageTool);
+
         // Register sitemap tool
         registry.register(sitemap::SitemapTool);
 
@@ -277,12 +478,129 @@ impl ToolRegistry {
         self.tools.values().cloned().collect()
     }
 
-    /// Execute a tool by name
+    /// Describe all registered tools, sorted by name
+    pub fn describe(&self) -> Vec<ToolDescriptor> {
+        let mut descriptors: Vec<ToolDescriptor> = self
+            .tools
+            .values()
+            .map(|tool| ToolDescriptor {
+                name: tool.name().to_string(),
+                description: tool.description().to_string(),
+                parameters_schema: tool.parameters_schema(),
+            })
+            .collect();
+        descriptors.sort_by(|a, b| a.name.cmp(&b.name));
+        descriptors
+    }
+
+    /// Set a callback run after every execution, replacing any previous one
+    pub fn set_observer(&mut self, observer: ToolObserver) {
+        self.observer = Some(observer);
+    }
+
+    /// Whether an observer is set
+    pub fn has_observer(&self) -> bool {
+        self.observer.is_some()
+    }
+
+    /// Report an execution to the observer, passing errors as failed results
+    pub(crate) fn notify(&self, name: &str, params: &Value, result: &Result<ToolResult>) {
+        let Some(observer) = &self.observer else { return };
+        match result {
+            Ok(result) => observer(name, params, result),
+            Err(e) => observer(name, params, &ToolResult::from_error(e)),
+        }
+    }
+
+    /// Execute a tool by name, recording its wall-clock time as `duration_ms` metadata
     pub fn execute(&self, name: &str, params: Value, context: &mut ToolContext) -> Result<ToolResult> {
+        let observed = self.observer.as_ref().map(|_| params.clone());
+        let result = match self.get(name) {
+            Some(tool) => with_error_screenshot(tool.execute(params, context), context),
+            None => Ok(ToolResult::failure(format!("Tool '{}' not found", name)).with_error_code("tool_not_found")),
+        };
+        if let Some(params) = observed {
+            self.notify(name, &params, &result);
+        }
+        result
+    }
+
+    /// Execute a tool by name, failing with [`BrowserError::Timeout`] if it runs longer than `timeout`.
+    ///
+    /// The tool runs on its own thread with a context on the session's active tab, so this returns
+    /// once the deadline passes no matter what the tool is blocked on. JavaScript still running in
+    /// the tab is then terminated, but the underlying CDP call (a navigation, a wait, ...) may keep
+    /// running in the background until it finishes or times out on its own; its result is discarded.
+    pub fn execute_with_timeout(
+        &self,
+        name: &str,
+        params: Value,
+        session: &Arc<BrowserSession>,
+        timeout: Duration,
+    ) -> Result<ToolResult> {
+        let Some(tool) = self.get(name).cloned() else {
+            return self.execute(name, params, &mut ToolContext::new(session));
+        };
+        let observed = self.observer.as_ref().map(|_| params.clone());
+
+        let (sender, receiver) = mpsc::channel();
+        let worker_session = session.clone();
+        thread::spawn(move || {
+            let mut context = ToolContext::new(&worker_session);
+            let _ = sender.send(tool.execute(params, &mut context));
+        });
+
+        let result = match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                if let Ok(tab) = session.tab() {
+                    let _ = tab.call_method(TerminateExecution(None));
+                }
+                Err(BrowserError::Timeout(format!(
+                    "Tool '{}' did not finish within {} ms",
+                    name,
+                    timeout.as_millis()
+                )))
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(BrowserError::ToolExecutionFailed { tool: name.to_string(), reason: "Tool panicked".into() })
+            }
+        };
+        let result = with_error_screenshot(result, &ToolContext::new(session));
+        if let Some(params) = observed {
+            self.notify(name, &params, &result);
+        }
+        result
+    }
+
+    /// Validate parameters for a tool by name without executing it
+    pub fn validate(&self, name: &str, params: &Value) -> Result<()> {
         match self.get(name) {
-            Some(tool) => tool.execute(params, context),
-            None => Ok(ToolResult::failure(format!("Tool '{}' not found", name))),
+            Some(tool) => tool.validate(params),
+            None => Err(BrowserError::InvalidArgument(format!("Tool '{}' not found", name))),
+        }
+    }
+
+    /// Execute several tools in order, sharing one context (and its DOM cache) between them.
+    ///
+    /// Errors are reported as failed results. With `stop_on_error`, no further actions run
+    /// after the first failure, so the returned list may be shorter than `actions`.
+    pub fn execute_batch(
+        &self,
+        actions: Vec<(String, Value)>,
+        context: &mut ToolContext,
+        stop_on_error: bool,
+    ) -> Vec<ToolResult> {
+        let mut results = Vec::with_capacity(actions.len());
+        for (name, params) in actions {
+            let result = self.execute(&name, params, context).unwrap_or_else(|e| ToolResult::from_error(&e));
+            let failed = !result.success;
+            results.push(result);
+            if failed && stop_on_error {
+                break;
+            }
         }
+        results
     }
 
     /// Get the number of registered tools
@@ -291,6 +609,42 @@ impl ToolRegistry {
     }
 }
 
+/// Metadata key of the base64 PNG screenshot attached to failed results
+pub const ERROR_SCREENSHOT_KEY: &str = "error_screenshot";
+
+/// When the session captures on error, turn a failure into a failed result with a screenshot of
+/// the context's tab as [`ERROR_SCREENSHOT_KEY`] metadata. Errors are returned as they are if the
+/// screenshot can't be taken, e.g. because the browser is gone.
+pub(crate) fn with_error_screenshot(result: Result<ToolResult>, context: &ToolContext) -> Result<ToolResult> {
+    if !context.session.capture_on_error() || matches!(&result, Ok(result) if result.success) {
+        return result;
+    }
+
+    let screenshot = context.tab().and_then(|tab| {
+        tab.capture_screenshot(CaptureScreenshotFormatOption::Png, None, None, true)
+            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))
+    });
+    let screenshot = match screenshot {
+        Ok(screenshot) => screenshot,
+        Err(e) => {
+            log::warn!("Failed to capture screenshot of failed tool: {}", e);
+            return result;
+        }
+    };
+
+    let failure = match result {
+        Ok(result) => result,
+        Err(e) => ToolResult::from_error(&e),
+    };
+    Ok(failure.with_metadata(ERROR_SCREENSHOT_KEY, serde_json::json!(BASE64.encode(screenshot))))
+}
+
+/// Store `elapsed` as `duration_ms` metadata, keeping any value the tool set itself
+fn record_duration(mut result: ToolResult, elapsed: Duration) -> ToolResult {
+    result.metadata.entry("duration_ms".to_string()).or_insert_with(|| serde_json::json!(elapsed.as_millis() as u64));
+    result
+}
+
 impl Default for ToolRegistry {
     fn default() -> Self {
         Self::with_defaults()
@@ -317,10 +671,65 @@ mod tests {
         assert_eq!(result.error, Some("Test error".to_string()));
     }
 
+    #[test]
+    fn test_tool_result_from_error() {
+        let result = ToolResult::from_error(&BrowserError::ElementNotFound("#missing".to_string()));
+        assert!(!result.success);
+        assert_eq!(result.error, Some("Element not found: #missing".to_string()));
+        assert_eq!(result.error_code, Some("element_not_found".to_string()));
+    }
+
+    #[test]
+    fn test_is_mutating() {
+        assert!(!snapshot::SnapshotTool.is_mutating());
+        assert!(!batch::BatchTool.is_mutating());
+        assert!(click::ClickTool.is_mutating());
+        assert!(evaluate::EvaluateTool.is_mutating());
+    }
+
+    #[test]
+    fn test_registry_validate_click_params() {
+        let registry = ToolRegistry::with_defaults();
+
+        assert!(registry.validate("click", &serde_json::json!({"selector": "#submit"})).is_ok());
+        assert!(registry.validate("click", &serde_json::json!({"index": 3})).is_ok());
+
+        let err = registry.validate("click", &serde_json::json!({"index": "first"})).unwrap_err();
+        assert!(matches!(err, BrowserError::InvalidArgument(ref msg) if msg.starts_with("Invalid parameters")));
+
+        assert!(matches!(
+            registry.validate("no_such_tool", &serde_json::json!({})),
+            Err(BrowserError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_registry_describe() {
+        let registry = ToolRegistry::with_defaults();
+        let descriptors = registry.describe();
+
+        assert_eq!(descriptors.len(), registry.count());
+        assert!(descriptors.iter().all(|d| !d.description.is_empty()));
+
+        let click = descriptors.iter().find(|d| d.name == "click").unwrap();
+        assert!(click.parameters_schema["properties"]["selector"].is_object());
+    }
+
     #[test]
     fn test_tool_result_with_metadata() {
         let result = ToolResult::success(None).with_metadata("duration_ms", serde_json::json!(100));
 
         assert!(result.metadata.contains_key("duration_ms"));
     }
+
+    #[test]
+    fn test_record_duration() {
+        let result = record_duration(ToolResult::success(None), Duration::from_millis(42));
+        assert_eq!(result.metadata.get("duration_ms"), Some(&serde_json::json!(42)));
+
+        // A duration set by the tool itself is kept
+        let result = ToolResult::success(None).with_metadata("duration_ms", serde_json::json!(7));
+        let result = record_duration(result, Duration::from_millis(42));
+        assert_eq!(result.metadata.get("duration_ms"), Some(&serde_json::json!(7)));
+    }
 }
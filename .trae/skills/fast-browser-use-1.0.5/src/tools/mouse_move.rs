@@ -0,0 +1,88 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult, human_mouse::dispatch_mouse_move}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Default number of mouse moves from the last known position to the target
+const DEFAULT_STEPS: u32 = 10;
+
+/// Parameters for the mouse_move tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MouseMoveParams {
+    /// Horizontal position in CSS pixels from the left edge of the viewport
+    pub x: f64,
+
+    /// Vertical position in CSS pixels from the top edge of the viewport
+    pub y: f64,
+
+    /// Number of mouse moves from the last known position, evenly spaced (default: 10)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steps: Option<u32>,
+}
+
+/// `steps` evenly spaced points on the straight line from `from` to `to`, ending at `to`
+fn interpolate(from: (f64, f64), to: (f64, f64), steps: u32) -> Vec<(f64, f64)> {
+    let steps = steps.max(1);
+    (1..=steps)
+        .map(|step| {
+            if step == steps {
+                return to;
+            }
+            let t = step as f64 / steps as f64;
+            (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+        })
+        .collect()
+}
+
+/// Tool for moving the mouse to viewport coordinates, e.g. to reveal hover menus or to build
+/// drag and canvas interactions
+#[derive(Default)]
+pub struct MouseMoveTool;
+
+impl Tool for MouseMoveTool {
+    type Params = MouseMoveParams;
+
+    fn name(&self) -> &str {
+        "mouse_move"
+    }
+
+    fn description(&self) -> &str {
+        "Move the mouse to the given viewport coordinates"
+    }
+
+    fn execute_typed(&self, params: MouseMoveParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let to = (params.x, params.y);
+
+        // Without a known position there is nothing to interpolate from, so jump straight there
+        let from = context.session.mouse_position_in(&tab);
+        let path = match from {
+            Some(from) => interpolate(from, to, params.steps.unwrap_or(DEFAULT_STEPS)),
+            None => vec![to],
+        };
+        for &position in &path {
+            dispatch_mouse_move(context.session, &tab, position)?;
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "x": params.x,
+            "y": params.y,
+            "from": from.map(|(x, y)| serde_json::json!({ "x": x, "y": y })),
+            "steps": path.len(),
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(
+            interpolate((0.0, 0.0), (100.0, 50.0), 4),
+            vec![(25.0, 12.5), (50.0, 25.0), (75.0, 37.5), (100.0, 50.0)]
+        );
+        assert_eq!(interpolate((10.0, 10.0), (20.0, 20.0), 0), vec![(20.0, 20.0)]);
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{error::Result,
             tools::{Tool, ToolContext, ToolResult,
+                    html_source::{decode_body, main_frame, original_response},
                     snapshot::{RenderMode, render_aria_tree},
                     utils::normalize_url}};
+use headless_chrome::{Tab, protocol::cdp::Page};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Parameters for the navigate tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -11,15 +14,64 @@ pub struct NavigateParams {
     /// URL to navigate to
     pub url: String,
 
-    /// Wait for navigation to complete (default: true)
+    /// Wait for the page to load as far as the session's page-load strategy asks for (default: true)
     #[serde(default = "default_wait")]
     pub wait_for_load: bool,
+
+    /// CSS selector to wait for after navigating (replaces the load wait when set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_for_selector: Option<String>,
+
+    /// Timeout in milliseconds for the selector or load wait (default: session default timeout)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
 }
 
 fn default_wait() -> bool {
     true
 }
 
+/// Whether documents of this MIME type are web pages that snapshots can read
+fn is_html(mime_type: &str) -> bool {
+    matches!(mime_type, "" | "text/html" | "application/xhtml+xml")
+}
+
+/// Whether a body of this MIME type is text, so it is returned decoded rather than as base64
+fn is_text(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || ["json", "xml", "javascript", "ecmascript"]
+            .iter()
+            .any(|kind| mime_type.ends_with(&format!("/{}", kind)) || mime_type.ends_with(&format!("+{}", kind)))
+}
+
+/// Result for a document that isn't a web page (e.g. JSON, plain text, an image or a PDF): its
+/// raw body, since Chrome shows it in a built-in viewer that snapshots can't read
+fn raw_response(tab: &Tab, url: &str, frame: &Page::Frame) -> Result<serde_json::Value> {
+    let resource = original_response(tab, frame)?;
+    let (body, encoding, length) = match (resource.base_64_encoded, is_text(&frame.mime_type)) {
+        (true, true) => {
+            let bytes = decode_body(&resource.content)?;
+            (String::from_utf8_lossy(&bytes).into_owned(), "text", bytes.len())
+        }
+        (true, false) => {
+            let length = decode_body(&resource.content)?.len();
+            (resource.content, "base64", length)
+        }
+        (false, _) => {
+            let length = resource.content.len();
+            (resource.content, "text", length)
+        }
+    };
+
+    Ok(serde_json::json!({
+        "url": url,
+        "content_type": frame.mime_type,
+        "encoding": encoding,
+        "length": length,
+        "body": body,
+    }))
+}
+
 /// Tool for navigating to a URL
 #[derive(Default)]
 pub struct NavigateTool;
@@ -31,16 +83,39 @@ impl Tool for NavigateTool {
         "navigate"
     }
 
+    fn description(&self) -> &str {
+        "Navigate to a specified URL in the browser"
+    }
+
     fn execute_typed(&self, params: NavigateParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Normalize the URL
         let normalized_url = normalize_url(&params.url);
 
         // Navigate to normalized URL
-        context.session.navigate(&normalized_url)?;
+        let tab = context.tab()?;
+        context.session.navigate_in(&tab, &normalized_url)?;
+
+        let timeout = params.timeout_ms.map(Duration::from_millis).unwrap_or_else(|| context.session.default_timeout());
 
-        // Wait for navigation if requested
-        if params.wait_for_load {
-            context.session.wait_for_navigation()?;
+        // Wait for the selector if given, otherwise for navigation if requested
+        let selector_found = if let Some(selector) = &params.wait_for_selector {
+            Some(tab.wait_for_element_with_custom_timeout(selector, timeout).is_ok())
+        } else {
+            if params.wait_for_load {
+                context.session.wait_for_page_load_in(&tab, timeout)?;
+            }
+            None
+        };
+
+        // Non-HTML documents have no DOM worth snapshotting; return their body instead
+        if let Ok(frame) = main_frame(&tab)
+            && !is_html(&frame.mime_type)
+        {
+            let mut result = raw_response(&tab, &normalized_url, &frame)?;
+            if let Some(found) = selector_found {
+                result["selector_found"] = serde_json::json!(found);
+            }
+            return Ok(ToolResult::success_with(result));
         }
 
         let snapshot = {
@@ -48,8 +123,36 @@ impl Tool for NavigateTool {
             render_aria_tree(&dom.root, RenderMode::Ai, None)
         };
 
-        Ok(ToolResult::success_with(serde_json::json!({
+        let mut result = serde_json::json!({
+            "url": normalized_url,
             "snapshot": snapshot
-        })))
+        });
+        if let Some(found) = selector_found {
+            result["selector_found"] = serde_json::json!(found);
+        }
+
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_detection() {
+        assert!(is_html("text/html"));
+        assert!(is_html("application/xhtml+xml"));
+        assert!(!is_html("application/json"));
+        assert!(!is_html("text/plain"));
+
+        assert!(is_text("text/plain"));
+        assert!(is_text("application/json"));
+        assert!(is_text("application/ld+json"));
+        assert!(is_text("image/svg+xml"));
+        assert!(is_text("application/javascript"));
+        assert!(!is_text("image/png"));
+        assert!(!is_text("application/pdf"));
+        assert!(!is_text("application/octet-stream"));
     }
 }
@@ -23,8 +23,13 @@ impl Tool for NewTabTool {
         "new_tab"
     }
 
+    fn description(&self) -> &str {
+        "Open a new tab and navigate to the specified URL"
+    }
+
     fn execute_typed(&self, params: NewTabParams, context: &mut ToolContext) -> Result<ToolResult> {
         let normalized_url = normalize_url(&params.url);
+        context.session.check_url_allowed(&normalized_url)?;
         let tab = context
             .session
             .browser()
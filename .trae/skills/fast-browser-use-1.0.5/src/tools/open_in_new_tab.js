@@ -0,0 +1,23 @@
+JSON.stringify(
+  (function () {
+    const selector = __SELECTOR__;
+    const element = document.querySelector(selector);
+    if (!element) {
+      return { success: false, error: "Element not found" };
+    }
+
+    const tagName = element.tagName.toLowerCase();
+    if (tagName !== "a" && tagName !== "area") {
+      return {
+        success: false,
+        error: "Element is not a link (<" + tagName + ">)",
+      };
+    }
+
+    if (!element.hasAttribute("href") || !element.href) {
+      return { success: false, error: "Link has no href" };
+    }
+
+    return { success: true, href: element.href };
+  })()
+);
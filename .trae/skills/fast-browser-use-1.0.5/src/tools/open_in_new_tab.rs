@@ -0,0 +1,107 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Parameters for the open_in_new_tab tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OpenInNewTabParams {
+    /// CSS selector of the link (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index of the link from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+}
+
+/// Tool for opening an on-page link in a new background tab
+#[derive(Default)]
+pub struct OpenInNewTabTool;
+
+const OPEN_IN_NEW_TAB_JS: &str = include_str!("open_in_new_tab.js");
+
+impl Tool for OpenInNewTabTool {
+    type Params = OpenInNewTabParams;
+
+    fn name(&self) -> &str {
+        "open_in_new_tab"
+    }
+
+    fn description(&self) -> &str {
+        "Open a link specified by CSS selector or index in a new background tab"
+    }
+
+    fn execute_typed(&self, params: OpenInNewTabParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Validate that exactly one selector method is provided
+        match (&params.selector, &params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "open_in_new_tab".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "open_in_new_tab".to_string(),
+                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let css_selector = if let Some(selector) = params.selector {
+            selector
+        } else if let Some(index) = params.index {
+            let dom = context.get_dom()?;
+            let selector = dom
+                .get_selector(index)
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+            selector.clone()
+        } else {
+            unreachable!("Validation above ensures one field is Some")
+        };
+
+        // Read the resolved href of the link
+        let selector_json = serde_json::to_string(&css_selector).expect("serializing CSS selector never fails");
+        let js = OPEN_IN_NEW_TAB_JS.replace("__SELECTOR__", &selector_json);
+
+        let result = context.tab()?.evaluate(&js, false).map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "open_in_new_tab".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Parse the JSON string returned by JavaScript
+        let result_json: serde_json::Value = if let Some(serde_json::Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+        };
+
+        if result_json["success"].as_bool() != Some(true) {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "open_in_new_tab".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            });
+        }
+
+        let href = result_json["href"].as_str().unwrap_or_default().to_string();
+
+        context.session.check_url_allowed(&href)?;
+
+        // Open the link in a new tab without bringing it to front
+        let tab = context.session.new_tab()?;
+        tab.navigate_to(&href)
+            .map_err(|e| BrowserError::NavigationFailed(format!("Failed to navigate to {}: {}", href, e)))?;
+
+        let tab_index = context.session.get_tabs()?.iter().position(|t| Arc::ptr_eq(t, &tab));
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "selector": css_selector,
+            "url": href,
+            "tab_index": tab_index
+        })))
+    }
+}
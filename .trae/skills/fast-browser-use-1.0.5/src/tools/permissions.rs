@@ -0,0 +1,133 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::protocol::cdp::Browser::{GrantPermissions, PermissionType, ResetPermissions};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the grant_permissions tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GrantPermissionsParams {
+    /// Permissions to grant, e.g. "geolocation", "notifications", "camera", "microphone", "clipboard";
+    /// CDP permission names such as "audioCapture" are accepted too
+    pub permissions: Vec<String>,
+
+    /// Origin to grant them to, e.g. "https://example.com" (default: all origins)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+}
+
+/// Parameters for the reset_permissions tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResetPermissionsParams {}
+
+/// Resolve a permission name to the CDP permission types it stands for
+fn permission_types(name: &str) -> Result<Vec<PermissionType>> {
+    let types = match name.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+        "camera" | "video" => vec![PermissionType::VideoCapture],
+        "microphone" | "mic" | "audio" => vec![PermissionType::AudioCapture],
+        "clipboard" => vec![PermissionType::ClipboardReadWrite, PermissionType::ClipboardSanitizedWrite],
+        "geolocation" | "location" => vec![PermissionType::Geolocation],
+        "notifications" | "notification" | "push" => vec![PermissionType::Notifications],
+        "midi" => vec![PermissionType::Midi],
+        "sensors" => vec![PermissionType::Sensors],
+        "screen" | "displaycapture" => vec![PermissionType::DisplayCapture],
+        _ => vec![serde_json::from_value(serde_json::Value::String(name.trim().to_string())).map_err(|_| {
+            BrowserError::InvalidArgument(format!(
+                "Unknown permission '{}'. Use e.g. geolocation, notifications, camera, microphone, clipboard, or a \
+                 CDP permission type such as 'audioCapture'",
+                name
+            ))
+        })?],
+    };
+    Ok(types)
+}
+
+/// Tool for granting HTML5 permissions so sites don't block on a permission prompt
+#[derive(Default)]
+pub struct GrantPermissionsTool;
+
+impl Tool for GrantPermissionsTool {
+    type Params = GrantPermissionsParams;
+
+    fn name(&self) -> &str {
+        "grant_permissions"
+    }
+
+    fn description(&self) -> &str {
+        "Grant permissions such as geolocation, notifications or camera without a prompt"
+    }
+
+    fn execute_typed(&self, params: GrantPermissionsParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if params.permissions.is_empty() {
+            return Err(BrowserError::InvalidArgument("At least one permission is required".to_string()));
+        }
+
+        let mut permissions: Vec<PermissionType> = Vec::new();
+        for name in &params.permissions {
+            for permission in permission_types(name)? {
+                if !permissions.contains(&permission) {
+                    permissions.push(permission);
+                }
+            }
+        }
+
+        context
+            .tab()?
+            .call_method(GrantPermissions {
+                permissions: permissions.clone(),
+                origin: params.origin.clone(),
+                browser_context_id: None,
+            })
+            .map_err(|e| BrowserError::ChromeError(format!("Failed to grant permissions: {}", e)))?;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "granted": permissions,
+            "origin": params.origin,
+        })))
+    }
+}
+
+/// Tool for reverting every permission override back to the browser defaults
+#[derive(Default)]
+pub struct ResetPermissionsTool;
+
+impl Tool for ResetPermissionsTool {
+    type Params = ResetPermissionsParams;
+
+    fn name(&self) -> &str {
+        "reset_permissions"
+    }
+
+    fn description(&self) -> &str {
+        "Reset all granted permissions to the browser defaults"
+    }
+
+    fn execute_typed(&self, _params: ResetPermissionsParams, context: &mut ToolContext) -> Result<ToolResult> {
+        context
+            .tab()?
+            .call_method(ResetPermissions { browser_context_id: None })
+            .map_err(|e| BrowserError::ChromeError(format!("Failed to reset permissions: {}", e)))?;
+
+        Ok(ToolResult::success_with(serde_json::json!({ "reset": true })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permission_friendly_names() {
+        assert_eq!(permission_types("camera").unwrap(), vec![PermissionType::VideoCapture]);
+        assert_eq!(permission_types("Microphone").unwrap(), vec![PermissionType::AudioCapture]);
+        assert_eq!(permission_types("notifications").unwrap(), vec![PermissionType::Notifications]);
+        assert_eq!(permission_types("clipboard").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_permission_cdp_names() {
+        assert_eq!(permission_types("audioCapture").unwrap(), vec![PermissionType::AudioCapture]);
+        assert_eq!(permission_types("idleDetection").unwrap(), vec![PermissionType::IdleDetection]);
+        assert!(matches!(permission_types("teleportation"), Err(BrowserError::InvalidArgument(_))));
+    }
+}
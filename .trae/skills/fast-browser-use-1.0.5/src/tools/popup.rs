@@ -0,0 +1,72 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+fn default_timeout_ms() -> u64 {
+    10000
+}
+
+fn default_activate() -> bool {
+    true
+}
+
+/// Parameters for the wait_for_popup tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WaitForPopupParams {
+    /// How long to wait for a popup or new tab to open (default: 10000)
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Switch to the popup so following tools act on it (default: true)
+    #[serde(default = "default_activate")]
+    pub activate: bool,
+}
+
+/// Tool for waiting until the page opens a popup or new tab
+#[derive(Default)]
+pub struct WaitForPopupTool;
+
+impl Tool for WaitForPopupTool {
+    type Params = WaitForPopupParams;
+
+    fn name(&self) -> &str {
+        "wait_for_popup"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Wait for the page to open a popup or new tab (e.g. an OAuth login window) and switch to it"
+    }
+
+    fn execute_typed(&self, params: WaitForPopupParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let popup = context.session.wait_for_new_target(params.timeout_ms)?;
+
+        // Popups start out on about:blank; give the initial navigation a chance to finish
+        if let Err(e) = popup.wait_until_navigated() {
+            log::debug!("Popup did not finish navigating: {}", e);
+        }
+
+        if params.activate {
+            popup
+                .activate()
+                .map_err(|e| BrowserError::TabOperationFailed(format!("Failed to activate popup: {}", e)))?;
+            // The DOM cache belongs to the previous tab
+            context.dom_tree = None;
+        }
+
+        let index = context.session.get_tabs()?.iter().position(|tab| Arc::ptr_eq(tab, &popup));
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "index": index,
+            "target_id": popup.get_target_id(),
+            "title": popup.get_title().unwrap_or_default(),
+            "url": popup.get_url(),
+            "activated": params.activate,
+        })))
+    }
+}
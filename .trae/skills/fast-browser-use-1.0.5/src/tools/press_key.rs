@@ -21,9 +21,12 @@ impl Tool for PressKeyTool {
         "press_key"
     }
 
+    fn description(&self) -> &str {
+        "Press a key on the keyboard"
+    }
+
     fn execute_typed(&self, params: PressKeyParams, context: &mut ToolContext) -> Result<ToolResult> {
         context
-            .session
             .tab()?
             .press_key(&params.key)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "press_key".to_string(), reason: e.to_string() })?;
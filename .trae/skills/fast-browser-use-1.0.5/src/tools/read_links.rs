@@ -24,6 +24,14 @@ impl Tool for ReadLinksTool {
         "read_links"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Read all links on the current page"
+    }
+
     fn execute_typed(&self, _params: ReadLinksParams, context: &mut ToolContext) -> Result<ToolResult> {
         // JavaScript code to extract all links on the page
         // We use JSON.stringify to ensure the result is returned properly
@@ -38,11 +46,8 @@ impl Tool for ReadLinksTool {
             )
         "#;
 
-        let result = context
-            .session
-            .tab()?
-            .evaluate(js_code, false)
-            .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+        let result =
+            context.tab()?.evaluate(js_code, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
 
         // Parse the JSON string result into Link structs
         let links: Vec<Link> = result
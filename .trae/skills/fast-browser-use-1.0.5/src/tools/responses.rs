@@ -0,0 +1,75 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the capture_responses tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CaptureResponsesParams {
+    /// Glob matched against the full response URL, where `*` matches anything (e.g. `*/api/*`)
+    pub url_pattern: String,
+}
+
+/// Parameters for the get_captured_responses tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct GetCapturedResponsesParams {
+    /// Clear the recorded responses after returning them (default: false)
+    #[serde(default)]
+    pub clear: bool,
+}
+
+/// Tool for starting to record XHR and fetch responses, bodies included
+#[derive(Default)]
+pub struct CaptureResponsesTool;
+
+impl Tool for CaptureResponsesTool {
+    type Params = CaptureResponsesParams;
+
+    fn name(&self) -> &str {
+        "capture_responses"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Start recording XHR and fetch responses whose URL matches a glob pattern, including their bodies"
+    }
+
+    fn execute_typed(&self, params: CaptureResponsesParams, context: &mut ToolContext) -> Result<ToolResult> {
+        context.session.capture_responses(&params.url_pattern)?;
+        Ok(ToolResult::success_with(serde_json::json!({
+            "url_pattern": params.url_pattern,
+            "capturing": true,
+        })))
+    }
+}
+
+/// Tool for reading the responses recorded since capture_responses
+#[derive(Default)]
+pub struct GetCapturedResponsesTool;
+
+impl Tool for GetCapturedResponsesTool {
+    type Params = GetCapturedResponsesParams;
+
+    fn name(&self) -> &str {
+        "get_captured_responses"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the XHR and fetch responses recorded since capture_responses, most recent last"
+    }
+
+    fn execute_typed(&self, params: GetCapturedResponsesParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let responses = context.session.get_captured_responses()?;
+        if params.clear {
+            context.session.clear_captured_responses()?;
+        }
+        Ok(ToolResult::success_with(responses))
+    }
+}
@@ -23,17 +23,28 @@ impl Tool for ScreenshotTool {
         "screenshot"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Capture a screenshot of the current page"
+    }
+
     fn execute_typed(&self, params: ScreenshotParams, context: &mut ToolContext) -> Result<ToolResult> {
-        let screenshot_data = context
-            .session
-            .tab()?
-            .capture_screenshot(
-                headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
-                None,
-                None,
-                params.full_page,
-            )
-            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?;
+        let screenshot_data = if params.full_page {
+            context.session.capture_full_page_in(&context.tab()?)?
+        } else {
+            context
+                .tab()?
+                .capture_screenshot(
+                    headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
+                    None,
+                    None,
+                    true,
+                )
+                .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?
+        };
 
         std::fs::write(&params.path, &screenshot_data)
             .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to save screenshot: {}", e)))?;
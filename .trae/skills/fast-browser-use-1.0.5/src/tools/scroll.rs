@@ -1,7 +1,9 @@
 use crate::{error::{BrowserError, Result},
             tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::protocol::cdp::Input::{DispatchMouseEvent, DispatchMouseEventTypeOption};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::{thread, time::Duration};
 
 /// Parameters for the scroll tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -10,6 +12,11 @@ pub struct ScrollParams {
     /// If not provided, scrolls to the bottom of the page.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<i32>,
+
+    /// Viewport point `[x, y]` to scroll at with mouse-wheel events instead of scrolling the
+    /// window, so the scrollable container under the point (chat panel, modal, ...) scrolls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<(f64, f64)>,
 }
 
 /// Tool for scrolling the page
@@ -18,6 +25,34 @@ pub struct ScrollTool;
 
 const SCROLL_JS: &str = include_str!("scroll.js");
 
+/// Remembers the element a wheel event at __POINT__ scrolls (the closest scrollable ancestor of
+/// the element under the point) and returns its scroll position and the viewport height
+const WHEEL_TARGET_JS: &str = r#"(() => {
+    const [x, y] = __POINT__;
+    let element = document.elementFromPoint(x, y);
+    while (element && element !== document.scrollingElement) {
+        const overflow = getComputedStyle(element).overflowY;
+        if ((overflow === 'auto' || overflow === 'scroll') && element.scrollHeight > element.clientHeight) {
+            break;
+        }
+        element = element.parentElement;
+    }
+    const target = element || document.scrollingElement;
+    window.__browserUseWheelTarget = target;
+    return JSON.stringify({ scrollTop: target.scrollTop, viewportHeight: window.innerHeight });
+})()"#;
+
+/// Scroll position of the element remembered by [`WHEEL_TARGET_JS`] and whether it is at its bottom
+const WHEEL_RESULT_JS: &str = r#"(() => {
+    const target = window.__browserUseWheelTarget || document.scrollingElement;
+    delete window.__browserUseWheelTarget;
+    const isAtBottom = Math.abs(target.scrollHeight - target.scrollTop - target.clientHeight) <= 1;
+    return JSON.stringify({ scrollTop: target.scrollTop, isAtBottom });
+})()"#;
+
+/// Time given to the browser to apply a wheel scroll before it is measured
+const WHEEL_SETTLE: Duration = Duration::from_millis(100);
+
 impl Tool for ScrollTool {
     type Params = ScrollParams;
 
@@ -25,14 +60,21 @@ impl Tool for ScrollTool {
         "scroll"
     }
 
+    fn description(&self) -> &str {
+        "Scroll the page by a specified amount or to the bottom"
+    }
+
     fn execute_typed(&self, params: ScrollParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if let Some(point) = params.target {
+            return wheel_scroll(context, point, params.amount);
+        }
+
         let config = serde_json::json!({
             "amount": params.amount
         });
         let scroll_js = SCROLL_JS.replace("__SCROLL_CONFIG__", &config.to_string());
 
         let result = context
-            .session
             .tab()?
             .evaluate(&scroll_js, true)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "scroll".to_string(), reason: e.to_string() })?;
@@ -61,6 +103,64 @@ impl Tool for ScrollTool {
     }
 }
 
+/// Evaluate one of the wheel scripts and parse the JSON string it returns
+fn evaluate_json(context: &ToolContext, js: &str) -> Result<serde_json::Value> {
+    let result = context
+        .tab()?
+        .evaluate(js, false)
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: "scroll".to_string(), reason: e.to_string() })?;
+    result.value.as_ref().and_then(|v| v.as_str()).and_then(|s| serde_json::from_str(s).ok()).ok_or_else(|| {
+        BrowserError::ToolExecutionFailed {
+            tool: "scroll".to_string(),
+            reason: "Failed to measure scroll position".to_string(),
+        }
+    })
+}
+
+/// Scroll whatever is under `point` with a mouse-wheel event and report how far it really moved
+fn wheel_scroll(context: &ToolContext, (x, y): (f64, f64), amount: Option<i32>) -> Result<ToolResult> {
+    let before = evaluate_json(context, &WHEEL_TARGET_JS.replace("__POINT__", &serde_json::json!([x, y]).to_string()))?;
+    let delta_y = match amount {
+        Some(amount) => amount as f64,
+        None => before["viewportHeight"].as_f64().unwrap_or(0.0),
+    };
+
+    context
+        .tab()?
+        .call_method(DispatchMouseEvent {
+            Type: DispatchMouseEventTypeOption::MouseWheel,
+            x,
+            y,
+            modifiers: None,
+            timestamp: None,
+            button: None,
+            buttons: None,
+            click_count: None,
+            force: None,
+            tangential_pressure: None,
+            tilt_x: None,
+            tilt_y: None,
+            twist: None,
+            delta_x: Some(0.0),
+            delta_y: Some(delta_y),
+            pointer_Type: None,
+        })
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: "scroll".to_string(), reason: e.to_string() })?;
+    thread::sleep(WHEEL_SETTLE);
+
+    let after = evaluate_json(context, WHEEL_RESULT_JS)?;
+    let actual_scroll =
+        (after["scrollTop"].as_f64().unwrap_or(0.0) - before["scrollTop"].as_f64().unwrap_or(0.0)).round() as i64;
+    let is_at_bottom = after["isAtBottom"].as_bool().unwrap_or(false);
+
+    Ok(ToolResult::success_with(serde_json::json!({
+        "scrolled": actual_scroll,
+        "isAtBottom": is_at_bottom,
+        "target": [x, y],
+        "message": format!("Scrolled the element at ({}, {}) by {} pixels.", x, y, actual_scroll)
+    })))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +192,15 @@ mod tests {
         let params: ScrollParams = serde_json::from_value(json).unwrap();
         assert_eq!(params.amount, None);
     }
+
+    #[test]
+    fn test_scroll_params_target() {
+        let json = serde_json::json!({
+            "amount": 200,
+            "target": [150.0, 320.5]
+        });
+
+        let params: ScrollParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.target, Some((150.0, 320.5)));
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult}};
+            tools::{Tool, ToolContext, ToolResult, utils::with_retry}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -31,6 +31,10 @@ impl Tool for SelectTool {
         "select"
     }
 
+    fn description(&self) -> &str {
+        "Select an option in a dropdown element by CSS selector or index"
+    }
+
     fn execute_typed(&self, params: SelectParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Validate that exactly one selector method is provided
         match (&params.selector, &params.index) {
@@ -52,11 +56,12 @@ impl Tool for SelectTool {
         let css_selector = if let Some(selector) = params.selector {
             selector
         } else if let Some(index) = params.index {
-            let dom = context.get_dom()?;
-            let selector = dom
-                .get_selector(index)
-                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
-            selector.clone()
+            // Make sure the index still resolves to a live element before selecting
+            let tab = context.tab()?;
+            with_retry(context, index, |context, selector| {
+                context.session.find_element(&tab, selector)?;
+                Ok(selector.to_string())
+            })?
         } else {
             unreachable!("Validation above ensures one field is Some")
         };
@@ -69,7 +74,6 @@ impl Tool for SelectTool {
         let select_js = SELECT_JS.replace("__SELECT_CONFIG__", &select_config.to_string());
 
         let result = context
-            .session
             .tab()?
             .evaluate(&select_js, false)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "select".to_string(), reason: e.to_string() })?;
@@ -0,0 +1,156 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, utils::js_string}};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GetSessionStorageParams {
+    /// The key to retrieve. If omitted, returns all key-value pairs.
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct SetSessionStorageParams {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RemoveSessionStorageParams {
+    /// The key to remove.
+    pub key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ClearSessionStorageParams;
+
+#[derive(Default)]
+pub struct GetSessionStorageTool;
+
+impl Tool for GetSessionStorageTool {
+    type Params = GetSessionStorageParams;
+
+    fn name(&self) -> &str {
+        "get_session_storage"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get sessionStorage items of the current page"
+    }
+
+    fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        let (script, is_json) = if let Some(key) = &params.key {
+            (format!("window.sessionStorage.getItem({})", js_string(key)), false)
+        } else {
+            (
+                r#"
+                JSON.stringify((function() {
+                    const items = {};
+                    for (let i = 0; i < sessionStorage.length; i++) {
+                        const key = sessionStorage.key(i);
+                        items[key] = sessionStorage.getItem(key);
+                    }
+                    return items;
+                })())
+                "#
+                .to_string(),
+                true,
+            )
+        };
+
+        let remote_object = context
+            .tab()?
+            .evaluate(&script, false)
+            .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to get session storage: {}", e)))?;
+
+        let value = remote_object.value.unwrap_or(Value::Null);
+
+        if is_json && let Some(json_str) = value.as_str() {
+            let parsed: Value = serde_json::from_str(json_str)
+                .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to parse session storage JSON: {}", e)))?;
+            return Ok(ToolResult::success_with(parsed));
+        }
+
+        Ok(ToolResult::success_with(value))
+    }
+}
+
+#[derive(Default)]
+pub struct SetSessionStorageTool;
+
+impl Tool for SetSessionStorageTool {
+    type Params = SetSessionStorageParams;
+
+    fn name(&self) -> &str {
+        "set_session_storage"
+    }
+
+    fn description(&self) -> &str {
+        "Set a sessionStorage item on the current page"
+    }
+
+    fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        let script = format!("window.sessionStorage.setItem({}, {})", js_string(&params.key), js_string(&params.value));
+
+        context
+            .tab()?
+            .evaluate(&script, false)
+            .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to set session storage: {}", e)))?;
+
+        Ok(ToolResult::success(None))
+    }
+}
+
+#[derive(Default)]
+pub struct RemoveSessionStorageTool;
+
+impl Tool for RemoveSessionStorageTool {
+    type Params = RemoveSessionStorageParams;
+
+    fn name(&self) -> &str {
+        "remove_session_storage"
+    }
+
+    fn description(&self) -> &str {
+        "Remove a sessionStorage item from the current page"
+    }
+
+    fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        let script = format!("window.sessionStorage.removeItem({})", js_string(&params.key));
+
+        context
+            .tab()?
+            .evaluate(&script, false)
+            .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to remove session storage item: {}", e)))?;
+
+        Ok(ToolResult::success(None))
+    }
+}
+
+#[derive(Default)]
+pub struct ClearSessionStorageTool;
+
+impl Tool for ClearSessionStorageTool {
+    type Params = ClearSessionStorageParams;
+
+    fn name(&self) -> &str {
+        "clear_session_storage"
+    }
+
+    fn description(&self) -> &str {
+        "Clear all sessionStorage items of the current page"
+    }
+
+    fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
+        context
+            .tab()?
+            .evaluate("window.sessionStorage.clear()", false)
+            .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to clear session storage: {}", e)))?;
+
+        Ok(ToolResult::success(None))
+    }
+}
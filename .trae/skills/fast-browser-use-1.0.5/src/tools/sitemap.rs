@@ -268,6 +268,10 @@ impl Tool for SitemapTool {
         "sitemap"
     }
 
+    fn description(&self) -> &str {
+        "Analyze a site's sitemaps and page structure"
+    }
+
     fn execute_typed(&self, params: SitemapParams, context: &mut ToolContext) -> Result<ToolResult> {
         let base_url = params.url.trim_end_matches('/');
         let sitemap_urls = vec![
@@ -284,29 +288,29 @@ impl Tool for SitemapTool {
             page_structures: Vec::new(),
         };
 
-        let tab = context.session.tab()?;
+        let tab = context.tab()?;
 
         // Try to fetch robots.txt first
         let robots_url = format!("{}/robots.txt", base_url);
-        context.session.navigate(&robots_url)?;
-        context.session.wait_for_navigation()?;
+        context.session.navigate_in(&tab, &robots_url)?;
+        context.session.wait_for_navigation_in(&tab, context.session.default_timeout())?;
 
         let robots_js = r#"document.body?.innerText || document.documentElement?.innerText || ''"#;
-        if let Ok(eval_result) = tab.evaluate(robots_js, false) {
-            if let Some(value) = &eval_result.value {
-                if let Some(text) = value.as_str() {
-                    if !text.contains("404") && !text.is_empty() && text.len() < 50000 {
-                        result.robots_txt = Some(text.to_string());
-                        // Extract sitemap URLs from robots.txt
-                        for line in text.lines() {
-                            let line = line.trim();
-                            if line.to_lowercase().starts_with("sitemap:") {
-                                let sitemap_url = line[8..].trim().to_string();
-                                if !result.sitemaps.contains(&sitemap_url) {
-                                    result.sitemaps.push(sitemap_url);
-                                }
-                            }
-                        }
+        if let Ok(eval_result) = tab.evaluate(robots_js, false)
+            && let Some(value) = &eval_result.value
+            && let Some(text) = value.as_str()
+            && !text.contains("404")
+            && !text.is_empty()
+            && text.len() < 50000
+        {
+            result.robots_txt = Some(text.to_string());
+            // Extract sitemap URLs from robots.txt
+            for line in text.lines() {
+                let line = line.trim();
+                if line.to_lowercase().starts_with("sitemap:") {
+                    let sitemap_url = line[8..].trim().to_string();
+                    if !result.sitemaps.contains(&sitemap_url) {
+                        result.sitemaps.push(sitemap_url);
                     }
                 }
             }
@@ -315,16 +319,15 @@ impl Tool for SitemapTool {
         // Try common sitemap URLs if none found in robots.txt
         if result.sitemaps.is_empty() {
             for sitemap_url in &sitemap_urls {
-                context.session.navigate(sitemap_url)?;
-                context.session.wait_for_navigation()?;
-
-                if let Ok(eval_result) = tab.evaluate(CHECK_SITEMAP_JS, false) {
-                    if let Some(value) = &eval_result.value {
-                        if value.as_str() == Some("valid") {
-                            result.sitemaps.push(sitemap_url.clone());
-                            break;
-                        }
-                    }
+                context.session.navigate_in(&tab, sitemap_url)?;
+                context.session.wait_for_navigation_in(&tab, context.session.default_timeout())?;
+
+                if let Ok(eval_result) = tab.evaluate(CHECK_SITEMAP_JS, false)
+                    && let Some(value) = &eval_result.value
+                    && value.as_str() == Some("valid")
+                {
+                    result.sitemaps.push(sitemap_url.clone());
+                    break;
                 }
             }
         }
@@ -340,25 +343,23 @@ impl Tool for SitemapTool {
             sitemap_queue.remove(0);
             sitemaps_parsed += 1;
 
-            context.session.navigate(&sitemap_url)?;
-            context.session.wait_for_navigation()?;
-
-            if let Ok(eval_result) = tab.evaluate(EXTRACT_URLS_JS, false) {
-                if let Some(value) = &eval_result.value {
-                    if let Some(json_str) = value.as_str() {
-                        if let Ok(urls) = serde_json::from_str::<Vec<String>>(json_str) {
-                            for url in urls {
-                                if url.starts_with("SITEMAP:") {
-                                    let nested_sitemap = url.trim_start_matches("SITEMAP:").to_string();
-                                    if !result.sitemaps.contains(&nested_sitemap) {
-                                        result.sitemaps.push(nested_sitemap.clone());
-                                        sitemap_queue.push(nested_sitemap);
-                                    }
-                                } else if !result.pages.contains(&url) {
-                                    result.pages.push(url);
-                                }
-                            }
+            context.session.navigate_in(&tab, &sitemap_url)?;
+            context.session.wait_for_navigation_in(&tab, context.session.default_timeout())?;
+
+            if let Ok(eval_result) = tab.evaluate(EXTRACT_URLS_JS, false)
+                && let Some(value) = &eval_result.value
+                && let Some(json_str) = value.as_str()
+                && let Ok(urls) = serde_json::from_str::<Vec<String>>(json_str)
+            {
+                for url in urls {
+                    if url.starts_with("SITEMAP:") {
+                        let nested_sitemap = url.trim_start_matches("SITEMAP:").to_string();
+                        if !result.sitemaps.contains(&nested_sitemap) {
+                            result.sitemaps.push(nested_sitemap.clone());
+                            sitemap_queue.push(nested_sitemap);
                         }
+                    } else if !result.pages.contains(&url) {
+                        result.pages.push(url);
                     }
                 }
             }
@@ -378,17 +379,15 @@ impl Tool for SitemapTool {
             pages_to_analyze.truncate(params.max_pages);
 
             for page_url in &pages_to_analyze {
-                context.session.navigate(page_url)?;
-                context.session.wait_for_navigation()?;
-
-                if let Ok(eval_result) = tab.evaluate(STRUCTURE_JS, false) {
-                    if let Some(value) = &eval_result.value {
-                        if let Some(json_str) = value.as_str() {
-                            if let Ok(structure) = serde_json::from_str::<PageStructure>(json_str) {
-                                result.page_structures.push(structure);
-                            }
-                        }
-                    }
+                context.session.navigate_in(&tab, page_url)?;
+                context.session.wait_for_navigation_in(&tab, context.session.default_timeout())?;
+
+                if let Ok(eval_result) = tab.evaluate(STRUCTURE_JS, false)
+                    && let Some(value) = &eval_result.value
+                    && let Some(json_str) = value.as_str()
+                    && let Ok(structure) = serde_json::from_str::<PageStructure>(json_str)
+                {
+                    result.page_structures.push(structure);
                 }
             }
         }
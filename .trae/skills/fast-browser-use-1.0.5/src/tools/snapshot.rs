@@ -10,8 +10,31 @@ pub struct SnapshotParams {
     /// Whether to include full snapshot or incremental
     #[serde(default)]
     pub incremental: bool,
+
+    /// Wait for the page to stop changing before taking the snapshot (default: false)
+    #[serde(default)]
+    pub wait_stable: bool,
+
+    /// Keep all meaningful text for context, including text that doesn't fit in an element's key (default: false)
+    #[serde(default)]
+    pub include_text: bool,
+
+    /// Append `[x,y,w,h]` bounding boxes (CSS pixels) to indexed elements (default: false)
+    #[serde(default)]
+    pub include_coordinates: bool,
+
+    /// Cut the snapshot after this many characters, at a line boundary, and end it with a comment
+    /// saying how many elements were left out (default: no limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_chars: Option<usize>,
 }
 
+/// How long the DOM must go without mutations to count as stable
+const DOM_STABLE_QUIET_MS: u64 = 500;
+
+/// Upper bound on the stability wait; the snapshot is taken anyway once it elapses
+const DOM_STABLE_TIMEOUT_MS: u64 = 5_000;
+
 /// Tool for getting an ARIA snapshot of the page in YAML format
 #[derive(Default)]
 pub struct SnapshotTool;
@@ -23,17 +46,40 @@ impl Tool for SnapshotTool {
         "snapshot"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get a snapshot of the current page with indexed interactive elements"
+    }
+
     fn execute_typed(&self, params: SnapshotParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let stable = if params.wait_stable {
+            let tab = context.tab()?;
+            let stable = context.session.wait_for_dom_stable_in(&tab, DOM_STABLE_QUIET_MS, DOM_STABLE_TIMEOUT_MS)?;
+            // A tree cached before the wait may already be outdated
+            context.dom_tree = None;
+            Some(stable)
+        } else {
+            None
+        };
+
         // Get or extract the DOM tree
         let dom = context.get_dom()?;
 
         // Generate YAML snapshot
-        let yaml_snapshot = render_aria_tree(&dom.root, RenderMode::Ai, None);
+        let options =
+            RenderOptions { include_text: params.include_text, include_coordinates: params.include_coordinates };
+        let mut yaml_snapshot = render_aria_tree_with_options(&dom.root, RenderMode::Ai, None, &options);
+        if let Some(max_chars) = params.max_chars {
+            yaml_snapshot = truncate_snapshot(&yaml_snapshot, max_chars);
+        }
 
         // Count interactive elements
         let interactive_count = dom.count_interactive();
 
-        let result = if params.incremental {
+        let mut result = if params.incremental {
             // TODO: Implement incremental snapshots
             serde_json::json!({
                 "full": yaml_snapshot,
@@ -45,11 +91,43 @@ impl Tool for SnapshotTool {
                 "interactive_count": interactive_count,
             })
         };
+        if let Some(stable) = stable {
+            result["stable"] = serde_json::json!(stable);
+        }
 
         Ok(ToolResult::success_with(result))
     }
 }
 
+/// Cut a rendered snapshot to at most `max_chars` characters plus a trailing marker comment.
+/// Only whole lines are kept, so the result is still valid YAML; the marker counts the elements
+/// (not text or prop lines) that were dropped.
+pub fn truncate_snapshot(yaml: &str, max_chars: usize) -> String {
+    if yaml.chars().count() <= max_chars {
+        return yaml.to_string();
+    }
+
+    let lines: Vec<&str> = yaml.lines().collect();
+    let mut used = 0;
+    let mut kept = 0;
+    for line in &lines {
+        // Every line after the first also costs its newline
+        used += line.chars().count() + usize::from(kept > 0);
+        if used > max_chars {
+            break;
+        }
+        kept += 1;
+    }
+    let omitted = lines[kept..]
+        .iter()
+        .map(|line| line.trim_start())
+        .filter(|line| line.starts_with("- ") && !line.starts_with("- text:") && !line.starts_with("- /"))
+        .count();
+
+    let marker = format!("# ... (truncated, {} elements omitted)", omitted);
+    if kept == 0 { marker } else { format!("{}\n{}", lines[..kept].join("\n"), marker) }
+}
+
 /// Rendering mode for ARIA tree
 #[derive(Debug, Clone, Copy)]
 pub enum RenderMode {
@@ -59,9 +137,33 @@ pub enum RenderMode {
     Expect,
 }
 
+/// Longest accessible name rendered in a key; YAML has a limit of 1024 characters per key
+const MAX_KEY_NAME_LEN: usize = 900;
+
+/// Optional extras for [`render_aria_tree_with_options`]; the defaults match [`render_aria_tree`]
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Keep text that would otherwise be dropped: accessible names too long for a key are
+    /// rendered as a `text` child instead of being omitted
+    pub include_text: bool,
+
+    /// Append the bounding box of each indexed element to its key as `[x,y,w,h]`
+    pub include_coordinates: bool,
+}
+
 /// Render an ARIA tree to YAML format
 /// Based on Playwright's renderAriaTree function
 pub fn render_aria_tree(root: &AriaNode, mode: RenderMode, previous: Option<&AriaNode>) -> String {
+    render_aria_tree_with_options(root, mode, previous, &RenderOptions::default())
+}
+
+/// Render an ARIA tree to YAML format with extra content selected by `options`
+pub fn render_aria_tree_with_options(
+    root: &AriaNode,
+    mode: RenderMode,
+    previous: Option<&AriaNode>,
+    options: &RenderOptions,
+) -> String {
     let mut lines = Vec::new();
 
     let render_cursor_pointer = matches!(mode, RenderMode::Ai);
@@ -72,7 +174,7 @@ pub fn render_aria_tree(root: &AriaNode, mode: RenderMode, previous: Option<&Ari
         &root.children
     } else {
         // Single root node case - wrap it
-        return render_single_node(root, mode, previous);
+        return render_single_node(root, mode, previous, options);
     };
 
     for node in nodes_to_render {
@@ -81,7 +183,7 @@ pub fn render_aria_tree(root: &AriaNode, mode: RenderMode, previous: Option<&Ari
                 visit_text(text, "", &mut lines);
             }
             AriaChild::Node(node) => {
-                visit(node, "", render_cursor_pointer, render_active, &mut lines, previous);
+                visit(node, "", render_cursor_pointer, render_active, options, &mut lines, previous);
             }
         }
     }
@@ -89,12 +191,17 @@ pub fn render_aria_tree(root: &AriaNode, mode: RenderMode, previous: Option<&Ari
     lines.join("\n")
 }
 
-fn render_single_node(root: &AriaNode, mode: RenderMode, previous: Option<&AriaNode>) -> String {
+fn render_single_node(
+    root: &AriaNode,
+    mode: RenderMode,
+    previous: Option<&AriaNode>,
+    options: &RenderOptions,
+) -> String {
     let mut lines = Vec::new();
     let render_cursor_pointer = matches!(mode, RenderMode::Ai);
     let render_active = matches!(mode, RenderMode::Ai);
 
-    visit(root, "", render_cursor_pointer, render_active, &mut lines, previous);
+    visit(root, "", render_cursor_pointer, render_active, options, &mut lines, previous);
 
     lines.join("\n")
 }
@@ -111,20 +218,26 @@ fn visit(
     indent: &str,
     render_cursor_pointer: bool,
     render_active: bool,
+    options: &RenderOptions,
     lines: &mut Vec<String>,
     _previous: Option<&AriaNode>,
 ) {
     // Create the key (role + name + attributes)
-    let key = create_key(aria_node, render_cursor_pointer, render_active);
+    let key = create_key(aria_node, render_cursor_pointer, render_active, options);
     let escaped_key = format!("{}- {}", indent, yaml_escape_key_if_needed(&key));
 
+    // A name too long for the key is kept as text when asked to
+    let overflow_name = (options.include_text && aria_node.name.len() > MAX_KEY_NAME_LEN).then_some(&aria_node.name);
+
     // Get single inlined text child if applicable
     let single_text_child = get_single_inlined_text_child(aria_node);
 
-    if aria_node.children.is_empty() && aria_node.props.is_empty() {
+    if overflow_name.is_none() && aria_node.children.is_empty() && aria_node.props.is_empty() {
         // Leaf node without children or props
         lines.push(escaped_key);
-    } else if let Some(text) = single_text_child {
+    } else if overflow_name.is_none()
+        && let Some(text) = single_text_child
+    {
         // Leaf node with just text inside
         lines.push(format!("{}: {}", escaped_key, yaml_escape_value_if_needed(&text)));
     } else {
@@ -138,6 +251,9 @@ fn visit(
 
         // Render children
         let child_indent = format!("{}  ", indent);
+        if let Some(name) = overflow_name {
+            visit_text(name, &child_indent, lines);
+        }
         let in_cursor_pointer = aria_node.index.is_some() && render_cursor_pointer && aria_node.has_pointer_cursor();
 
         for child in &aria_node.children {
@@ -151,6 +267,7 @@ fn visit(
                         &child_indent,
                         render_cursor_pointer && !in_cursor_pointer,
                         render_active,
+                        options,
                         lines,
                         None,
                     );
@@ -160,12 +277,16 @@ fn visit(
     }
 }
 
-fn create_key(aria_node: &AriaNode, render_cursor_pointer: bool, render_active: bool) -> String {
+fn create_key(
+    aria_node: &AriaNode,
+    render_cursor_pointer: bool,
+    render_active: bool,
+    options: &RenderOptions,
+) -> String {
     let mut key = aria_node.role.clone();
 
     // Add name if present and not too long
-    if !aria_node.name.is_empty() && aria_node.name.len() <= 900 {
-        // YAML has a limit of 1024 characters per key
+    if !aria_node.name.is_empty() && aria_node.name.len() <= MAX_KEY_NAME_LEN {
         let name = &aria_node.name;
         // Simple stringification (no regex handling for now)
         key.push(' ');
@@ -213,19 +334,36 @@ fn create_key(aria_node: &AriaNode, render_cursor_pointer: bool, render_active:
     if let Some(index) = aria_node.index {
         key.push_str(&format!(" [index={}]", index));
 
+        if let Some(element_ref) = &aria_node.element_ref {
+            key.push_str(&format!(" [ref={}]", element_ref));
+        }
+
         if render_cursor_pointer && aria_node.has_pointer_cursor() {
             key.push_str(" [cursor=pointer]");
         }
+
+        if options.include_coordinates
+            && let Some(rect) = &aria_node.box_info.rect
+        {
+            key.push_str(&format!(
+                " [{},{},{},{}]",
+                rect.x.round(),
+                rect.y.round(),
+                rect.width.round(),
+                rect.height.round()
+            ));
+        }
     }
 
     key
 }
 
 fn get_single_inlined_text_child(aria_node: &AriaNode) -> Option<String> {
-    if aria_node.children.len() == 1 && aria_node.props.is_empty() {
-        if let AriaChild::Text(text) = &aria_node.children[0] {
-            return Some(text.clone());
-        }
+    if aria_node.children.len() == 1
+        && aria_node.props.is_empty()
+        && let AriaChild::Text(text) = &aria_node.children[0]
+    {
+        return Some(text.clone());
     }
     None
 }
@@ -233,6 +371,7 @@ fn get_single_inlined_text_child(aria_node: &AriaNode) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dom::element::Rect;
 
     #[test]
     fn test_render_simple_tree() {
@@ -315,10 +454,72 @@ mod tests {
         assert!(yaml.contains("[level=1]"));
     }
 
+    #[test]
+    fn test_render_include_text() {
+        let description = format!("Terms: {}", "read carefully ".repeat(70));
+        let mut root = AriaNode::fragment();
+        root.children.push(AriaChild::Node(Box::new(AriaNode::new("generic", description.as_str()))));
+
+        // By default the over-long name is dropped from the key and lost
+        let yaml = render_aria_tree(&root, RenderMode::Ai, None);
+        assert_eq!(yaml, "- generic");
+
+        let options = RenderOptions { include_text: true, ..Default::default() };
+        let yaml = render_aria_tree_with_options(&root, RenderMode::Ai, None, &options);
+        assert_eq!(yaml, format!("- generic:\n  - text: {}", yaml_escape_value_if_needed(&description)));
+        // The ": " in the text forces quoting, so the YAML stays valid
+        assert!(yaml.contains("- text: \"Terms: read carefully"));
+    }
+
+    #[test]
+    fn test_render_include_coordinates() {
+        let mut button = AriaNode::new("button", "Save").with_index(0);
+        button.box_info.rect = Some(Rect { x: 10.4, y: 20.6, width: 80.0, height: 24.0 });
+        let mut root = AriaNode::fragment();
+        root.children.push(AriaChild::Node(Box::new(button)));
+        // Only indexed elements are annotated
+        root.children.push(AriaChild::Node(Box::new(AriaNode::new("paragraph", "Note"))));
+
+        assert!(!render_aria_tree(&root, RenderMode::Ai, None).contains("[10,21,80,24]"));
+
+        let options = RenderOptions { include_coordinates: true, ..Default::default() };
+        let yaml = render_aria_tree_with_options(&root, RenderMode::Ai, None, &options);
+        assert_eq!(yaml, "- 'button \"Save\" [index=0] [10,21,80,24]'\n- 'paragraph \"Note\"'");
+    }
+
     #[test]
     fn test_empty_snapshot() {
         let root = AriaNode::fragment();
         let yaml = render_aria_tree(&root, RenderMode::Ai, None);
         assert_eq!(yaml.trim(), "");
     }
+
+    #[test]
+    fn test_truncate_snapshot_keeps_whole_lines() {
+        let mut root = AriaNode::fragment();
+        let mut list = AriaNode::new("list", "");
+        for i in 0..20 {
+            let mut item = AriaNode::new("listitem", "");
+            item.children.push(AriaChild::Text(format!("Item number {}", i)));
+            list.children.push(AriaChild::Node(Box::new(item)));
+        }
+        root.children.push(AriaChild::Node(Box::new(list)));
+        root.children.push(AriaChild::Node(Box::new(AriaNode::new("button", "Done").with_index(0))));
+        let yaml = render_aria_tree(&root, RenderMode::Ai, None);
+        let original: Vec<&str> = yaml.lines().collect();
+
+        for max_chars in 0..yaml.len() {
+            let truncated = truncate_snapshot(&yaml, max_chars);
+            let (content, marker) = truncated.rsplit_once('\n').unwrap_or(("", &truncated));
+            assert!(content.chars().count() <= max_chars);
+            // Every kept line is a whole line of the original, in order
+            let kept: Vec<&str> = content.lines().collect();
+            assert_eq!(kept, original[..kept.len()]);
+
+            let omitted = original.len() - kept.len();
+            assert_eq!(marker, format!("# ... (truncated, {} elements omitted)", omitted));
+        }
+
+        assert_eq!(truncate_snapshot(&yaml, yaml.len()), yaml);
+    }
 }
@@ -0,0 +1,102 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, utils::with_retry}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the submit_form tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SubmitFormParams {
+    /// CSS selector of the form or of any element inside it (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+}
+
+/// Tool for submitting the form an element belongs to, without hunting for its submit button
+#[derive(Default)]
+pub struct SubmitFormTool;
+
+/// Submit the element's form as a user would: `requestSubmit()` validates the form and fires the
+/// submit event, so page handlers run. Older browsers get the event dispatched by hand before
+/// `submit()`. Everything is read and called through the prototype, since inputs named e.g.
+/// `action` or `submit` shadow the form's own properties.
+const SUBMIT_FORM_JS: &str = "function() {
+    const form = this instanceof HTMLFormElement ? this : (this.form || this.closest('form'));
+    if (!form) return null;
+    const proto = HTMLFormElement.prototype;
+    const read = (name) => Object.getOwnPropertyDescriptor(proto, name).get.call(form);
+    const action = read('action');
+    const method = read('method');
+    if (typeof proto.requestSubmit === 'function') {
+        proto.requestSubmit.call(form);
+    } else if (form.dispatchEvent(new Event('submit', { bubbles: true, cancelable: true }))) {
+        proto.submit.call(form);
+    }
+    return JSON.stringify({ action, method });
+}";
+
+impl Tool for SubmitFormTool {
+    type Params = SubmitFormParams;
+
+    fn name(&self) -> &str {
+        "submit_form"
+    }
+
+    fn description(&self) -> &str {
+        "Submit the form enclosing an element specified by CSS selector or index"
+    }
+
+    fn execute_typed(&self, params: SubmitFormParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Validate that exactly one selector method is provided
+        match (&params.selector, &params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "submit_form".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "submit_form".to_string(),
+                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let tab = context.tab()?;
+        let (element, css_selector) = if let Some(selector) = params.selector {
+            (context.session.find_element(&tab, &selector)?, selector)
+        } else if let Some(index) = params.index {
+            with_retry(context, index, |context, selector| {
+                Ok((context.session.find_element(&tab, selector)?, selector.to_string()))
+            })?
+        } else {
+            unreachable!("Validation above ensures one field is Some")
+        };
+
+        let result = element.call_js_fn(SUBMIT_FORM_JS, vec![], false).map_err(|e| {
+            BrowserError::ToolExecutionFailed { tool: "submit_form".to_string(), reason: e.to_string() }
+        })?;
+        // The form's action and method come back as a JSON string; null means there was no form
+        let form = match result.value {
+            Some(serde_json::Value::String(json_str)) => serde_json::from_str::<serde_json::Value>(&json_str).ok(),
+            _ => None,
+        };
+
+        match form {
+            Some(form) => Ok(ToolResult::success_with(serde_json::json!({
+                "selector": css_selector,
+                "action": form["action"],
+                "method": form["method"],
+            }))),
+            None => Err(BrowserError::ToolExecutionFailed {
+                tool: "submit_form".to_string(),
+                reason: format!("Element '{}' is not inside a form", css_selector),
+            }),
+        }
+    }
+}
@@ -21,6 +21,10 @@ impl Tool for SwitchTabTool {
         "switch_tab"
     }
 
+    fn description(&self) -> &str {
+        "Switch to a specific tab by index"
+    }
+
     fn execute_typed(&self, params: SwitchTabParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Get all tabs to validate index
         let tabs = context.session.get_tabs()?;
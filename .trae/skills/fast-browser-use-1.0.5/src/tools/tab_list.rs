@@ -16,9 +16,27 @@ pub struct TabInfo {
     pub url: String,
 }
 
-/// Parameters for the tab_list tool (no parameters needed)
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct TabListParams {}
+/// Parameters for the tab_list tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TabListParams {
+    /// Only list tabs whose title contains this text (case-insensitive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_contains: Option<String>,
+
+    /// Only list tabs whose URL contains this text (case-insensitive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_contains: Option<String>,
+}
+
+impl TabListParams {
+    /// Whether `tab` passes both filters
+    fn matches(&self, tab: &TabInfo) -> bool {
+        let contains = |text: &str, part: &Option<String>| {
+            part.as_ref().is_none_or(|part| text.to_lowercase().contains(&part.to_lowercase()))
+        };
+        contains(&tab.title, &self.title_contains) && contains(&tab.url, &self.url_contains)
+    }
+}
 
 /// Tool for listing all browser tabs
 #[derive(Default)]
@@ -31,7 +49,15 @@ impl Tool for TabListTool {
         "tab_list"
     }
 
-    fn execute_typed(&self, _params: TabListParams, context: &mut ToolContext) -> Result<ToolResult> {
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Get the list of browser tabs with their titles and URLs, optionally filtered by title or URL"
+    }
+
+    fn execute_typed(&self, params: TabListParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Get all tabs
         let tabs = context.session.get_tabs()?;
         let active_tab = context.session.tab()?;
@@ -52,25 +78,54 @@ impl Tool for TabListTool {
         }
 
         // Build summary text
+        let total = tab_list.len();
         let active_index = tab_list.iter().position(|t| t.active).unwrap_or(0);
-        let active_info = &tab_list[active_index];
+        let active_title = tab_list.get(active_index).map(|t| t.title.clone()).unwrap_or_default();
+
+        // Filtered tabs keep their index so it can be passed to switch_tab or close_tab
+        let filtered = params.title_contains.is_some() || params.url_contains.is_some();
+        tab_list.retain(|tab| params.matches(tab));
 
-        let summary = if !tab_list.is_empty() {
-            let all_tabs_str = tab_list
+        let summary = if total == 0 {
+            "No tabs available".to_string()
+        } else {
+            let tabs_str = tab_list
                 .iter()
                 .map(|tab| format!("[{}] Title: {} (URL: {})", tab.index, tab.title, tab.url))
                 .collect::<Vec<_>>()
                 .join("\n");
+            let heading = if filtered { "Matching Tabs" } else { "All Tabs" };
 
-            format!("Current Tab: [{}] {}\nAll Tabs:\n{}", active_index, active_info.title, all_tabs_str)
-        } else {
-            "No tabs available".to_string()
+            format!("Current Tab: [{}] {}\n{}:\n{}", active_index, active_title, heading, tabs_str)
         };
 
         Ok(ToolResult::success_with(serde_json::json!({
             "tab_list": tab_list,
             "count": tab_list.len(),
+            "total": total,
             "summary": summary
         })))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_list_params_filter() {
+        let tab = TabInfo {
+            index: 2,
+            active: false,
+            title: "Pull Requests · Example".to_string(),
+            url: "https://github.com/example/pulls".to_string(),
+        };
+        assert!(TabListParams::default().matches(&tab));
+
+        let by_title = TabListParams { title_contains: Some("pull requests".to_string()), url_contains: None };
+        assert!(by_title.matches(&tab));
+
+        let both = TabListParams { title_contains: Some("Pull".to_string()), url_contains: Some("gitlab".to_string()) };
+        assert!(!both.matches(&tab));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the set_timezone tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SetTimezoneParams {
+    /// IANA timezone ID such as "America/New_York" or "Europe/Berlin"
+    pub timezone: String,
+}
+
+/// Tool for emulating a browser timezone
+#[derive(Default)]
+pub struct SetTimezoneTool;
+
+impl Tool for SetTimezoneTool {
+    type Params = SetTimezoneParams;
+
+    fn name(&self) -> &str {
+        "set_timezone"
+    }
+
+    fn description(&self) -> &str {
+        "Emulate a timezone (IANA ID such as America/New_York) for dates and times shown by the page"
+    }
+
+    fn execute_typed(&self, params: SetTimezoneParams, context: &mut ToolContext) -> Result<ToolResult> {
+        context.session.set_timezone(&params.timezone)?;
+
+        Ok(ToolResult::success_with(serde_json::json!({ "timezone": params.timezone })))
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{thread, time::Duration};
+
+/// Parameters for the type_text tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TypeTextParams {
+    /// Text to type into whatever currently has focus
+    pub text: String,
+
+    /// Pause in milliseconds between characters. When set, each character is typed as key
+    /// events (keydown/keyup) like a person typing; otherwise the text is inserted at once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay_ms: Option<u64>,
+}
+
+/// Tool for typing text into the focused element (e.g. a contenteditable or a game canvas)
+/// without selecting an element first, unlike the input tool
+#[derive(Default)]
+pub struct TypeTextTool;
+
+impl Tool for TypeTextTool {
+    type Params = TypeTextParams;
+
+    fn name(&self) -> &str {
+        "type_text"
+    }
+
+    fn description(&self) -> &str {
+        "Type text into the currently focused element"
+    }
+
+    fn execute_typed(&self, params: TypeTextParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let failed = |e: anyhow::Error| BrowserError::ToolExecutionFailed {
+            tool: "type_text".to_string(),
+            reason: e.to_string(),
+        };
+
+        let method = match params.delay_ms.filter(|&delay| delay > 0) {
+            Some(delay) => {
+                let mut buffer = [0u8; 4];
+                for (i, c) in params.text.chars().enumerate() {
+                    if i > 0 {
+                        thread::sleep(Duration::from_millis(delay));
+                    }
+                    tab.type_str(c.encode_utf8(&mut buffer)).map_err(failed)?;
+                }
+                "key_events"
+            }
+            None => {
+                tab.send_character(&params.text).map_err(failed)?;
+                "insert_text"
+            }
+        };
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "typed": params.text.chars().count(),
+            "method": method,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_text_params_default_delay() {
+        let params: TypeTextParams = serde_json::from_value(serde_json::json!({ "text": "hello" })).unwrap();
+        assert_eq!(params.text, "hello");
+        assert!(params.delay_ms.is_none());
+    }
+}
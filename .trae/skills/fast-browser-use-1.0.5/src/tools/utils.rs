@@ -1,3 +1,13 @@
+use crate::{error::{BrowserError, Result},
+            tools::ToolContext};
+use std::{thread, time::Duration};
+
+/// Number of attempts made to act on an index-resolved element before giving up
+pub(crate) const ELEMENT_RETRY_ATTEMPTS: usize = 3;
+
+/// Base delay between attempts; grows linearly with each retry
+const ELEMENT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
 /// Normalize an incomplete URL by adding missing protocol and handling common patterns
 pub fn normalize_url(url: &str) -> String {
     let trimmed = url.trim();
@@ -35,6 +45,85 @@ pub fn normalize_url(url: &str) -> String {
     format!("https://www.{}.com", trimmed)
 }
 
+/// Encode a string as a JavaScript string literal, safe to splice into evaluated code
+pub(crate) fn js_string(s: &str) -> String {
+    serde_json::to_string(s).expect("serializing a string never fails")
+}
+
+/// Resolve an element index to a CSS selector and run `op` on it, re-extracting the DOM and
+/// re-resolving the index when the element can no longer be found (e.g. the page re-rendered
+/// between the snapshot and the action).
+pub(crate) fn with_retry<T, F>(context: &mut ToolContext, index: usize, op: F) -> Result<T>
+where
+    F: FnMut(&ToolContext, &str) -> Result<T>,
+{
+    retry_stale(
+        context,
+        ELEMENT_RETRY_ATTEMPTS,
+        ELEMENT_RETRY_BACKOFF,
+        |context, attempt| {
+            if attempt > 0 {
+                // Drop the cached tree so get_dom() extracts a fresh one
+                context.dom_tree = None;
+            }
+            context
+                .get_dom()?
+                .get_selector(index)
+                .cloned()
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))
+        },
+        op,
+    )
+}
+
+/// Like [`with_retry`], but resolves a stable element ref instead of a positional index. The
+/// ref survives re-snapshots, so a retry finds the same element even when indices have shifted.
+pub(crate) fn with_ref_retry<T, F>(context: &mut ToolContext, element_ref: &str, op: F) -> Result<T>
+where
+    F: FnMut(&ToolContext, &str) -> Result<T>,
+{
+    retry_stale(
+        context,
+        ELEMENT_RETRY_ATTEMPTS,
+        ELEMENT_RETRY_BACKOFF,
+        |context, attempt| {
+            if attempt > 0 {
+                context.dom_tree = None;
+            }
+            context
+                .get_dom()?
+                .get_selector_by_ref(element_ref)
+                .cloned()
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with ref '{}'", element_ref)))
+        },
+        op,
+    )
+}
+
+/// Retry loop behind [`with_retry`] and [`with_ref_retry`]. Only `ElementNotFound` errors are retried; any other error
+/// is returned immediately.
+fn retry_stale<S, T, R, F>(state: &mut S, attempts: usize, backoff: Duration, mut resolve: R, mut op: F) -> Result<T>
+where
+    R: FnMut(&mut S, usize) -> Result<String>,
+    F: FnMut(&S, &str) -> Result<T>,
+{
+    let mut attempt = 0;
+    loop {
+        let result = resolve(state, attempt).and_then(|selector| op(state, &selector));
+        match result {
+            Err(BrowserError::ElementNotFound(reason)) => {
+                attempt += 1;
+                if attempt >= attempts {
+                    return Err(BrowserError::ElementNotFound(format!("{} (after {} attempts)", reason, attempts)));
+                }
+                log::debug!("Element lookup failed (attempt {}/{}), retrying: {}", attempt, attempts, reason);
+                thread::sleep(backoff * attempt as u32);
+            }
+            other => return other,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,4 +178,93 @@ mod tests {
         assert_eq!(normalize_url("  example.com  "), "https://example.com");
         assert_eq!(normalize_url("  https://example.com  "), "https://example.com");
     }
+
+    #[test]
+    fn test_retry_stale_succeeds_on_second_attempt() {
+        // The first resolution yields a stale selector; after a refresh the element exists
+        let mut resolved = Vec::new();
+        let result = retry_stale(
+            &mut resolved,
+            ELEMENT_RETRY_ATTEMPTS,
+            Duration::ZERO,
+            |resolved, attempt| {
+                let selector = if attempt == 0 { "#stale" } else { "#fresh" };
+                resolved.push(selector.to_string());
+                Ok(selector.to_string())
+            },
+            |_, selector| match selector {
+                "#fresh" => Ok(selector.to_string()),
+                _ => Err(BrowserError::ElementNotFound(format!("Element '{}' not found", selector))),
+            },
+        );
+
+        assert_eq!(result.unwrap(), "#fresh");
+        assert_eq!(resolved, vec!["#stale", "#fresh"]);
+    }
+
+    #[test]
+    fn test_retry_stale_gives_up_after_max_attempts() {
+        let mut calls = 0;
+        let result: Result<()> = retry_stale(
+            &mut calls,
+            ELEMENT_RETRY_ATTEMPTS,
+            Duration::ZERO,
+            |calls, _| {
+                *calls += 1;
+                Ok("#missing".to_string())
+            },
+            |_, selector| Err(BrowserError::ElementNotFound(selector.to_string())),
+        );
+
+        assert!(matches!(result, Err(BrowserError::ElementNotFound(_))));
+        assert_eq!(calls, ELEMENT_RETRY_ATTEMPTS);
+    }
+
+    #[test]
+    fn test_retry_stale_does_not_retry_other_errors() {
+        let mut calls = 0;
+        let result: Result<()> = retry_stale(
+            &mut calls,
+            ELEMENT_RETRY_ATTEMPTS,
+            Duration::ZERO,
+            |calls, _| {
+                *calls += 1;
+                Ok("#button".to_string())
+            },
+            |_, _| Err(BrowserError::ToolExecutionFailed { tool: "click".to_string(), reason: "boom".to_string() }),
+        );
+
+        assert!(matches!(result, Err(BrowserError::ToolExecutionFailed { .. })));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_with_retry_re_extracts_dom_for_stale_index() {
+        use crate::browser::{BrowserSession, LaunchOptions};
+
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        session.navigate("data:text/html,<button id='go'>Go</button>").expect("Failed to navigate");
+        session.wait_for_navigation().expect("Failed to wait for navigation");
+
+        let mut context = ToolContext::new(&session);
+        let dom = context.get_dom().expect("Failed to extract DOM");
+        let index = dom.selectors.iter().position(|s| !s.is_empty()).expect("Button should be indexed");
+        let fresh_selector = dom.selectors[index].clone();
+
+        // Simulate a re-render between snapshot and action: the cached index now points nowhere
+        context.dom_tree.as_mut().unwrap().selectors[index] = "#gone".to_string();
+
+        let mut tried = Vec::new();
+        let selector = with_retry(&mut context, index, |context, selector| {
+            tried.push(selector.to_string());
+            let tab = context.session.tab()?;
+            context.session.find_element(&tab, selector)?;
+            Ok(selector.to_string())
+        })
+        .expect("Retry should re-resolve the index against a fresh DOM");
+
+        assert_eq!(selector, fresh_selector);
+        assert_eq!(tried, vec!["#gone".to_string(), fresh_selector]);
+    }
 }
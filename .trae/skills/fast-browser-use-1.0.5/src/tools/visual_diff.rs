@@ -0,0 +1,172 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use image::{Rgba, RgbaImage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+
+/// Largest per-channel difference still treated as the same pixel, so anti-aliasing
+/// and compression noise don't count as changes
+const CHANNEL_TOLERANCE: u8 = 16;
+
+/// Parameters for the visual_diff tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VisualDiffParams {
+    /// Path to the baseline PNG to compare the current viewport against
+    pub baseline_path: String,
+
+    /// Maximum percentage of differing pixels (0-100) for the comparison to pass
+    #[serde(default)]
+    pub threshold: f64,
+
+    /// Path to write a diff image with changed pixels in red
+    pub output_diff_path: Option<String>,
+}
+
+/// Tool for comparing the current viewport against a baseline screenshot
+#[derive(Default)]
+pub struct VisualDiffTool;
+
+impl Tool for VisualDiffTool {
+    type Params = VisualDiffParams;
+
+    fn name(&self) -> &str {
+        "visual_diff"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Compare a screenshot of the current viewport against a baseline image"
+    }
+
+    fn execute_typed(&self, params: VisualDiffParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if !(0.0..=100.0).contains(&params.threshold) {
+            return Err(BrowserError::InvalidArgument(format!(
+                "threshold must be a percentage between 0 and 100, got {}",
+                params.threshold
+            )));
+        }
+
+        let baseline = image::open(&params.baseline_path)
+            .map_err(|e| {
+                BrowserError::InvalidArgument(format!("Failed to load baseline '{}': {}", params.baseline_path, e))
+            })?
+            .to_rgba8();
+
+        let screenshot_data = context
+            .tab()?
+            .capture_screenshot(
+                headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
+                None,
+                None,
+                true,
+            )
+            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?;
+        let current = image::load_from_memory(&screenshot_data)
+            .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to load screenshot image: {}", e)))?
+            .to_rgba8();
+
+        let (differing, diff) = compare_images(&baseline, &current);
+        let total = (diff.width() as u64 * diff.height() as u64).max(1);
+        let mismatch_percentage = differing as f64 * 100.0 / total as f64;
+
+        let mut result = serde_json::json!({
+            "mismatch_percentage": mismatch_percentage,
+            "differing_pixels": differing,
+            "total_pixels": total,
+            "threshold": params.threshold,
+            "passed": mismatch_percentage <= params.threshold,
+            "baseline_size": [baseline.width(), baseline.height()],
+            "current_size": [current.width(), current.height()],
+        });
+
+        if let Some(path) = params.output_diff_path {
+            let mut bytes: Vec<u8> = Vec::new();
+            diff.write_to(&mut Cursor::new(&mut bytes), image::ImageOutputFormat::Png)
+                .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to encode diff image: {}", e)))?;
+            std::fs::write(&path, &bytes)
+                .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to save diff image: {}", e)))?;
+            result["diff_path"] = serde_json::Value::String(path);
+        }
+
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+/// Compare two images pixel by pixel, returning the number of differing pixels and a diff image.
+///
+/// The diff covers the larger of the two sizes: unchanged pixels are a faded copy of the
+/// baseline, changed pixels are red, and pixels outside either image count as changed.
+fn compare_images(baseline: &RgbaImage, current: &RgbaImage) -> (u64, RgbaImage) {
+    let width = baseline.width().max(current.width());
+    let height = baseline.height().max(current.height());
+    let mut diff = RgbaImage::new(width, height);
+    let mut differing = 0;
+
+    for (x, y, out) in diff.enumerate_pixels_mut() {
+        let before = (x < baseline.width() && y < baseline.height()).then(|| baseline.get_pixel(x, y));
+        let after = (x < current.width() && y < current.height()).then(|| current.get_pixel(x, y));
+
+        match (before, after) {
+            (Some(before), Some(after)) if pixels_match(before, after) => *out = faded(before),
+            _ => {
+                differing += 1;
+                *out = Rgba([255, 0, 0, 255]);
+            }
+        }
+    }
+
+    (differing, diff)
+}
+
+fn pixels_match(a: &Rgba<u8>, b: &Rgba<u8>) -> bool {
+    a.0.iter().zip(b.0.iter()).all(|(a, b)| a.abs_diff(*b) <= CHANNEL_TOLERANCE)
+}
+
+/// Grayscale, lightened version of a pixel so highlighted changes stand out
+fn faded(pixel: &Rgba<u8>) -> Rgba<u8> {
+    let [r, g, b, _] = pixel.0;
+    let luma = (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000;
+    let light = (255 - (255 - luma) / 3) as u8;
+    Rgba([light, light, light, 255])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_identical_images() {
+        let image = RgbaImage::from_pixel(4, 3, Rgba([10, 20, 30, 255]));
+        let (differing, diff) = compare_images(&image, &image);
+        assert_eq!(differing, 0);
+        assert_eq!(diff.dimensions(), (4, 3));
+        assert!(diff.pixels().all(|p| *p != Rgba([255, 0, 0, 255])));
+    }
+
+    #[test]
+    fn test_compare_counts_changes_above_tolerance() {
+        let baseline = RgbaImage::from_pixel(2, 2, Rgba([100, 100, 100, 255]));
+        let mut current = baseline.clone();
+        current.put_pixel(0, 0, Rgba([100 + CHANNEL_TOLERANCE, 100, 100, 255]));
+        current.put_pixel(1, 1, Rgba([200, 100, 100, 255]));
+
+        let (differing, diff) = compare_images(&baseline, &current);
+        assert_eq!(differing, 1);
+        assert_eq!(*diff.get_pixel(1, 1), Rgba([255, 0, 0, 255]));
+        assert_ne!(*diff.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_compare_different_sizes() {
+        let baseline = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 255]));
+        let current = RgbaImage::from_pixel(3, 2, Rgba([0, 0, 0, 255]));
+
+        let (differing, diff) = compare_images(&baseline, &current);
+        assert_eq!(diff.dimensions(), (3, 2));
+        assert_eq!(differing, 2);
+    }
+}
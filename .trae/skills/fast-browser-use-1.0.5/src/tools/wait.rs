@@ -9,14 +9,13 @@ pub struct WaitParams {
     /// CSS selector to wait for
     pub selector: String,
 
-    /// Timeout in milliseconds (default: 30000)
-    #[serde(default = "default_timeout")]
-    pub timeout_ms: u64,
+    /// Timeout in milliseconds (default: session default timeout)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
 }
 
-fn default_timeout() -> u64 {
-    30000
-}
+/// How often the selector is checked while waiting
+const WAIT_POLL_MS: u64 = 100;
 
 #[derive(Default)]
 pub struct WaitTool;
@@ -28,18 +27,34 @@ impl Tool for WaitTool {
         "wait"
     }
 
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Wait for an element to appear on the page"
+    }
+
     fn execute_typed(&self, params: WaitParams, context: &mut ToolContext) -> Result<ToolResult> {
         let start = std::time::Instant::now();
+        let timeout = params.timeout_ms.map(Duration::from_millis).unwrap_or_else(|| context.session.default_timeout());
+
+        let selector_json = serde_json::to_string(&params.selector).expect("serializing CSS selector never fails");
+        let js_expr = format!("document.querySelector({}) !== null", selector_json);
 
         context
             .session
-            .tab()?
-            .wait_for_element_with_custom_timeout(&params.selector, Duration::from_millis(params.timeout_ms))
-            .map_err(|e| {
-                BrowserError::Timeout(format!(
-                    "Element '{}' not found within {} ms: {}",
-                    params.selector, params.timeout_ms, e
-                ))
+            .wait_for_function_in(&context.tab()?, &js_expr, timeout.as_millis() as u64, WAIT_POLL_MS)
+            .map_err(|e| match e {
+                BrowserError::Timeout(_) => BrowserError::Timeout(format!(
+                    "Element '{}' not found within {} ms",
+                    params.selector,
+                    timeout.as_millis()
+                )),
+                BrowserError::InvalidArgument(_) => {
+                    BrowserError::InvalidArgument(format!("Invalid CSS selector '{}'", params.selector))
+                }
+                other => other,
             })?;
 
         let elapsed = start.elapsed().as_millis() as u64;
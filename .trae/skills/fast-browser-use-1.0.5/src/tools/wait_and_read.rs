@@ -0,0 +1,77 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, utils::js_string}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Parameters for the wait_and_read tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WaitAndReadParams {
+    /// CSS selector of the element to read
+    pub selector: String,
+
+    /// Timeout in milliseconds (default: session default timeout)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+/// How often the element is checked while waiting
+const WAIT_POLL_MS: u64 = 100;
+
+/// The element's `innerText` once it is non-blank, otherwise null. Checking and reading in one
+/// evaluation means the text can't change between the two.
+const READ_TEXT_JS: &str = "(() => {
+    const element = document.querySelector(__SELECTOR__);
+    const text = element ? element.innerText : '';
+    return text.trim() ? text : null;
+})()";
+
+/// Tool for waiting until an element exists and has text, then returning the text
+#[derive(Default)]
+pub struct WaitAndReadTool;
+
+impl Tool for WaitAndReadTool {
+    type Params = WaitAndReadParams;
+
+    fn name(&self) -> &str {
+        "wait_and_read"
+    }
+
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
+    fn description(&self) -> &str {
+        "Wait for an element to appear with non-empty text, then return its text"
+    }
+
+    fn execute_typed(&self, params: WaitAndReadParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let start = Instant::now();
+        let timeout = params.timeout_ms.map(Duration::from_millis).unwrap_or_else(|| context.session.default_timeout());
+
+        let js_expr = READ_TEXT_JS.replace("__SELECTOR__", &js_string(&params.selector));
+
+        let text = context
+            .session
+            .wait_for_function_in(&context.tab()?, &js_expr, timeout.as_millis() as u64, WAIT_POLL_MS)
+            .map_err(|e| match e {
+                BrowserError::Timeout(_) => BrowserError::Timeout(format!(
+                    "Element '{}' with text not found within {} ms",
+                    params.selector,
+                    timeout.as_millis()
+                )),
+                BrowserError::InvalidArgument(_) => {
+                    BrowserError::InvalidArgument(format!("Invalid CSS selector '{}'", params.selector))
+                }
+                other => other,
+            })?;
+
+        let elapsed = start.elapsed().as_millis() as u64;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "selector": params.selector,
+            "text": text,
+            "elapsed_ms": elapsed
+        })))
+    }
+}
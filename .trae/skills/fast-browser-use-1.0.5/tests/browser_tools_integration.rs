@@ -1,6 +1,32 @@
-use browser_use::{BrowserSession, LaunchOptions,
-                  tools::{HoverParams, ScrollParams, SelectParams, Tool, ToolContext, hover::HoverTool,
-                          scroll::ScrollTool, select::SelectTool}};
+use browser_use::{BrowserError, BrowserSession, LaunchOptions, ToolRegistry,
+                  tools::{CheckParams, ClickAtParams, DescribeElementParams, DismissBannersParams,
+                          ERROR_SCREENSHOT_KEY, EvaluateParams, GrantPermissionsParams, HighlightParams,
+                          HoverCaptureParams, HoverParams, HtmlSourceParams, InjectCssParams, InteractiveListParams,
+                          MouseMoveParams, PageContextParams, ReadClipboardParams, RemoveCssParams, ResetPermissionsParams,
+                          ScrollParams, SelectParams, SubmitFormParams, Tool, ToolContext, TypeTextParams,
+                          VisualDiffParams, WaitAndReadParams, WaitParams, WriteClipboardParams,
+                          check::CheckTool,
+                          click_at::ClickAtTool,
+                          clipboard::{ReadClipboardTool, WriteClipboardTool},
+                          context::PageContextTool,
+                          describe_element::DescribeElementTool,
+                          dismiss_banners::DismissBannersTool,
+                          evaluate::EvaluateTool,
+                          highlight::HighlightTool,
+                          hover::HoverTool,
+                          hover_capture::HoverCaptureTool,
+                          html_source::HtmlSourceTool,
+                          inject_css::{InjectCssTool, RemoveCssTool},
+                          interactive_list::InteractiveListTool,
+                          mouse_move::MouseMoveTool,
+                          permissions::{GrantPermissionsTool, ResetPermissionsTool},
+                          scroll::ScrollTool,
+                          select::SelectTool,
+                          submit::SubmitFormTool,
+                          type_text::TypeTextTool,
+                          visual_diff::VisualDiffTool,
+                          wait::WaitTool,
+                          wait_and_read::WaitAndReadTool}};
 use log::info;
 
 #[test]
@@ -34,7 +60,7 @@ fn test_select_tool() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = SelectTool::default();
+    let tool = SelectTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to select an option
@@ -83,12 +109,15 @@ fn test_hover_tool() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = HoverTool::default();
+    let tool = HoverTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool
     let result = tool
-        .execute_typed(HoverParams { selector: Some("#hover-btn".to_string()), index: None }, &mut context)
+        .execute_typed(
+            HoverParams { selector: Some("#hover-btn".to_string()), index: None, emulate_human: false },
+            &mut context,
+        )
         .expect("Failed to execute hover tool");
 
     // Verify the result
@@ -124,12 +153,13 @@ fn test_scroll_tool_with_amount() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = ScrollTool::default();
+    let tool = ScrollTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to scroll down 500 pixels
-    let result =
-        tool.execute_typed(ScrollParams { amount: Some(500) }, &mut context).expect("Failed to execute scroll tool");
+    let result = tool
+        .execute_typed(ScrollParams { amount: Some(500), target: None }, &mut context)
+        .expect("Failed to execute scroll tool");
 
     // Verify the result
     assert!(result.success, "Tool execution should succeed");
@@ -164,13 +194,14 @@ fn test_scroll_tool_to_bottom() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = ScrollTool::default();
+    let tool = ScrollTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool multiple times to reach bottom
     for _ in 0..10 {
-        let result =
-            tool.execute_typed(ScrollParams { amount: None }, &mut context).expect("Failed to execute scroll tool");
+        let result = tool
+            .execute_typed(ScrollParams { amount: None, target: None }, &mut context)
+            .expect("Failed to execute scroll tool");
 
         assert!(result.success);
 
@@ -216,7 +247,7 @@ fn test_select_with_index() {
     let _dom = session.extract_dom().expect("Failed to extract DOM");
 
     // Create tool and context
-    let tool = SelectTool::default();
+    let tool = SelectTool;
     let mut context = ToolContext::new(&session);
 
     // Try to select using index (the select element should have index 0 since it's the first interactive element)
@@ -231,3 +262,872 @@ fn test_select_with_index() {
         info!("Select with index failed (may be expected if select not indexed)");
     }
 }
+
+#[test]
+#[ignore]
+fn test_evaluate_await_promise() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+
+    let tool = EvaluateTool;
+    let mut context = ToolContext::new(&session);
+
+    let code = "new Promise(resolve => setTimeout(() => resolve({ answer: 42, items: [1, 2] }), 100))";
+    let result = tool
+        .execute_typed(EvaluateParams { code: code.to_string(), await_promise: true, args: vec![] }, &mut context)
+        .expect("Failed to evaluate promise");
+
+    let data = result.data.unwrap();
+    info!("Evaluate promise result: {}", serde_json::to_string_pretty(&data).unwrap());
+    assert_eq!(data["result"], serde_json::json!({ "answer": 42, "items": [1, 2] }));
+
+    // A rejected promise surfaces as an evaluation error
+    let result = tool.execute_typed(
+        EvaluateParams { code: "Promise.reject(new Error('nope'))".to_string(), await_promise: true, args: vec![] },
+        &mut context,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+#[ignore]
+fn test_evaluate_with_args() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+
+    let tool = EvaluateTool;
+    let mut context = ToolContext::new(&session);
+
+    // The string contains quotes that would break naive interpolation
+    let params = EvaluateParams {
+        code: "return { doubled: args[0] * 2, text: args[1].toUpperCase() };".to_string(),
+        await_promise: false,
+        args: vec![serde_json::json!(21), serde_json::json!("it's \"quoted\"")],
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to evaluate with args");
+
+    let data = result.data.unwrap();
+    info!("Evaluate with args result: {}", serde_json::to_string_pretty(&data).unwrap());
+    assert_eq!(data["result"], serde_json::json!({ "doubled": 42, "text": "IT'S \"QUOTED\"" }));
+}
+
+#[test]
+#[ignore]
+fn test_wait_tool_timeout_variant() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<h1>No target here</h1>").expect("Failed to navigate");
+
+    let tool = WaitTool;
+    let mut context = ToolContext::new(&session);
+
+    let result = tool.execute_typed(WaitParams { selector: "#never".to_string(), timeout_ms: Some(100) }, &mut context);
+    assert!(matches!(result, Err(BrowserError::Timeout(_))), "Expected a timeout, got {:?}", result.err());
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_wait_and_read_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    // The result element exists right away but only gets its text later
+    let html = "<div id='result'> </div>\
+                <script>setTimeout(() => document.getElementById('result').innerText = '42 results', 300)</script>";
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    let params = WaitAndReadParams { selector: "#result".to_string(), timeout_ms: Some(5000) };
+    let data = WaitAndReadTool.execute_typed(params, &mut context).expect("Failed to wait and read").data.unwrap();
+    assert_eq!(data["text"], "42 results");
+    assert!(data["elapsed_ms"].as_u64().unwrap() >= 200);
+
+    let params = WaitAndReadParams { selector: "#never".to_string(), timeout_ms: Some(100) };
+    let result = WaitAndReadTool.execute_typed(params, &mut context);
+    assert!(matches!(result, Err(BrowserError::Timeout(_))), "Expected a timeout, got {:?}", result.err());
+}
+
+#[test]
+#[ignore]
+fn test_wait_for_navigation_timeout_variant() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    // A page that never finishes loading: the image request is held open by a non-routable address
+    session.navigate("data:text/html,<img src='http://10.255.255.1/slow.png'>").expect("Failed to navigate");
+
+    let result = session.wait_for_navigation_with_timeout(std::time::Duration::from_millis(1));
+    assert!(matches!(result, Err(BrowserError::Timeout(_))), "Expected a timeout, got {:?}", result.err());
+}
+
+#[test]
+#[ignore]
+fn test_execute_batch() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <input id="name" />
+        <button id="go" onclick="document.title = document.getElementById('name').value">Go</button>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let registry = ToolRegistry::with_defaults();
+    let mut context = ToolContext::new(&session);
+
+    let actions = vec![
+        ("input".to_string(), serde_json::json!({ "selector": "#name", "text": "batched" })),
+        ("click".to_string(), serde_json::json!({ "selector": "#go" })),
+        ("evaluate".to_string(), serde_json::json!({ "code": "document.title" })),
+    ];
+    let results = registry.execute_batch(actions, &mut context, true);
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| r.success));
+    assert_eq!(results[2].data.as_ref().unwrap()["result"], "batched");
+
+    // The first failure stops the batch unless stop_on_error is off
+    let actions = vec![
+        ("click".to_string(), serde_json::json!({ "selector": "#missing" })),
+        ("click".to_string(), serde_json::json!({ "selector": "#go" })),
+    ];
+    let results = registry.execute_batch(actions.clone(), &mut context, true);
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].success);
+    assert!(results[0].error_code.is_some());
+
+    let results = registry.execute_batch(actions, &mut context, false);
+    assert_eq!(results.len(), 2);
+    assert!(results[1].success);
+}
+
+#[test]
+#[ignore]
+fn test_batch_tool_uses_session_registry() {
+    let mut session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let observed = calls.clone();
+    session
+        .tool_registry_mut()
+        .set_observer(Box::new(move |name, _, _| observed.lock().unwrap().push(name.to_string())));
+
+    let result = session
+        .execute_tool(
+            "batch",
+            serde_json::json!({ "actions": [{ "tool": "evaluate", "params": { "code": "1 + 1" } }] }),
+        )
+        .expect("Failed to execute batch tool");
+    assert!(result.success);
+
+    // The nested action is observed before the batch itself finishes
+    assert_eq!(*calls.lock().unwrap(), vec!["evaluate".to_string(), "batch".to_string()]);
+}
+
+#[test]
+#[ignore]
+fn test_hover_capture_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <button id="tip" onmouseover="document.getElementById('tooltip').style.display = 'block'">Hover Me</button>
+        <div id="tooltip" style="display: none">Tooltip text</div>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let tool = HoverCaptureTool;
+    let mut context = ToolContext::new(&session);
+
+    let result = tool
+        .execute_typed(
+            HoverCaptureParams { selector: Some("#tip".to_string()), index: None, settle_ms: 100 },
+            &mut context,
+        )
+        .expect("Failed to execute hover_capture tool");
+
+    let data = result.data.unwrap();
+    assert_eq!(data["selector"].as_str(), Some("#tip"));
+    assert!(data["size_bytes"].as_u64().unwrap() > 0);
+    assert!(!data["image_base64"].as_str().unwrap().is_empty());
+}
+
+#[test]
+#[ignore]
+fn test_clipboard_round_trip() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("https://example.com").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+
+    WriteClipboardTool
+        .execute_typed(WriteClipboardParams { text: "copied \"text\"\nline 2".to_string() }, &mut context)
+        .expect("Failed to write clipboard");
+
+    let result =
+        ReadClipboardTool.execute_typed(ReadClipboardParams {}, &mut context).expect("Failed to read clipboard");
+    assert_eq!(result.data.unwrap()["text"], "copied \"text\"\nline 2");
+}
+
+#[test]
+#[ignore]
+fn test_capture_full_page() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true).window_size(800, 600))
+        .expect("Failed to launch browser");
+    let html =
+        r#"<body style="margin: 0"><div style="height: 3000px; background: linear-gradient(red, blue)"></div></body>"#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let png = session.capture_full_page().expect("Failed to capture full page");
+    let image = image::load_from_memory(&png).expect("Capture should be a valid image");
+    info!("Full page capture: {}x{}", image.width(), image.height());
+    assert!(image.height() >= 3000, "Capture should include content below the fold");
+
+    // The viewport is restored afterwards
+    let inner_height = session.tab().unwrap().evaluate("window.innerHeight", false).unwrap().value.unwrap();
+    assert!(inner_height.as_u64().unwrap() < 3000);
+}
+
+#[test]
+#[ignore]
+fn test_visual_diff_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true).window_size(400, 300))
+        .expect("Failed to launch browser");
+    session
+        .navigate("data:text/html,<body style=\"margin: 0; background: white\"></body>")
+        .expect("Failed to navigate");
+
+    let dir = std::env::temp_dir();
+    let baseline_path = dir.join("visual_diff_baseline.png");
+    let diff_path = dir.join("visual_diff_output.png");
+    let baseline = session
+        .tab()
+        .unwrap()
+        .capture_screenshot(headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png, None, None, true)
+        .expect("Failed to capture baseline");
+    std::fs::write(&baseline_path, baseline).expect("Failed to write baseline");
+
+    let tool = VisualDiffTool;
+    let mut context = ToolContext::new(&session);
+    let params = |threshold| VisualDiffParams {
+        baseline_path: baseline_path.to_string_lossy().into_owned(),
+        threshold,
+        output_diff_path: Some(diff_path.to_string_lossy().into_owned()),
+    };
+
+    let result = tool.execute_typed(params(0.0), &mut context).expect("Failed to diff");
+    let data = result.data.unwrap();
+    assert_eq!(data["passed"], true);
+    assert_eq!(data["differing_pixels"], 0);
+
+    // Paint the top half of the page so roughly half the pixels change
+    session
+        .tab()
+        .unwrap()
+        .evaluate("document.body.innerHTML = '<div style=\"height: 150px; background: black\"></div>'", false)
+        .expect("Failed to change page");
+
+    let result = tool.execute_typed(params(10.0), &mut context).expect("Failed to diff");
+    let data = result.data.unwrap();
+    info!("Visual diff: {}", data);
+    assert_eq!(data["passed"], false);
+    assert!(data["mismatch_percentage"].as_f64().unwrap() > 10.0);
+    assert!(image::open(&diff_path).is_ok(), "Diff image should be written");
+}
+
+#[test]
+#[ignore]
+fn test_highlight_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"<body style="margin: 0"><button id="go" style="margin: 20px; width: 100px; height: 40px">Go</button></body>"#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let tool = HighlightTool;
+    let mut context = ToolContext::new(&session);
+    let result = tool
+        .execute_typed(
+            HighlightParams { selector: Some("#go".to_string()), index: None, duration_ms: 500 },
+            &mut context,
+        )
+        .expect("Failed to highlight");
+    let data = result.data.unwrap();
+    assert_eq!(data["rect"]["width"], 100.0);
+    assert_eq!(data["rect"]["height"], 40.0);
+
+    let overlays = "document.querySelectorAll('[data-browser-use-highlight]').length";
+    let count = session.tab().unwrap().evaluate(overlays, false).unwrap().value.unwrap();
+    assert_eq!(count, 1);
+
+    // The overlay removes itself once the duration has passed
+    std::thread::sleep(std::time::Duration::from_millis(800));
+    let count = session.tab().unwrap().evaluate(overlays, false).unwrap().value.unwrap();
+    assert_eq!(count, 0);
+
+    let err = tool
+        .execute_typed(
+            HighlightParams { selector: Some("#missing".to_string()), index: None, duration_ms: 500 },
+            &mut context,
+        )
+        .unwrap_err();
+    assert!(matches!(err, BrowserError::ElementNotFound(_)));
+}
+
+#[test]
+#[ignore]
+fn test_interactive_list_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <body style="margin: 0">
+            <h1>Not interactive</h1>
+            <button id="go" style="width: 100px; height: 40px">Go</button>
+            <a href="/next">Next</a>
+            <button id="hidden" style="display: none">Hidden</button>
+        </body>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let tool = InteractiveListTool;
+    let mut context = ToolContext::new(&session);
+    let result = tool.execute_typed(InteractiveListParams {}, &mut context).expect("Failed to list elements");
+    let data = result.data.unwrap();
+    info!("Interactive list result: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    let elements = data["elements"].as_array().unwrap();
+    assert_eq!(data["count"], elements.len());
+    assert_eq!(elements.len(), 2, "Only the visible button and link should be listed");
+
+    let button = elements.iter().find(|e| e["name"] == "Go").expect("Button should be listed");
+    assert_eq!(button["role"], "button");
+    assert!(button["index"].is_u64());
+    assert_eq!(button["selector"], "#go");
+    assert_eq!(button["rect"]["width"], 100.0);
+    assert_eq!(button["rect"]["height"], 40.0);
+
+    let link = elements.iter().find(|e| e["name"] == "Next").expect("Link should be listed");
+    assert_eq!(link["role"], "link");
+    assert!(link["rect"].is_object());
+
+    assert!(elements.iter().all(|e| e["name"] != "Hidden"));
+}
+
+#[test]
+#[ignore]
+fn test_check_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <input type="checkbox" id="terms">
+        <input type="radio" name="plan" id="basic" checked>
+        <div id="changes">0</div>
+        <script>
+            document.getElementById('terms').addEventListener('change', function() {
+                const changes = document.getElementById('changes');
+                changes.textContent = Number(changes.textContent) + 1;
+            });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let tool = CheckTool;
+    let mut context = ToolContext::new(&session);
+    let check = |context: &mut ToolContext, selector: &str, checked| {
+        tool.execute_typed(CheckParams { selector: Some(selector.to_string()), index: None, checked }, context)
+            .expect("Failed to set checked state")
+            .data
+            .unwrap()
+    };
+
+    let data = check(&mut context, "#terms", true);
+    assert_eq!(data["checked"], true);
+    assert_eq!(data["changed"], true);
+
+    // Already checked, so nothing is clicked
+    let data = check(&mut context, "#terms", true);
+    assert_eq!(data["changed"], false);
+    let changes = session.tab().unwrap().evaluate("document.getElementById('changes').textContent", false).unwrap();
+    assert_eq!(changes.value.unwrap(), "1");
+
+    let data = check(&mut context, "#terms", false);
+    assert_eq!(data["checked"], false);
+
+    // Radios can't be unchecked by clicking
+    let data = check(&mut context, "#basic", false);
+    assert_eq!(data["checked"], false);
+}
+
+#[test]
+#[ignore]
+fn test_html_source_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"<!DOCTYPE html><html><body><p id="original">Original</p><script>document.body.insertAdjacentHTML('beforeend', '<p id="added">Added</p>')</script></body></html>"#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+
+    let tool = HtmlSourceTool;
+    let mut context = ToolContext::new(&session);
+
+    let rendered = tool.execute_typed(HtmlSourceParams { rendered: true }, &mut context).unwrap().data.unwrap();
+    let rendered_html = rendered["html"].as_str().unwrap();
+    assert!(rendered_html.starts_with("<!DOCTYPE html>"));
+    // Once in the script, once as the element it inserted
+    assert_eq!(rendered_html.matches(r#"id="added""#).count(), 2);
+    assert_eq!(rendered["length"], rendered_html.len());
+
+    let original = tool.execute_typed(HtmlSourceParams { rendered: false }, &mut context).unwrap().data.unwrap();
+    let original_html = original["html"].as_str().unwrap();
+    info!("Original HTML: {}", original_html);
+    assert!(original_html.contains(r#"id="original""#));
+    assert_eq!(original_html.matches(r#"id="added""#).count(), 1);
+}
+
+#[test]
+#[ignore]
+fn test_grant_and_reset_permissions() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("https://example.com").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let query = "navigator.permissions.query({ name: 'notifications' }).then(status => status.state)";
+    let state = |context: &mut ToolContext| {
+        EvaluateTool
+            .execute_typed(EvaluateParams { code: query.to_string(), await_promise: true, args: Vec::new() }, context)
+            .expect("Failed to query permission")
+            .data
+            .unwrap()["result"]
+            .clone()
+    };
+
+    let mut context = ToolContext::new(&session);
+    let result = GrantPermissionsTool
+        .execute_typed(
+            GrantPermissionsParams {
+                permissions: vec!["notifications".to_string(), "geolocation".to_string()],
+                origin: Some("https://example.com".to_string()),
+            },
+            &mut context,
+        )
+        .expect("Failed to grant permissions");
+    info!("Grant permissions result: {}", result.data.as_ref().unwrap());
+    assert_eq!(state(&mut context), "granted");
+
+    ResetPermissionsTool.execute_typed(ResetPermissionsParams {}, &mut context).expect("Failed to reset permissions");
+    assert_ne!(state(&mut context), "granted");
+
+    let unknown = GrantPermissionsTool.execute_typed(
+        GrantPermissionsParams { permissions: vec!["teleportation".to_string()], origin: None },
+        &mut context,
+    );
+    assert!(matches!(unknown, Err(BrowserError::InvalidArgument(_))));
+}
+
+#[test]
+#[ignore]
+fn test_execute_with_timeout() {
+    use std::{sync::Arc,
+              time::{Duration, Instant}};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    let session = Arc::new(session);
+
+    let registry = ToolRegistry::with_defaults();
+
+    let started = Instant::now();
+    let result = registry.execute_with_timeout(
+        "evaluate",
+        serde_json::json!({ "code": "while (true) {}" }),
+        &session,
+        Duration::from_millis(500),
+    );
+    assert!(matches!(result, Err(BrowserError::Timeout(_))), "Expected a timeout, got {:?}", result);
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    // A tool blocked on something other than JavaScript is cut off at the deadline too
+    let started = Instant::now();
+    let result = registry.execute_with_timeout(
+        "wait",
+        serde_json::json!({ "selector": "#never", "timeout_ms": 10_000 }),
+        &session,
+        Duration::from_millis(500),
+    );
+    assert!(matches!(result, Err(BrowserError::Timeout(_))), "Expected a timeout, got {:?}", result);
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    // The runaway script was terminated, so the page still responds
+    let result = registry
+        .execute_with_timeout("evaluate", serde_json::json!({ "code": "1 + 1" }), &session, Duration::from_secs(5))
+        .expect("Failed to evaluate after timeout");
+    assert!(result.metadata["duration_ms"].is_u64());
+    assert_eq!(result.data.unwrap()["result"], 2);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_click_at_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <canvas id="map" width="400" height="300" style="position: absolute; left: 0; top: 0"></canvas>
+        <script>
+            document.getElementById('map').addEventListener('mousedown', function (e) {
+                document.title = e.button + ':' + e.clientX + ',' + e.clientY;
+            });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let tool = ClickAtTool;
+    let mut context = ToolContext::new(&session);
+
+    let result = tool
+        .execute_typed(ClickAtParams { x: 120.0, y: 80.0, button: None }, &mut context)
+        .expect("Failed to click at coordinates");
+    let data = result.data.unwrap();
+    assert_eq!(data["x"], 120.0);
+    assert_eq!(data["y"], 80.0);
+    assert_eq!(session.tab().unwrap().get_title().unwrap(), "0:120,80");
+
+    tool.execute_typed(ClickAtParams { x: 10.0, y: 20.0, button: Some("right".to_string()) }, &mut context)
+        .expect("Failed to right-click at coordinates");
+    assert_eq!(session.tab().unwrap().get_title().unwrap(), "2:10,20");
+
+    let err = tool.execute_typed(ClickAtParams { x: 1.0, y: 1.0, button: Some("thumb".to_string()) }, &mut context);
+    assert!(matches!(err, Err(BrowserError::InvalidArgument(_))));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_scroll_tool_wheel_target() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <div id="panel" style="position: absolute; left: 0; top: 0; width: 300px; height: 200px; overflow-y: auto">
+            <div style="height: 2000px">Messages</div>
+        </div>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let tool = ScrollTool;
+    let mut context = ToolContext::new(&session);
+
+    let result = tool
+        .execute_typed(ScrollParams { amount: Some(300), target: Some((100.0, 100.0)) }, &mut context)
+        .expect("Failed to scroll with the wheel");
+    let data = result.data.unwrap();
+    info!("Wheel scroll result: {}", data);
+    assert!(data["scrolled"].as_i64().unwrap() > 0);
+
+    // The panel moved, the window did not
+    let panel = session.tab().unwrap().evaluate("document.getElementById('panel').scrollTop", false).unwrap();
+    assert_eq!(panel.value.unwrap().as_f64().unwrap() as i64, data["scrolled"].as_i64().unwrap());
+    let window = session.tab().unwrap().evaluate("window.scrollY", false).unwrap();
+    assert_eq!(window.value.unwrap().as_f64().unwrap(), 0.0);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_read_only_blocks_mutating_tools() {
+    let session =
+        BrowserSession::launch(LaunchOptions::new().headless(true).read_only(true)).expect("Failed to launch browser");
+    let mut context = ToolContext::new(&session);
+
+    let result = HtmlSourceTool
+        .execute_checked(HtmlSourceParams { rendered: true }, &mut context)
+        .expect("Reads should be allowed in read-only mode");
+    assert!(result.success);
+
+    let result = EvaluateTool.execute_checked(
+        EvaluateParams { code: "document.title = 'changed'".to_string(), await_promise: false, args: Vec::new() },
+        &mut context,
+    );
+    assert!(matches!(result, Err(BrowserError::InvalidArgument(ref msg)) if msg.contains("read-only mode")));
+
+    // Tools run by name (e.g. from a batch) are gated too
+    let result = session.execute_tool("navigate", serde_json::json!({ "url": "https://example.com" }));
+    assert!(matches!(result, Err(BrowserError::InvalidArgument(_))));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_registry_observer_fires() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mut registry = ToolRegistry::with_defaults();
+    let recorded = calls.clone();
+    registry.set_observer(Box::new(move |name, params, result| {
+        recorded.lock().unwrap().push((name.to_string(), params.clone(), result.success));
+    }));
+
+    let mut context = ToolContext::new(&session);
+    registry.execute("evaluate", serde_json::json!({ "code": "1 + 1" }), &mut context).expect("Failed to evaluate");
+    registry.execute("no_such_tool", serde_json::json!({}), &mut context).unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0], ("evaluate".to_string(), serde_json::json!({ "code": "1 + 1" }), true));
+    assert_eq!(calls[1].0, "no_such_tool");
+    assert!(!calls[1].2);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_capture_on_error_attaches_screenshot() {
+    let options = LaunchOptions::new().headless(true).capture_on_error(true);
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    session.navigate("data:text/html,<h1>No buttons here</h1>").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    // A missing element fails the click; the failure comes back as a result with a screenshot
+    let registry = ToolRegistry::with_defaults();
+    let mut context = ToolContext::new(&session);
+    let result = registry
+        .execute("click", serde_json::json!({ "selector": "#missing" }), &mut context)
+        .expect("Failure should be returned as a result");
+    assert!(!result.success);
+    assert_eq!(result.error_code.as_deref(), Some("element_not_found"));
+    let screenshot = result.metadata[ERROR_SCREENSHOT_KEY].as_str().expect("No screenshot attached");
+    assert!(screenshot.starts_with("iVBORw0KGgo"), "Not a base64 PNG");
+
+    // Without the option the error is returned as is
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let mut context = ToolContext::new(&session);
+    let err = registry.execute("click", serde_json::json!({ "selector": "#missing" }), &mut context).unwrap_err();
+    assert!(matches!(err, BrowserError::ElementNotFound(_)));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_mouse_move_tool_continues_from_last_position() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <script>
+            window.moves = [];
+            document.addEventListener('mousemove', function (e) { window.moves.push([e.clientX, e.clientY]); });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mut context = ToolContext::new(&session);
+    ClickAtTool
+        .execute_typed(ClickAtParams { x: 100.0, y: 100.0, button: None }, &mut context)
+        .expect("Failed to click at coordinates");
+    assert_eq!(session.mouse_position(), Some((100.0, 100.0)));
+
+    let result = MouseMoveTool
+        .execute_typed(MouseMoveParams { x: 200.0, y: 150.0, steps: Some(5) }, &mut context)
+        .expect("Failed to move mouse");
+    let data = result.data.unwrap();
+    assert_eq!(data["from"], serde_json::json!({ "x": 100.0, "y": 100.0 }));
+    assert_eq!(data["steps"], 5);
+    assert_eq!(session.mouse_position(), Some((200.0, 150.0)));
+
+    let moves = session.tab().unwrap().evaluate("JSON.stringify(window.moves.slice(-5))", false).unwrap();
+    assert_eq!(moves.value.unwrap(), "[[120,110],[140,120],[160,130],[180,140],[200,150]]");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_type_text_tool_types_into_focused_element() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <div id="editor" contenteditable="true"></div>
+        <script>
+            window.keys = 0;
+            document.addEventListener('keydown', function () { window.keys++; });
+            document.getElementById('editor').focus();
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mut context = ToolContext::new(&session);
+    let result = TypeTextTool
+        .execute_typed(TypeTextParams { text: "Héllo".to_string(), delay_ms: None }, &mut context)
+        .expect("Failed to type text");
+    assert_eq!(result.data.unwrap()["typed"], 5);
+
+    let result = TypeTextTool
+        .execute_typed(TypeTextParams { text: " you".to_string(), delay_ms: Some(10) }, &mut context)
+        .expect("Failed to type text with delay");
+    assert_eq!(result.data.unwrap()["method"], "key_events");
+
+    let tab = session.tab().unwrap();
+    let text = tab.evaluate("document.getElementById('editor').textContent", false).unwrap();
+    assert_eq!(text.value.unwrap(), "Héllo you");
+    // Only the delayed text was typed as key events
+    let keys = tab.evaluate("window.keys", false).unwrap();
+    assert_eq!(keys.value.unwrap(), 4);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_submit_form_tool_runs_submit_handlers() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <form id="search" action="/search" method="post">
+            <input id="query" name="action" value="rust">
+        </form>
+        <p id="outside">Not in a form</p>
+        <script>
+            window.submitted = 0;
+            document.getElementById('search').addEventListener('submit', function (e) {
+                e.preventDefault();
+                window.submitted++;
+            });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mut context = ToolContext::new(&session);
+    let result = SubmitFormTool
+        .execute_typed(SubmitFormParams { selector: Some("#query".to_string()), index: None }, &mut context)
+        .expect("Failed to submit form");
+    let data = result.data.unwrap();
+    assert_eq!(data["method"], "post");
+    // The action is read from the form even though an input named "action" shadows it
+    assert!(data["action"].as_str().unwrap().contains("search"));
+
+    let submitted = session.tab().unwrap().evaluate("window.submitted", false).unwrap();
+    assert_eq!(submitted.value.unwrap(), 1);
+
+    let err = SubmitFormTool
+        .execute_typed(SubmitFormParams { selector: Some("#outside".to_string()), index: None }, &mut context)
+        .unwrap_err();
+    assert!(err.to_string().contains("not inside a form"));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_describe_element_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <body style="margin: 0">
+            <div id="box" class="card" style="width: 200px; height: 50px; padding: 10px; display: flex">Box</div>
+            <p id="hidden" style="display: none">Hidden</p>
+        </body>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mut context = ToolContext::new(&session);
+    let params = DescribeElementParams {
+        selector: Some("#box".to_string()),
+        index: None,
+        styles: vec!["display".to_string(), "padding-top".to_string()],
+    };
+    let result = DescribeElementTool.execute_typed(params, &mut context).expect("Failed to describe element");
+    let data = result.data.unwrap();
+    assert_eq!(data["tag"], "div");
+    assert_eq!(data["attributes"]["class"], "card");
+    assert_eq!(data["styles"], serde_json::json!({ "display": "flex", "padding-top": "10px" }));
+    assert_eq!(data["box_model"]["content"]["width"], 200.0);
+    assert_eq!(data["box_model"]["width"], 220.0);
+
+    // Elements that aren't rendered have no box model
+    let params = DescribeElementParams { selector: Some("#hidden".to_string()), index: None, styles: vec![] };
+    let result = DescribeElementTool.execute_typed(params, &mut context).expect("Failed to describe element");
+    assert!(result.data.unwrap()["box_model"].is_null());
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_inject_css_tool_persists_across_navigation() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let page = "data:text/html,<div id='banner'>Accept cookies</div>";
+    session.navigate(page).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let banner_display = |session: &BrowserSession| {
+        let tab = session.tab().unwrap();
+        let result = tab.evaluate("getComputedStyle(document.getElementById('banner')).display", false).unwrap();
+        result.value.unwrap()
+    };
+
+    let mut context = ToolContext::new(&session);
+    let params = InjectCssParams { css: "#banner { display: none !important; }".to_string(), persist: true };
+    let result = InjectCssTool.execute_typed(params, &mut context).expect("Failed to inject CSS");
+    let id = result.data.unwrap()["id"].as_str().unwrap().to_string();
+    assert_eq!(banner_display(&session), "none");
+
+    // The style is added again on the next page
+    session.navigate(page).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    assert_eq!(banner_display(&session), "none");
+
+    let result =
+        RemoveCssTool.execute_typed(RemoveCssParams { id: id.clone() }, &mut context).expect("Failed to remove CSS");
+    assert_eq!(result.data.unwrap()["removed"], true);
+    assert_eq!(banner_display(&session), "block");
+
+    // Once removed, it stays gone after navigating and can't be removed twice
+    session.navigate(page).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    assert_eq!(banner_display(&session), "block");
+    assert!(RemoveCssTool.execute_typed(RemoveCssParams { id }, &mut context).is_err());
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_dismiss_banners_tool_clicks_consent_button() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <button onclick="window.wrong = true">OK</button>
+        <div id="cookie-notice" style="position: fixed; bottom: 0">
+            We use cookies.
+            <button onclick="window.wrong = true">Settings</button>
+            <button onclick="document.getElementById('cookie-notice').remove()">Accept all</button>
+        </div>
+    "#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mut context = ToolContext::new(&session);
+    let result = DismissBannersTool
+        .execute_typed(DismissBannersParams::default(), &mut context)
+        .expect("Failed to dismiss banners");
+    let data = result.data.unwrap();
+    assert_eq!(data["dismissed"], true);
+    assert_eq!(data["method"], "text");
+    assert_eq!(data["text"], "Accept all");
+    assert_eq!(data["banner"], "div#cookie-notice");
+
+    let tab = session.tab().unwrap();
+    let remaining = tab.evaluate("!!document.getElementById('cookie-notice') || !!window.wrong", false).unwrap();
+    assert_eq!(remaining.value.unwrap(), false);
+
+    // Nothing left to dismiss
+    let result = DismissBannersTool
+        .execute_typed(DismissBannersParams::default(), &mut context)
+        .expect("Failed to dismiss banners");
+    assert_eq!(result.data.unwrap()["dismissed"], false);
+
+    // Custom texts match buttons the built-in list doesn't know
+    let html = "<div class='consent'><button onclick=\"document.title='ja'\">Alles klar</button></div>";
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    let params = DismissBannersParams { texts: vec!["alles klar".to_string()], selectors: vec![] };
+    let result = DismissBannersTool.execute_typed(params, &mut context).expect("Failed to dismiss banners");
+    assert_eq!(result.data.unwrap()["dismissed"], true);
+    assert_eq!(tab.evaluate("document.title", false).unwrap().value.unwrap(), "ja");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_page_context_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = "<title>Context</title><button>Go</button><script>console.error('boom'); console.log('fine')</script>";
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mut context = ToolContext::new(&session);
+    let result =
+        PageContextTool.execute_typed(PageContextParams::default(), &mut context).expect("Failed to get page context");
+    let data = result.data.unwrap();
+    assert!(data["url"].as_str().unwrap().starts_with("data:text/html"));
+    assert_eq!(data["title"], "Context");
+    assert!(data["snapshot"].as_str().unwrap().contains("Go"));
+    assert_eq!(data["interactive_count"], 1);
+    let console_errors = data["recent_console_errors"].as_array().unwrap();
+    assert_eq!(console_errors.len(), 1);
+    assert!(console_errors[0]["text"].as_str().unwrap().contains("boom"));
+    assert!(data["recent_network_errors"].as_array().unwrap().is_empty());
+
+    // Sections can be left out
+    let params = PageContextParams { snapshot: false, console_errors: false, ..Default::default() };
+    let data = PageContextTool.execute_typed(params, &mut context).expect("Failed to get page context").data.unwrap();
+    assert!(data.get("snapshot").is_none());
+    assert!(data.get("recent_console_errors").is_none());
+    assert_eq!(data["title"], "Context");
+}
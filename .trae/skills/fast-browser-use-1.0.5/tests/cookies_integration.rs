@@ -1,6 +1,6 @@
 use browser_use::{BrowserSession, LaunchOptions,
-                  tools::{cookies::{CookieParam, GetCookiesParams, SetCookiesParams},
-                          Tool, ToolContext, cookies::{GetCookiesTool, SetCookiesTool}}};
+                  tools::{cookies::{ClearCookiesParams, CookieParam, GetCookiesParams, SetCookiesParams},
+                          Tool, ToolContext, cookies::{ClearCookiesTool, GetCookiesTool, SetCookiesTool}}};
 use log::info;
 
 #[test]
@@ -13,8 +13,8 @@ fn test_cookies_workflow() {
     
     // Create tool context
     let mut context = ToolContext::new(&session);
-    let set_cookies_tool = SetCookiesTool::default();
-    let get_cookies_tool = GetCookiesTool::default();
+    let set_cookies_tool = SetCookiesTool;
+    let get_cookies_tool = GetCookiesTool;
 
     // 1. Set a cookie
     let cookie = CookieParam {
@@ -54,3 +54,42 @@ fn test_cookies_workflow() {
     
     info!("Successfully set and retrieved cookies!");
 }
+
+fn example_cookie(name: &str, domain: &str) -> CookieParam {
+    CookieParam {
+        name: name.to_string(),
+        value: "value".to_string(),
+        domain: Some(domain.to_string()),
+        path: Some("/".to_string()),
+        secure: Some(false),
+        http_only: Some(false),
+        same_site: None,
+        expires: None,
+        url: Some(format!("https://{}", domain)),
+    }
+}
+
+#[test]
+#[ignore]
+fn test_clear_cookies() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("https://example.com").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    let cookies = vec![example_cookie("a", "example.com"), example_cookie("b", "example.org")];
+    SetCookiesTool.execute_typed(SetCookiesParams { cookies }, &mut context).expect("Failed to set cookies");
+
+    // Domain filter only removes the matching cookie
+    let result = ClearCookiesTool
+        .execute_typed(ClearCookiesParams { domain: Some("example.org".to_string()) }, &mut context)
+        .expect("Failed to clear cookies for domain");
+    assert_eq!(result.data.unwrap()["cleared"], 1);
+
+    let remaining = session.get_cookies().unwrap();
+    assert!(remaining.iter().any(|c| c.name == "a"), "Cookies of other domains should be kept");
+
+    // Without a filter everything goes
+    let result = ClearCookiesTool.execute_typed(ClearCookiesParams::default(), &mut context).unwrap();
+    assert!(result.data.unwrap()["cleared"].as_u64().unwrap() >= 1);
+    assert!(session.get_cookies().unwrap().is_empty());
+}
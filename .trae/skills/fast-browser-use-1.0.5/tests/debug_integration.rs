@@ -1,6 +1,9 @@
 use browser_use::{BrowserSession, LaunchOptions,
-                  tools::{debug::{GetConsoleLogsParams, GetNetworkErrorsParams, GetConsoleLogsTool, GetNetworkErrorsTool},
-                          Tool, ToolContext}};
+                  tools::{CaptureResponsesParams, GetCapturedResponsesParams, Tool, ToolContext,
+                          debug::{ClearConsoleLogsParams, ClearConsoleLogsTool, GetConsoleLogsParams,
+                                  GetConsoleLogsTool, GetJsExceptionsParams, GetJsExceptionsTool, GetNetworkErrorsParams,
+                                  GetNetworkErrorsTool},
+                          responses::{CaptureResponsesTool, GetCapturedResponsesTool}}};
 use log::info;
 use std::thread;
 use std::time::Duration;
@@ -36,12 +39,12 @@ fn test_debug_tools() {
     
     // Create tool context
     let mut context = ToolContext::new(&session);
-    let logs_tool = GetConsoleLogsTool::default();
-    let errors_tool = GetNetworkErrorsTool::default();
+    let logs_tool = GetConsoleLogsTool;
+    let errors_tool = GetNetworkErrorsTool;
 
     // 1. Get console logs
     let logs_result = logs_tool
-        .execute_typed(GetConsoleLogsParams {}, &mut context)
+        .execute_typed(GetConsoleLogsParams::default(), &mut context)
         .expect("Failed to execute get_console_logs");
 
     assert!(logs_result.success);
@@ -58,6 +61,10 @@ fn test_debug_tools() {
     assert!(has_log, "Should capture console.log");
     assert!(has_warn, "Should capture console.warn");
 
+    // Structured arguments are kept alongside the joined text
+    let log_entry = logs_arr.iter().find(|l| l["text"].as_str().unwrap_or("").contains("Test log message")).unwrap();
+    assert_eq!(log_entry["args"], serde_json::json!(["Test log message"]));
+
     // 2. Get network errors
     // Note: Network errors might take longer or behave differently in headless depending on environment
     let errors_result = errors_tool
@@ -81,3 +88,130 @@ fn test_debug_tools() {
     // Network errors might be empty if the browser handles it purely as a console error for data: URLs
     // But let's see.
 }
+
+#[test]
+#[ignore]
+fn test_clear_console_logs() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    session
+        .navigate("data:text/html,<script>console.log('Before clear');</script>")
+        .expect("Failed to navigate");
+    thread::sleep(Duration::from_secs(1));
+
+    let mut context = ToolContext::new(&session);
+    let logs_tool = GetConsoleLogsTool;
+
+    let logs = logs_tool.execute_typed(GetConsoleLogsParams::default(), &mut context).unwrap().data.unwrap();
+    assert!(!logs.as_array().unwrap().is_empty(), "Should capture logs before clearing");
+
+    ClearConsoleLogsTool.execute_typed(ClearConsoleLogsParams {}, &mut context).expect("Failed to clear logs");
+
+    let logs = logs_tool.execute_typed(GetConsoleLogsParams::default(), &mut context).unwrap().data.unwrap();
+    assert!(logs.as_array().unwrap().is_empty(), "Logs should be empty after clearing");
+
+    // A fresh entry falls inside the since_ms window
+    session.tab().unwrap().evaluate("console.log('After clear')", false).unwrap();
+    thread::sleep(Duration::from_millis(500));
+    let recent = logs_tool.execute_typed(GetConsoleLogsParams { since_ms: Some(60_000) }, &mut context).unwrap();
+    assert_eq!(recent.data.unwrap().as_array().unwrap().len(), 1);
+}
+
+#[test]
+#[ignore]
+fn test_get_js_exceptions() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    session
+        .navigate("data:text/html,<script>setTimeout(function () { undefinedFunction(); }, 0);</script>")
+        .expect("Failed to navigate");
+    thread::sleep(Duration::from_secs(1));
+
+    let mut context = ToolContext::new(&session);
+    let result = GetJsExceptionsTool
+        .execute_typed(GetJsExceptionsParams {}, &mut context)
+        .expect("Failed to execute get_js_exceptions");
+
+    let exceptions = result.data.unwrap();
+    info!("Captured exceptions: {:?}", exceptions);
+
+    let exceptions_arr = exceptions.as_array().expect("Exceptions should be an array");
+    assert!(
+        exceptions_arr.iter().any(|e| e["message"].as_str().unwrap_or("").contains("undefinedFunction")),
+        "Should capture the ReferenceError"
+    );
+}
+
+#[test]
+#[ignore]
+fn test_handle_dialog() {
+    use browser_use::tools::dialog::{HandleDialogParams, HandleDialogTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    let tab = session.tab().unwrap();
+
+    // An alert opened before any handler is set stays open until the tool answers it
+    tab.evaluate("setTimeout(() => alert('Session expired'), 0)", false).unwrap();
+    thread::sleep(Duration::from_millis(500));
+    assert_eq!(session.get_dialogs().unwrap().last().unwrap().accepted, None);
+
+    let mut context = ToolContext::new(&session);
+    let result = HandleDialogTool
+        .execute_typed(HandleDialogParams { accept: true, prompt_text: Some("Ada".to_string()) }, &mut context)
+        .expect("Failed to execute handle_dialog");
+    let data = result.data.unwrap();
+    info!("Handle dialog result: {}", data);
+    assert_eq!(data["handled"][0]["message"].as_str(), Some("Session expired"));
+    assert_eq!(data["handled"][0]["type_"].as_str(), Some("alert"));
+
+    // Later dialogs are answered as soon as they open
+    let name = tab.evaluate("prompt('Your name?')", false).unwrap();
+    assert_eq!(name.value, Some(serde_json::json!("Ada")));
+
+    session.set_dialog_handler(false, None).unwrap();
+    let confirmed = tab.evaluate("confirm('Delete everything?')", false).unwrap();
+    assert_eq!(confirmed.value, Some(serde_json::json!(false)));
+
+    let dialogs = session.get_dialogs().unwrap();
+    assert_eq!(dialogs.len(), 3);
+    assert_eq!(dialogs[2].message, "Delete everything?");
+    assert_eq!(dialogs[2].accepted, Some(false));
+}
+
+#[test]
+#[ignore] // Requires Chrome and network access
+fn test_capture_responses() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let mut context = ToolContext::new(&session);
+    CaptureResponsesTool
+        .execute_typed(CaptureResponsesParams { url_pattern: "*httpbin.org/json*".to_string() }, &mut context)
+        .expect("Failed to start capturing");
+
+    session.navigate("data:text/html,<h1>API page</h1>").expect("Failed to navigate");
+    let fetch_js = "Promise.all([fetch('https://httpbin.org/json'), fetch('https://httpbin.org/uuid')])";
+    session.tab().unwrap().evaluate(fetch_js, true).expect("Failed to fetch");
+
+    // The body is fetched once the response has finished loading
+    let mut responses = Vec::new();
+    for _ in 0..50 {
+        responses = session.get_captured_responses().expect("Failed to get captured responses");
+        if !responses.is_empty() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    assert_eq!(responses.len(), 1, "Only the matching response is captured");
+    assert_eq!(responses[0].status, 200);
+    assert_eq!(responses[0].mime_type, "application/json");
+    assert!(!responses[0].base64_encoded);
+    let body: serde_json::Value = serde_json::from_str(&responses[0].body).expect("Body is not JSON");
+    assert!(body.get("slideshow").is_some());
+
+    let result = GetCapturedResponsesTool
+        .execute_typed(GetCapturedResponsesParams { clear: true }, &mut context)
+        .expect("Failed to get captured responses");
+    assert_eq!(result.data.unwrap().as_array().unwrap().len(), 1);
+    assert!(session.get_captured_responses().unwrap().is_empty());
+}
@@ -76,7 +76,7 @@ fn test_read_links() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let tool = ReadLinksTool::default();
+    let tool = ReadLinksTool;
     let mut context = ToolContext::new(&session);
 
     let result = tool.execute_typed(ReadLinksParams {}, &mut context).expect("Failed execute");
@@ -141,7 +141,7 @@ fn test_press_key_enter() {
     std::thread::sleep(std::time::Duration::from_millis(200));
 
     // Create tool and context
-    let tool = PressKeyTool::default();
+    let tool = PressKeyTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to press Enter
@@ -168,3 +168,175 @@ fn test_press_key_enter() {
     // Note: Due to limitations with data: URLs and event handling,
     // we mainly verify that the tool executes without error
 }
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_cdp_accessibility_tree() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session
+        .navigate("data:text/html,<button aria-label='Save draft'>Save</button><a href='/x'>Docs</a>")
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let nodes = session.get_cdp_accessibility_tree().expect("Failed to get accessibility tree");
+    let nodes = nodes.as_array().expect("Accessibility tree should be a node array");
+    info!("Accessibility tree has {} nodes", nodes.len());
+
+    let button = nodes
+        .iter()
+        .find(|node| node["role"]["value"] == "button")
+        .expect("Accessibility tree should contain the button");
+    assert_eq!(button["name"]["value"], "Save draft");
+    assert!(nodes.iter().any(|node| node["role"]["value"] == "link"));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_selector_strategy() {
+    use browser_use::SelectorStrategy;
+
+    let mut session =
+        BrowserSession::launch(LaunchOptions::new().headless(true).selector_strategy(SelectorStrategy::DataTestId))
+            .expect("Failed to launch browser");
+    session
+        .navigate(
+            "data:text/html,<section data-testid='cart'><div><button>Buy</button></div></section>\
+             <button aria-label='Close dialog'>X</button>",
+        )
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let dom = session.extract_dom().expect("Failed to extract DOM");
+    info!("Selectors: {:?}", dom.selectors);
+    assert!(dom.selectors.iter().any(|s| s.starts_with("section[data-testid=\"cart\"] > ")));
+
+    session.set_selector_strategy(SelectorStrategy::AriaLabel);
+    let dom = session.extract_dom().expect("Failed to extract DOM");
+    assert!(dom.selectors.iter().any(|s| s == "button[aria-label=\"Close\\ dialog\"]"));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_stable_refs_survive_index_shift() {
+    use browser_use::tools::{ClickParams, Tool, ToolContext, click::ClickTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session
+        .navigate(
+            "data:text/html,<button onclick=\"document.title='cancel'\">Cancel</button>\
+             <button onclick=\"document.title='save'\">Save</button>",
+        )
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let find = |dom: &browser_use::DomTree, name: &str| {
+        dom.interactive_indices()
+            .into_iter()
+            .filter_map(|index| dom.find_node_by_index(index))
+            .find(|node| node.name == name)
+            .cloned()
+            .expect("Button not found")
+    };
+
+    let before = find(&session.extract_dom().expect("Failed to extract DOM"), "Save");
+    let save_ref = before.element_ref.clone().expect("Indexed element has no ref");
+
+    // Inserting an element in front of the button shifts every index after it
+    session
+        .tab()
+        .unwrap()
+        .evaluate(
+            "document.body.insertAdjacentHTML('afterbegin', '<button onclick=\"document.title=\\'new\\'\">New</button>')",
+            false,
+        )
+        .expect("Failed to insert button");
+
+    let dom = session.extract_dom().expect("Failed to extract DOM");
+    let after = find(&dom, "Save");
+    assert_ne!(after.index, before.index);
+    assert_eq!(after.element_ref.as_deref(), Some(save_ref.as_str()));
+    assert_eq!(dom.get_selector_by_ref(&save_ref).unwrap(), &format!("[data-browseruse-ref=\"{}\"]", save_ref));
+
+    // A click by the old index would now hit a different button; the ref still hits "Save"
+    let mut context = ToolContext::new(&session);
+    let result = ClickTool
+        .execute_typed(
+            ClickParams { selector: None, index: None, element_ref: Some(save_ref), emulate_human: false },
+            &mut context,
+        )
+        .expect("Failed to click by ref");
+    assert_eq!(result.data.unwrap()["method"], "ref");
+
+    let title = session.tab().unwrap().evaluate("document.title", false).unwrap();
+    assert_eq!(title.value.unwrap(), "save");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_max_nodes_truncates_large_page() {
+    use browser_use::ExtractOptions;
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    // 3000 paragraphs of content with a button at the very end
+    session
+        .navigate(
+            "data:text/html,<div id='content'></div><script>\
+             const content = document.getElementById('content');\
+             for (let i = 0; i < 3000; i++) { const p = document.createElement('p'); p.textContent = 'Row ' + i; content.appendChild(p); }\
+             const button = document.createElement('button'); button.textContent = 'Load more'; document.body.appendChild(button);\
+             </script>",
+        )
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let full = session.extract_dom().expect("Failed to extract DOM");
+    assert!(!full.truncated);
+    assert!(full.count_nodes() > 3000);
+
+    let capped =
+        session.extract_dom_with_options(&ExtractOptions::default().max_nodes(100)).expect("Failed to extract DOM");
+    info!("Capped tree has {} nodes", capped.count_nodes());
+    assert!(capped.truncated);
+    assert!(capped.count_nodes() <= 201);
+
+    // The button past the budget is still kept, since interactive elements take priority
+    let has_button = capped
+        .interactive_indices()
+        .into_iter()
+        .filter_map(|index| capped.find_node_by_index(index))
+        .any(|node| node.role == "button" && node.name == "Load more");
+    assert!(has_button);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_click_inside_same_origin_iframe() {
+    use browser_use::tools::{ClickParams, Tool, ToolContext, click::ClickTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session
+        .navigate(
+            "data:text/html,<h1>Outer</h1>\
+             <iframe srcdoc=\"<button id='inner' onclick='parent.document.title=&quot;clicked&quot;'>Go</button>\"></iframe>",
+        )
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    // The top document has no #inner; the lookup falls back to the iframe's document
+    let tab = session.tab().unwrap();
+    let element = session.find_element(&tab, "#inner").expect("Element in iframe not found");
+    assert_eq!(element.get_inner_text().unwrap(), "Go");
+
+    let mut context = ToolContext::new(&session);
+    ClickTool
+        .execute_typed(
+            ClickParams { selector: Some("#inner".to_string()), index: None, element_ref: None, emulate_human: false },
+            &mut context,
+        )
+        .expect("Failed to click inside iframe");
+
+    let title = tab.evaluate("document.title", false).unwrap();
+    assert_eq!(title.value.unwrap(), "clicked");
+
+    assert!(session.find_element(&tab, "#missing").is_err());
+}
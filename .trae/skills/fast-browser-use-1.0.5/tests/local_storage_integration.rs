@@ -12,10 +12,10 @@ fn test_local_storage_workflow() {
     session.navigate("https://example.com").expect("Failed to navigate");
 
     let mut context = ToolContext::new(&session);
-    let get_tool = GetLocalStorageTool::default();
-    let set_tool = SetLocalStorageTool::default();
-    let remove_tool = RemoveLocalStorageTool::default();
-    let clear_tool = ClearLocalStorageTool::default();
+    let get_tool = GetLocalStorageTool;
+    let set_tool = SetLocalStorageTool;
+    let remove_tool = RemoveLocalStorageTool;
+    let clear_tool = ClearLocalStorageTool;
 
     // 1. Set a value
     let set_result = set_tool
@@ -31,7 +31,7 @@ fn test_basic_markdown_extraction() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool
@@ -104,7 +104,7 @@ fn test_readability_filtering() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     let result =
@@ -163,7 +163,7 @@ fn test_markdown_pagination() {
 
     std::thread::sleep(std::time::Duration::from_millis(1000));
 
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     // Get first page with small page size
@@ -172,6 +172,7 @@ fn test_markdown_pagination() {
             GetMarkdownParams {
                 page: 1,
                 page_size: 5000, // Small page size to force pagination
+                ..Default::default()
             },
             &mut context,
         )
@@ -211,6 +212,7 @@ fn test_markdown_pagination() {
             GetMarkdownParams {
                 page: 2,
                 page_size: 5000,
+                ..Default::default()
             },
             &mut context,
         )
@@ -250,7 +252,7 @@ fn test_empty_page() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     let result = tool.execute_typed(GetMarkdownParams::default(), &mut context);
@@ -315,7 +317,7 @@ fn test_table_conversion() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     let result =
@@ -367,7 +369,7 @@ fn test_double_execution_same_page() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     // First execution
@@ -430,12 +432,12 @@ fn test_page_clamping() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let tool = GetMarkdownTool::default();
+    let tool = GetMarkdownTool;
     let mut context = ToolContext::new(&session);
 
     // Request page 999 (way beyond available content)
     let result = tool
-        .execute_typed(GetMarkdownParams { page: 999, page_size: 100_000 }, &mut context)
+        .execute_typed(GetMarkdownParams { page: 999, page_size: 100_000, ..Default::default() }, &mut context)
         .expect("Failed to execute markdown tool");
 
     assert!(result.success);
@@ -446,3 +448,102 @@ fn test_page_clamping() {
     assert_eq!(data["totalPages"].as_u64(), Some(1));
     assert_eq!(data["hasMorePages"].as_bool(), Some(false));
 }
+
+/// Content loaded only when scrolled into view is extracted with trigger_lazy
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_markdown_trigger_lazy() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <!DOCTYPE html>
+        <html>
+        <head><title>Feed</title></head>
+        <body>
+            <article>
+                <h1>Feed</h1>
+                <p>The first post of the feed is visible right away, and it is long enough for Readability to treat it as the article content.</p>
+                <div style="height: 5000px"></div>
+                <p id="lazy">Loading...</p>
+            </article>
+            <script>
+                new IntersectionObserver(function (entries, observer) {
+                    if (entries[0].isIntersecting) {
+                        document.getElementById('lazy').textContent = 'The second post was loaded lazily once it scrolled into view.';
+                        observer.disconnect();
+                    }
+                }).observe(document.getElementById('lazy'));
+            </script>
+        </body>
+        </html>
+    "#;
+
+    let data_url = format!("data:text/html,{}", urlencoding::encode(html));
+    session.navigate(&data_url).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let tool = GetMarkdownTool;
+    let mut context = ToolContext::new(&session);
+
+    let result =
+        tool.execute_typed(GetMarkdownParams::default(), &mut context).expect("Failed to execute markdown tool");
+    let markdown = result.data.unwrap()["markdown"].as_str().unwrap().to_string();
+    assert!(!markdown.contains("loaded lazily"));
+
+    let result = tool
+        .execute_typed(GetMarkdownParams { trigger_lazy: true, ..Default::default() }, &mut context)
+        .expect("Failed to execute markdown tool");
+    let markdown = result.data.unwrap()["markdown"].as_str().unwrap().to_string();
+    info!("Markdown after triggering lazy content: {}", markdown);
+    assert!(markdown.contains("loaded lazily"));
+
+    // The page is back at the top afterwards
+    let scroll_y = session.tab().unwrap().evaluate("window.scrollY", false).unwrap();
+    assert_eq!(scroll_y.value.unwrap().as_f64().unwrap(), 0.0);
+}
+
+/// Page globals with the same names as the extraction script's don't break get_markdown
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_markdown_ignores_page_globals() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <!DOCTYPE html>
+        <html>
+        <head>
+            <title>Globals</title>
+            <script>const READABILITY_SCRIPT = "taken by the page"; window.Readability = null;</script>
+        </head>
+        <body>
+            <article>
+                <h1>Declared Globals</h1>
+                <p>The page declares a constant with the same name as the one the extraction script uses.</p>
+            </article>
+        </body>
+        </html>
+    "#;
+
+    let data_url = format!("data:text/html,{}", urlencoding::encode(html));
+    session.navigate(&data_url).expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let tool = GetMarkdownTool;
+    let mut context = ToolContext::new(&session);
+
+    // Twice, since a second run in the same world would redeclare the script's own constant
+    for _ in 0..2 {
+        let result =
+            tool.execute_typed(GetMarkdownParams::default(), &mut context).expect("Failed to execute markdown tool");
+        let markdown = result.data.unwrap()["markdown"].as_str().unwrap().to_string();
+        assert!(markdown.contains("same name"));
+    }
+
+    // The page's own constant is untouched
+    let value = session.tab().unwrap().evaluate("READABILITY_SCRIPT", false).unwrap().value.unwrap();
+    assert_eq!(value, "taken by the page");
+
+    // Isolated scripts see the DOM but not the page's globals
+    let isolated = session.evaluate_isolated("typeof READABILITY_SCRIPT + ' ' + document.title").unwrap();
+    assert_eq!(isolated.value.unwrap(), "undefined Globals");
+}
@@ -1,6 +1,8 @@
-use browser_use::{BrowserSession, LaunchOptions,
-                  tools::{CloseParams, GoBackParams, GoForwardParams, Tool, ToolContext, close::CloseTool,
-                          go_back::GoBackTool, go_forward::GoForwardTool}};
+use browser_use::{BrowserSession, ConnectionOptions, LaunchOptions, PageLoadStrategy,
+                  tools::{CloseParams, GoBackParams, GoForwardParams, NavigateParams, SetLocaleParams,
+                          SetTimezoneParams, Tool, ToolContext, close::CloseTool, go_back::GoBackTool,
+                          go_forward::GoForwardTool, locale::SetLocaleTool, navigate::NavigateTool,
+                          timezone::SetTimezoneTool}};
 use log::info;
 
 #[test]
@@ -23,7 +25,7 @@ fn test_go_back_tool() {
     assert!(current_url.contains("Page 2"));
 
     // Create tool and context
-    let tool = GoBackTool::default();
+    let tool = GoBackTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to go back
@@ -37,6 +39,7 @@ fn test_go_back_tool() {
     info!("Go back result: {}", serde_json::to_string_pretty(&data).unwrap());
 
     assert_eq!(data["message"].as_str(), Some("Navigated back in history"));
+    assert_eq!(data["navigated"].as_bool(), Some(true));
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
@@ -61,7 +64,7 @@ fn test_go_forward_tool() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Go back to page 1
-    session.go_back().expect("Failed to go back");
+    assert!(session.go_back().expect("Failed to go back"));
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
@@ -70,7 +73,7 @@ fn test_go_forward_tool() {
     assert!(current_url.contains("Page 1"));
 
     // Create tool and context
-    let tool = GoForwardTool::default();
+    let tool = GoForwardTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to go forward
@@ -122,8 +125,8 @@ fn test_navigation_workflow() {
     info!("On page 3");
 
     // Create tools
-    let go_back_tool = GoBackTool::default();
-    let go_forward_tool = GoForwardTool::default();
+    let go_back_tool = GoBackTool;
+    let go_forward_tool = GoForwardTool;
 
     // Go back to page 2
     let mut context = ToolContext::new(&session);
@@ -176,7 +179,7 @@ fn test_close_tool() {
     assert!(!tabs.is_empty(), "Should have at least one tab");
 
     // Create tool and context
-    let tool = CloseTool::default();
+    let tool = CloseTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to close the browser
@@ -208,14 +211,16 @@ fn test_go_back_on_first_page() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = GoBackTool::default();
+    let tool = GoBackTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool - should succeed but do nothing
     let result = tool.execute_typed(GoBackParams {}, &mut context).expect("Failed to execute go_back tool");
 
     assert!(result.success, "Tool execution should succeed even if no previous page");
-    info!("Go back on first page result: {}", serde_json::to_string_pretty(&result.data.unwrap()).unwrap());
+    let data = result.data.unwrap();
+    info!("Go back on first page result: {}", serde_json::to_string_pretty(&data).unwrap());
+    assert_eq!(data["navigated"].as_bool(), Some(false));
 }
 
 #[test]
@@ -230,12 +235,375 @@ fn test_go_forward_on_last_page() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = GoForwardTool::default();
+    let tool = GoForwardTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool - should succeed but do nothing
     let result = tool.execute_typed(GoForwardParams {}, &mut context).expect("Failed to execute go_forward tool");
 
     assert!(result.success, "Tool execution should succeed even if no forward history");
-    info!("Go forward on last page result: {}", serde_json::to_string_pretty(&result.data.unwrap()).unwrap());
+    let data = result.data.unwrap();
+    info!("Go forward on last page result: {}", serde_json::to_string_pretty(&data).unwrap());
+    assert_eq!(data["navigated"].as_bool(), Some(false));
+}
+
+#[test]
+#[ignore]
+fn test_navigate_tool_returns_normalized_url() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let tool = NavigateTool;
+    let mut context = ToolContext::new(&session);
+
+    let params = NavigateParams {
+        url: "  example.com  ".to_string(),
+        wait_for_load: true,
+        wait_for_selector: None,
+        timeout_ms: None,
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to execute navigate tool");
+
+    assert!(result.success);
+    let data = result.data.unwrap();
+    assert_eq!(data["url"].as_str(), Some("https://example.com"));
+    assert!(data["snapshot"].is_string());
+}
+
+#[test]
+#[ignore]
+fn test_navigate_tool_wait_for_selector() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let tool = NavigateTool;
+    let mut context = ToolContext::new(&session);
+
+    let params = NavigateParams {
+        url: "data:text/html,<html><body><h1 id='title'>Ready</h1></body></html>".to_string(),
+        wait_for_load: true,
+        wait_for_selector: Some("#title".to_string()),
+        timeout_ms: Some(5000),
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to execute navigate tool");
+    assert_eq!(result.data.unwrap()["selector_found"].as_bool(), Some(true));
+
+    // A selector that never appears is reported rather than treated as an error
+    let mut context = ToolContext::new(&session);
+    let params = NavigateParams {
+        url: "data:text/html,<html><body><h1>Ready</h1></body></html>".to_string(),
+        wait_for_load: true,
+        wait_for_selector: Some("#missing".to_string()),
+        timeout_ms: Some(500),
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to execute navigate tool");
+    assert_eq!(result.data.unwrap()["selector_found"].as_bool(), Some(false));
+}
+
+#[test]
+#[ignore]
+fn test_navigate_tool_eager_page_load() {
+    let options = LaunchOptions::new().headless(true).page_load_strategy(PageLoadStrategy::Eager);
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    assert_eq!(session.page_load_strategy(), PageLoadStrategy::Eager);
+
+    let tool = NavigateTool;
+    let mut context = ToolContext::new(&session);
+    let params = NavigateParams {
+        url: "data:text/html,<html><body><h1>Parsed</h1></body></html>".to_string(),
+        wait_for_load: true,
+        wait_for_selector: None,
+        timeout_ms: Some(5000),
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to execute navigate tool");
+    assert!(result.data.unwrap()["snapshot"].as_str().unwrap().contains("Parsed"));
+}
+
+#[test]
+#[ignore]
+fn test_navigate_tool_returns_non_html_body() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let tool = NavigateTool;
+    let mut context = ToolContext::new(&session);
+    let params = NavigateParams {
+        url: r#"data:application/json,{"ok":true}"#.to_string(),
+        wait_for_load: true,
+        wait_for_selector: None,
+        timeout_ms: None,
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to execute navigate tool");
+    let data = result.data.unwrap();
+    assert_eq!(data["content_type"].as_str(), Some("application/json"));
+    assert_eq!(data["encoding"].as_str(), Some("text"));
+    assert_eq!(data["body"].as_str(), Some(r#"{"ok":true}"#));
+    assert!(data.get("snapshot").is_none());
+
+    // Binary bodies come back base64-encoded
+    let mut context = ToolContext::new(&session);
+    let params = NavigateParams {
+        url: "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==".to_string(),
+        wait_for_load: true,
+        wait_for_selector: None,
+        timeout_ms: None,
+    };
+    let result = tool.execute_typed(params, &mut context).expect("Failed to execute navigate tool");
+    let data = result.data.unwrap();
+    assert_eq!(data["content_type"].as_str(), Some("image/png"));
+    assert_eq!(data["encoding"].as_str(), Some("base64"));
+    assert_eq!(
+        data["body"].as_str(),
+        Some("iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==")
+    );
+    assert_eq!(data["length"].as_u64(), Some(70));
+}
+
+/// Load a page with a remote image and report whether it loaded
+fn image_loaded(session: &BrowserSession) -> bool {
+    let html = r#"<img src="https://www.google.com/images/branding/googlelogo/1x/googlelogo_color_272x92dp.png"
+        onload="document.title = 'loaded'" onerror="document.title = 'blocked'">"#;
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    let title = session
+        .wait_for_function(
+            "document.title === 'loaded' || document.title === 'blocked' ? document.title : null",
+            10_000,
+            100,
+        )
+        .expect("Image neither loaded nor failed");
+    title == "loaded"
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_block_resource_types_toggle() {
+    let options = LaunchOptions::new().headless(true).block_resources(vec!["Image".to_string()]);
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+
+    assert!(!image_loaded(&session), "Images should be blocked");
+
+    session.block_resource_types(Vec::new()).expect("Failed to stop blocking");
+    assert!(image_loaded(&session), "Images should load once blocking is off");
+
+    session.block_resource_types(vec!["Image".to_string()]).expect("Failed to block images");
+    assert!(!image_loaded(&session), "Images should be blocked again");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_block_urls() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    assert!(image_loaded(&session), "Images should load without block rules");
+
+    session.block_urls(vec!["*://www.google.com/images/*".to_string()]).expect("Failed to block URLs");
+    assert!(!image_loaded(&session), "Matching URLs should be blocked");
+
+    // Rules apply to tabs opened afterwards too
+    let tab = session.new_tab().expect("Failed to open tab");
+    tab.activate().expect("Failed to activate tab");
+    assert!(!image_loaded(&session), "New tabs should inherit the block rules");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_user_agent_override() {
+    let options = LaunchOptions::new().headless(true).user_agent("LaunchAgent/1.0");
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+
+    let user_agent = |session: &BrowserSession| {
+        session.tab().unwrap().evaluate("navigator.userAgent", false).unwrap().value.unwrap()
+    };
+    assert_eq!(user_agent(&session), "LaunchAgent/1.0");
+
+    session.set_user_agent("RuntimeAgent/2.0").expect("Failed to set user agent");
+    assert_eq!(user_agent(&session), "RuntimeAgent/2.0");
+
+    // New tabs inherit the override
+    let tab = session.new_tab().expect("Failed to open tab");
+    assert_eq!(tab.evaluate("navigator.userAgent", false).unwrap().value.unwrap(), "RuntimeAgent/2.0");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_connect_applies_session_config() {
+    let launched = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let options = ConnectionOptions::new(launched.browser().get_ws_url())
+        .session(LaunchOptions::new().user_agent("ConnectAgent/1.0").timezone("Asia/Tokyo"));
+    let session = BrowserSession::connect(options).expect("Failed to connect to browser");
+    assert_eq!(session.config().user_agent.as_deref(), Some("ConnectAgent/1.0"));
+
+    session.navigate("about:blank").expect("Failed to navigate");
+    let tab = session.tab().unwrap();
+    assert_eq!(tab.evaluate("navigator.userAgent", false).unwrap().value.unwrap(), "ConnectAgent/1.0");
+    let timezone = tab.evaluate("Intl.DateTimeFormat().resolvedOptions().timeZone", false).unwrap().value.unwrap();
+    assert_eq!(timezone, "Asia/Tokyo");
+}
+
+#[test]
+#[ignore] // Requires Chrome and network access
+fn test_extra_headers() {
+    let headers = std::collections::HashMap::from([("X-Launch-Header".to_string(), "from-launch".to_string())]);
+    let options = LaunchOptions::new().headless(true).extra_headers(headers);
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+
+    let echoed_headers = |session: &BrowserSession| {
+        session.navigate("https://httpbin.org/headers").expect("Failed to navigate");
+        session.wait_for_navigation().expect("Failed to load page");
+        session.tab().unwrap().evaluate("document.body.innerText", false).unwrap().value.unwrap().to_string()
+    };
+    assert!(echoed_headers(&session).contains("from-launch"));
+
+    let headers = std::collections::HashMap::from([("X-Runtime-Header".to_string(), "from-runtime".to_string())]);
+    session.set_extra_headers(headers).expect("Failed to set headers");
+    let body = echoed_headers(&session);
+    assert!(body.contains("from-runtime"));
+    assert!(!body.contains("from-launch"), "Setting headers replaces the previous ones");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_set_locale_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    SetLocaleTool
+        .execute_typed(SetLocaleParams { locale: "de-DE".to_string() }, &mut context)
+        .expect("Failed to set locale");
+
+    let formatted = session.tab().unwrap().evaluate("(1234.5).toLocaleString()", false).unwrap().value.unwrap();
+    assert_eq!(formatted, "1.234,5");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_set_timezone_tool() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    SetTimezoneTool
+        .execute_typed(SetTimezoneParams { timezone: "America/New_York".to_string() }, &mut context)
+        .expect("Failed to set timezone");
+
+    let timezone =
+        session.tab().unwrap().evaluate("Intl.DateTimeFormat().resolvedOptions().timeZone", false).unwrap().value;
+    assert_eq!(timezone.unwrap(), "America/New_York");
+
+    let result =
+        SetTimezoneTool.execute_typed(SetTimezoneParams { timezone: "Mars/Olympus_Mons".to_string() }, &mut context);
+    assert!(matches!(result, Err(browser_use::BrowserError::InvalidArgument(_))), "Got {:?}", result.err());
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_emulated_print_media() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("about:blank").expect("Failed to navigate");
+
+    let is_print = |session: &BrowserSession| {
+        session.tab().unwrap().evaluate("matchMedia('print').matches", false).unwrap().value.unwrap() == true
+    };
+    assert!(!is_print(&session));
+
+    session.set_emulated_media(Some("print")).expect("Failed to emulate print media");
+    assert!(is_print(&session));
+
+    session.reset_emulated_media().expect("Failed to reset media");
+    assert!(!is_print(&session));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_history_without_entry_returns_at_once() {
+    let mut session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.set_default_timeout(std::time::Duration::from_secs(30));
+    for page in ["data:text/html,<h1>First</h1>", "data:text/html,<h1>Last</h1>"] {
+        session.navigate(page).expect("Failed to navigate");
+        session.wait_for_navigation().expect("Failed to wait for navigation");
+    }
+
+    // There is no entry to go forward to, so this must not wait for the 30s default timeout
+    let started = std::time::Instant::now();
+    assert!(!session.go_forward().expect("Failed to go forward"));
+    assert!(started.elapsed() < std::time::Duration::from_secs(1));
+
+    assert!(session.go_back().expect("Failed to go back"));
+    assert!(session.tab().unwrap().get_url().contains("First"));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_print_pdf() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<h1>Printable</h1>").expect("Failed to navigate");
+
+    let pdf = session.print_pdf(true, true).expect("Failed to print PDF");
+    assert!(pdf.starts_with(b"%PDF"), "Output should be a PDF document");
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_health_tool() {
+    use browser_use::tools::{HealthParams, health::HealthTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    assert!(session.is_alive());
+
+    let mut context = ToolContext::new(&session);
+    let result = HealthTool.execute_typed(HealthParams {}, &mut context).expect("Failed to execute health tool");
+    let data = result.data.unwrap();
+    info!("Health: {}", data);
+    assert_eq!(data["alive"], true);
+    assert!(data["product"].as_str().unwrap().contains("Chrome"));
+    assert_eq!(data["tab_count"].as_u64(), Some(1));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_mhtml_tool() {
+    use browser_use::tools::{MhtmlParams, mhtml::MhtmlTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session
+        .navigate("data:text/html,<style>h1 { color: red; }</style><h1>Archived</h1>")
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let mhtml = session.capture_mhtml().expect("Failed to capture MHTML");
+    assert!(mhtml.contains("MIME-Version"), "Output should be a MIME archive");
+    assert!(mhtml.contains("Archived"));
+
+    let path = std::env::temp_dir().join("browser_use_test_archive.mhtml");
+    let mut context = ToolContext::new(&session);
+    let result = MhtmlTool
+        .execute_typed(MhtmlParams { path: Some(path.to_string_lossy().to_string()) }, &mut context)
+        .expect("Failed to execute mhtml tool");
+    let data = result.data.unwrap();
+    assert_eq!(data["size_bytes"].as_u64(), Some(std::fs::metadata(&path).unwrap().len()));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_stealth_script_hides_webdriver() {
+    let check = "JSON.stringify([navigator.webdriver, navigator.plugins.length > 0, !!window.chrome.runtime])";
+    let evaluate =
+        |tab: &headless_chrome::Tab| tab.evaluate(check, false).unwrap().value.unwrap().as_str().unwrap().to_string();
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<h1>Stealth</h1>").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    assert_eq!(evaluate(&session.tab().unwrap()), "[null,true,true]");
+
+    // Tabs opened later get the init script too
+    let tab = session.new_tab().expect("Failed to open tab");
+    tab.navigate_to("data:text/html,<h1>Second tab</h1>").unwrap().wait_until_navigated().unwrap();
+    assert_eq!(evaluate(&tab), "[null,true,true]");
+
+    let session =
+        BrowserSession::launch(LaunchOptions::new().headless(true).stealth(false)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<h1>Plain</h1>").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+    let webdriver = session.tab().unwrap().evaluate("navigator.webdriver", false).unwrap();
+    assert_eq!(webdriver.value, Some(serde_json::json!(true)));
 }
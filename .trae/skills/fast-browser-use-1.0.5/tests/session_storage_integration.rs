@@ -0,0 +1,72 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{Tool, ToolContext,
+                          session_storage::{ClearSessionStorageParams, ClearSessionStorageTool,
+                                            GetSessionStorageParams, GetSessionStorageTool,
+                                            RemoveSessionStorageParams, RemoveSessionStorageTool,
+                                            SetSessionStorageParams, SetSessionStorageTool}}};
+use log::info;
+
+#[test]
+#[ignore]
+fn test_session_storage_workflow() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    // Navigate to a domain to have a context for sessionStorage
+    session.navigate("https://example.com").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    let get_tool = GetSessionStorageTool;
+    let set_tool = SetSessionStorageTool;
+    let remove_tool = RemoveSessionStorageTool;
+    let clear_tool = ClearSessionStorageTool;
+
+    // 1. Set a value
+    let set_result = set_tool
+        .execute_typed(SetSessionStorageParams { key: "token".to_string(), value: "abc123".to_string() }, &mut context)
+        .expect("Failed to set sessionStorage");
+    assert!(set_result.success);
+
+    // 2. Get the value
+    let get_result = get_tool
+        .execute_typed(GetSessionStorageParams { key: Some("token".to_string()) }, &mut context)
+        .expect("Failed to get sessionStorage");
+    assert_eq!(get_result.data.unwrap().as_str(), Some("abc123"));
+
+    // 3. Set another value
+    set_tool
+        .execute_typed(SetSessionStorageParams { key: "key2".to_string(), value: "value2".to_string() }, &mut context)
+        .expect("Failed to set sessionStorage key2");
+
+    // 4. Get all values
+    let all_data = get_tool
+        .execute_typed(GetSessionStorageParams { key: None }, &mut context)
+        .expect("Failed to get all sessionStorage")
+        .data
+        .unwrap();
+    assert_eq!(all_data["token"].as_str(), Some("abc123"));
+    assert_eq!(all_data["key2"].as_str(), Some("value2"));
+
+    // sessionStorage is separate from localStorage
+    let local = session.tab().unwrap().evaluate("window.localStorage.getItem('token')", false).unwrap();
+    assert!(local.value.is_none_or(|v| v.is_null()));
+
+    // 5. Remove one value
+    remove_tool
+        .execute_typed(RemoveSessionStorageParams { key: "token".to_string() }, &mut context)
+        .expect("Failed to remove sessionStorage item");
+    let get_removed = get_tool
+        .execute_typed(GetSessionStorageParams { key: Some("token".to_string()) }, &mut context)
+        .expect("Failed to get sessionStorage");
+    assert!(get_removed.data.unwrap().is_null());
+
+    // 6. Clear all
+    clear_tool.execute_typed(ClearSessionStorageParams, &mut context).expect("Failed to clear sessionStorage");
+    let cleared = get_tool
+        .execute_typed(GetSessionStorageParams { key: None }, &mut context)
+        .expect("Failed to get all sessionStorage after clear")
+        .data
+        .unwrap();
+    assert!(cleared.as_object().unwrap().is_empty());
+
+    info!("SessionStorage workflow passed!");
+}
@@ -15,13 +15,14 @@ fn test_sitemap_tool_basic() {
         .expect("Failed to launch browser");
 
     // Test with a real site that has a sitemap (example.com is simple)
-    let tool = SitemapTool::default();
+    let tool = SitemapTool;
     let mut context = ToolContext::new(&session);
 
     let params = SitemapParams {
         url: "https://example.com".to_string(),
         analyze_structure: false,
         max_pages: 5,
+        max_sitemaps: 10,
     };
 
     let result = tool.execute_typed(params, &mut context).expect("Failed to execute sitemap tool");
@@ -46,7 +47,7 @@ fn test_sitemap_tool_with_structure_analysis() {
     let session = BrowserSession::launch(LaunchOptions::new().headless(true))
         .expect("Failed to launch browser");
 
-    let tool = SitemapTool::default();
+    let tool = SitemapTool;
     let mut context = ToolContext::new(&session);
 
     // Use a site that we know has structure
@@ -54,6 +55,7 @@ fn test_sitemap_tool_with_structure_analysis() {
         url: "https://example.com".to_string(),
         analyze_structure: true,
         max_pages: 2,
+        max_sitemaps: 10,
     };
 
     let result = tool.execute_typed(params, &mut context).expect("Failed to execute sitemap tool");
@@ -69,15 +71,15 @@ fn test_sitemap_tool_with_structure_analysis() {
     assert!(page_structures.is_some(), "Should have page_structures array");
 
     // If any pages were analyzed, check the structure format
-    if let Some(structures) = page_structures {
-        if !structures.is_empty() {
-            let first = &structures[0];
-            assert!(first["url"].as_str().is_some(), "Page structure should have url");
-            assert!(first["title"].as_str().is_some(), "Page structure should have title");
-            assert!(first["headings"].is_array(), "Page structure should have headings array");
-            assert!(first["nav_links"].is_array(), "Page structure should have nav_links array");
-            assert!(first["sections"].is_array(), "Page structure should have sections array");
-        }
+    if let Some(structures) = page_structures
+        && !structures.is_empty()
+    {
+        let first = &structures[0];
+        assert!(first["url"].as_str().is_some(), "Page structure should have url");
+        assert!(first["title"].as_str().is_some(), "Page structure should have title");
+        assert!(first["headings"].is_array(), "Page structure should have headings array");
+        assert!(first["nav_links"].is_array(), "Page structure should have nav_links array");
+        assert!(first["sections"].is_array(), "Page structure should have sections array");
     }
 }
 
@@ -95,6 +97,7 @@ fn test_sitemap_analyze_function() {
         "https://example.com",
         true,
         2,
+        10,
     ).expect("Failed to analyze sitemap");
 
     info!("Analyze sitemap result: {:?}", result);
@@ -272,7 +275,7 @@ fn test_sitemap_robots_txt_parsing() {
     let session = BrowserSession::launch(LaunchOptions::new().headless(true))
         .expect("Failed to launch browser");
 
-    let tool = SitemapTool::default();
+    let tool = SitemapTool;
     let mut context = ToolContext::new(&session);
 
     // Test with a site known to have robots.txt with sitemap
@@ -280,6 +283,7 @@ fn test_sitemap_robots_txt_parsing() {
         url: "https://www.google.com".to_string(),
         analyze_structure: false,
         max_pages: 1,
+        max_sitemaps: 10,
     };
 
     let result = tool.execute_typed(params, &mut context).expect("Failed to execute sitemap tool");
@@ -302,13 +306,14 @@ fn test_sitemap_max_pages_limit() {
     let session = BrowserSession::launch(LaunchOptions::new().headless(true))
         .expect("Failed to launch browser");
 
-    let tool = SitemapTool::default();
+    let tool = SitemapTool;
     let mut context = ToolContext::new(&session);
 
     let params = SitemapParams {
         url: "https://example.com".to_string(),
         analyze_structure: true,
         max_pages: 1, // Limit to 1 page
+        max_sitemaps: 10,
     };
 
     let result = tool.execute_typed(params, &mut context).expect("Failed to execute sitemap tool");
@@ -22,7 +22,7 @@ fn test_new_tab() {
     info!("Initial tab count: {}", initial_count);
 
     // Create tool and context
-    let tool = NewTabTool::default();
+    let tool = NewTabTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to create a new tab
@@ -64,11 +64,11 @@ fn test_tab_list() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create tool and context
-    let tool = TabListTool::default();
+    let tool = TabListTool;
     let mut context = ToolContext::new(&session);
 
     // Execute the tool
-    let result = tool.execute_typed(TabListParams {}, &mut context).expect("Failed to execute tab_list tool");
+    let result = tool.execute_typed(TabListParams::default(), &mut context).expect("Failed to execute tab_list tool");
 
     // Verify the result
     assert!(result.success, "Tool execution should succeed");
@@ -92,6 +92,35 @@ fn test_tab_list() {
     assert!(first_tab["url"].is_string(), "Tab should have url");
 }
 
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_tab_list_filters() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<title>Inbox</title>").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    for page in ["<title>Search results</title>", "<title>Invoice 42</title>"] {
+        NewTabTool
+            .execute_typed(NewTabParams { url: format!("data:text/html,{}", page) }, &mut context)
+            .expect("Failed to create new tab");
+    }
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let params = TabListParams { title_contains: Some("in".to_string()), url_contains: None };
+    let data = TabListTool.execute_typed(params, &mut context).expect("Failed to list tabs").data.unwrap();
+    let titles: Vec<&str> = data["tab_list"].as_array().unwrap().iter().map(|t| t["title"].as_str().unwrap()).collect();
+    assert_eq!(titles, ["Inbox", "Invoice 42"]);
+    assert_eq!(data["count"], 2);
+    assert_eq!(data["total"], 3);
+    // Indices still refer to the unfiltered list
+    assert_eq!(data["tab_list"][1]["index"], 2);
+
+    let params = TabListParams { title_contains: None, url_contains: Some("search".to_string()) };
+    let data = TabListTool.execute_typed(params, &mut context).expect("Failed to list tabs").data.unwrap();
+    assert_eq!(data["count"], 1);
+    assert_eq!(data["tab_list"][0]["title"], "Search results");
+}
+
 #[test]
 #[ignore]
 fn test_new_tab_and_switch() {
@@ -103,7 +132,7 @@ fn test_new_tab_and_switch() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Create a new tab
-    let new_tab_tool = NewTabTool::default();
+    let new_tab_tool = NewTabTool;
     let mut context = ToolContext::new(&session);
 
     let result = new_tab_tool
@@ -118,10 +147,11 @@ fn test_new_tab_and_switch() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // List tabs to verify count increased by 1
-    let tab_list_tool = TabListTool::default();
+    let tab_list_tool = TabListTool;
     let mut context = ToolContext::new(&session);
 
-    let result = tab_list_tool.execute_typed(TabListParams {}, &mut context).expect("Failed to execute tab_list tool");
+    let result =
+        tab_list_tool.execute_typed(TabListParams::default(), &mut context).expect("Failed to execute tab_list tool");
 
     assert!(result.success);
     let data = result.data.unwrap();
@@ -131,7 +161,7 @@ fn test_new_tab_and_switch() {
     assert!(count >= 2, "Should have at least 2 tabs, got {}", count);
 
     // Switch to first tab (index 0)
-    let switch_tab_tool = SwitchTabTool::default();
+    let switch_tab_tool = SwitchTabTool;
     let mut context = ToolContext::new(&session);
 
     let result = switch_tab_tool
@@ -155,7 +185,7 @@ fn test_switch_tab_invalid_index() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Try to switch to invalid index
-    let switch_tab_tool = SwitchTabTool::default();
+    let switch_tab_tool = SwitchTabTool;
     let mut context = ToolContext::new(&session);
 
     let result = switch_tab_tool
@@ -178,7 +208,7 @@ fn test_close_tab() {
 
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let new_tab_tool = NewTabTool::default();
+    let new_tab_tool = NewTabTool;
     let mut context = ToolContext::new(&session);
 
     new_tab_tool
@@ -191,21 +221,23 @@ fn test_close_tab() {
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Verify we have at least 2 tabs
-    let tab_list_tool = TabListTool::default();
+    let tab_list_tool = TabListTool;
     let mut context = ToolContext::new(&session);
 
-    let result = tab_list_tool.execute_typed(TabListParams {}, &mut context).expect("Failed to execute tab_list tool");
+    let result =
+        tab_list_tool.execute_typed(TabListParams::default(), &mut context).expect("Failed to execute tab_list tool");
 
     let count_before = result.data.unwrap()["count"].as_u64().unwrap();
     info!("Tab count before closing: {}", count_before);
     assert!(count_before >= 2, "Should have at least 2 tabs before closing, got {}", count_before);
 
     // Close the active tab (second tab)
-    let close_tab_tool = CloseTabTool::default();
+    let close_tab_tool = CloseTabTool;
     let mut context = ToolContext::new(&session);
 
-    let result =
-        close_tab_tool.execute_typed(CloseTabParams {}, &mut context).expect("Failed to execute close_tab tool");
+    let result = close_tab_tool
+        .execute_typed(CloseTabParams { index: None }, &mut context)
+        .expect("Failed to execute close_tab tool");
 
     assert!(result.success, "Close tab should succeed");
     info!("Closed tab: {}", serde_json::to_string_pretty(&result.data.unwrap()).unwrap());
@@ -214,13 +246,44 @@ fn test_close_tab() {
 
     // Verify we now have one less tab
     let mut context = ToolContext::new(&session);
-    let result = tab_list_tool.execute_typed(TabListParams {}, &mut context).expect("Failed to execute tab_list tool");
+    let result =
+        tab_list_tool.execute_typed(TabListParams::default(), &mut context).expect("Failed to execute tab_list tool");
 
     let count_after = result.data.unwrap()["count"].as_u64().unwrap();
     info!("Tab count after closing: {}", count_after);
     assert_eq!(count_after, count_before - 1, "Should have one less tab after closing");
 }
 
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_close_background_tab_by_index() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<title>First</title>").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    NewTabTool
+        .execute_typed(NewTabParams { url: "data:text/html,<title>Second</title>".to_string() }, &mut context)
+        .expect("Failed to create new tab");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    assert_eq!(session.tab().unwrap().get_title().unwrap(), "Second");
+
+    // Close the first tab while the second stays active
+    let result =
+        CloseTabTool.execute_typed(CloseTabParams { index: Some(0) }, &mut context).expect("Failed to close tab");
+    assert!(result.success, "Close tab should succeed");
+    assert_eq!(result.data.unwrap()["title"], "First");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let tabs = session.get_tabs().expect("Failed to get tabs");
+    assert_eq!(tabs.len(), 1);
+    assert_eq!(tabs[0].get_title().unwrap(), "Second");
+    assert_eq!(session.tab().unwrap().get_title().unwrap(), "Second");
+
+    let result =
+        CloseTabTool.execute_typed(CloseTabParams { index: Some(5) }, &mut context).expect("Failed to run close_tab");
+    assert!(!result.success, "Should fail for invalid index");
+}
+
 #[test]
 #[ignore]
 fn test_tab_workflow() {
@@ -233,7 +296,7 @@ fn test_tab_workflow() {
     std::thread::sleep(std::time::Duration::from_millis(300));
 
     // Create second tab
-    let new_tab_tool = NewTabTool::default();
+    let new_tab_tool = NewTabTool;
     let mut context = ToolContext::new(&session);
 
     new_tab_tool
@@ -257,10 +320,10 @@ fn test_tab_workflow() {
     std::thread::sleep(std::time::Duration::from_millis(300));
 
     // List all tabs
-    let tab_list_tool = TabListTool::default();
+    let tab_list_tool = TabListTool;
     let mut context = ToolContext::new(&session);
 
-    let result = tab_list_tool.execute_typed(TabListParams {}, &mut context).expect("Failed to list tabs");
+    let result = tab_list_tool.execute_typed(TabListParams::default(), &mut context).expect("Failed to list tabs");
 
     let count = result.data.as_ref().unwrap()["count"].as_u64().unwrap();
     info!("Total tabs: {}", count);
@@ -268,7 +331,7 @@ fn test_tab_workflow() {
     info!("All tabs: {}", result.data.unwrap()["summary"].as_str().unwrap());
 
     // Switch to second tab (index 1)
-    let switch_tab_tool = SwitchTabTool::default();
+    let switch_tab_tool = SwitchTabTool;
     let mut context = ToolContext::new(&session);
 
     let result =
@@ -280,10 +343,11 @@ fn test_tab_workflow() {
     std::thread::sleep(std::time::Duration::from_millis(300));
 
     // Close the current tab (tab 2, index 1)
-    let close_tab_tool = CloseTabTool::default();
+    let close_tab_tool = CloseTabTool;
     let mut context = ToolContext::new(&session);
 
-    let result = close_tab_tool.execute_typed(CloseTabParams {}, &mut context).expect("Failed to close tab");
+    let result =
+        close_tab_tool.execute_typed(CloseTabParams { index: None }, &mut context).expect("Failed to close tab");
 
     assert!(result.success);
     info!("Closed: {}", result.data.unwrap()["message"].as_str().unwrap());
@@ -292,9 +356,117 @@ fn test_tab_workflow() {
 
     // List tabs again to verify we have 2 tabs left
     let mut context = ToolContext::new(&session);
-    let result = tab_list_tool.execute_typed(TabListParams {}, &mut context).expect("Failed to list tabs");
+    let result = tab_list_tool.execute_typed(TabListParams::default(), &mut context).expect("Failed to list tabs");
 
     let final_count = result.data.unwrap()["count"].as_u64().unwrap();
     info!("Final tab count: {}", final_count);
     assert_eq!(final_count, count - 1, "Should have one less tab after closing");
 }
+
+#[test]
+#[ignore]
+fn test_open_in_new_tab() {
+    use browser_use::tools::{OpenInNewTabParams, Tool, ToolContext, open_in_new_tab::OpenInNewTabTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    session
+        .navigate(
+            "data:text/html,<html><body><a id='link' href='data:text/html,<h1>Target</h1>'>Go</a><button \
+             id='btn'>Button</button></body></html>",
+        )
+        .expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    let initial_count = session.get_tabs().expect("Failed to get tabs").len();
+
+    let tool = OpenInNewTabTool;
+    let mut context = ToolContext::new(&session);
+
+    let result = tool
+        .execute_typed(OpenInNewTabParams { selector: Some("#link".to_string()), index: None }, &mut context)
+        .expect("Failed to execute open_in_new_tab tool");
+
+    let data = result.data.unwrap();
+    info!("Open in new tab result: {}", serde_json::to_string_pretty(&data).unwrap());
+    assert_eq!(data["tab_index"].as_u64(), Some(initial_count as u64));
+    assert_eq!(session.get_tabs().expect("Failed to get tabs").len(), initial_count + 1);
+
+    // Non-link elements are rejected
+    let result =
+        tool.execute_typed(OpenInNewTabParams { selector: Some("#btn".to_string()), index: None }, &mut context);
+    assert!(result.is_err());
+}
+
+#[test]
+#[ignore]
+fn test_wait_for_popup() {
+    use browser_use::tools::{WaitForPopupParams, popup::WaitForPopupTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    session.navigate("about:blank").expect("Failed to navigate");
+    session.wait_for_navigation().expect("Failed to wait for navigation");
+
+    // Nothing opens a popup yet
+    assert!(session.wait_for_new_target(200).is_err());
+
+    session
+        .tab()
+        .expect("Failed to get tab")
+        .evaluate("setTimeout(() => window.open('data:text/html,<title>Popup</title><h1>Popup</h1>'), 300)", false)
+        .expect("Failed to schedule window.open");
+
+    let tool = WaitForPopupTool;
+    let mut context = ToolContext::new(&session);
+    let result = tool
+        .execute_typed(WaitForPopupParams { timeout_ms: 5000, activate: true }, &mut context)
+        .expect("Failed to execute wait_for_popup tool");
+
+    let data = result.data.unwrap();
+    info!("Wait for popup result: {}", serde_json::to_string_pretty(&data).unwrap());
+    assert_eq!(data["title"].as_str(), Some("Popup"));
+    assert_eq!(data["index"].as_u64(), Some(1));
+    assert_eq!(session.get_tabs().expect("Failed to get tabs").len(), 2);
+
+    // The popup was adopted, so it is not reported again
+    assert!(session.wait_for_new_target(200).is_err());
+}
+
+#[test]
+#[ignore]
+fn test_tool_context_for_tab() {
+    use browser_use::tools::{NavigateParams, navigate::NavigateTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let first = session.tab().expect("Failed to get tab");
+    let second = session.new_tab().expect("Failed to open tab");
+
+    // Drive both tabs at once, each through its own context
+    std::thread::scope(|scope| {
+        for (tab, title) in [(first.clone(), "First"), (second.clone(), "Second")] {
+            let session = &session;
+            scope.spawn(move || {
+                let mut context = ToolContext::for_tab(session, tab);
+                NavigateTool
+                    .execute_typed(
+                        NavigateParams {
+                            url: format!("data:text/html,<title>{}</title><h1>{}</h1>", title, title),
+                            wait_for_load: true,
+                            wait_for_selector: None,
+                            timeout_ms: None,
+                        },
+                        &mut context,
+                    )
+                    .expect("Failed to navigate");
+            });
+        }
+    });
+
+    assert_eq!(first.get_title().unwrap(), "First");
+    assert_eq!(second.get_title().unwrap(), "Second");
+
+    // Contexts without an explicit tab still use the active tab
+    let context = ToolContext::new(&session);
+    assert!(std::sync::Arc::ptr_eq(&context.tab().unwrap(), &session.tab().unwrap()));
+}